@@ -1,3 +1,5 @@
+use crate::entity::{DependencyReport, LanguageReport, LicenseReport, StackReport};
+use crate::service::{BuiltinReporters, ReporterRegistry};
 use clap::{Parser, Subcommand};
 use config::ConfigError;
 
@@ -8,6 +10,18 @@ use config::ConfigError;
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    #[arg(
+        short,
+        long,
+        global = true,
+        conflicts_with = "verbose",
+        help = "Only print errors to stderr"
+    )]
+    pub quiet: bool,
+
+    #[arg(short, long, global = true, help = "Print debug output to stderr")]
+    pub verbose: bool,
 }
 
 #[derive(Subcommand)]
@@ -24,8 +38,130 @@ pub enum Commands {
         )]
         exclude: Option<Vec<String>>,
 
-        #[arg(short, long, help = "Output format: table, json [default: table]")]
-        reporter: Option<String>,
+        #[arg(
+            short,
+            long,
+            help = reporter_help::<LanguageReport>()
+        )]
+        reporter: Option<Vec<String>>,
+
+        #[arg(short, long, help = "Config file path")]
+        config: Option<String>,
+
+        #[arg(
+            short,
+            long,
+            help = "Percentage basis and sort order: files, bytes, lines [default: files]"
+        )]
+        weight: Option<String>,
+
+        #[arg(
+            long,
+            overrides_with = "no_sniff_content",
+            help = "Detect extensionless files by their shebang or Vim/Emacs modeline"
+        )]
+        sniff_content: bool,
+
+        #[arg(
+            long,
+            overrides_with = "sniff_content",
+            help = "Do not sniff file content, even if the config file enables it"
+        )]
+        no_sniff_content: bool,
+
+        #[arg(
+            long,
+            help = "Count vendored and generated files toward language percentages"
+        )]
+        include_vendored: bool,
+
+        #[arg(
+            long,
+            help = "Break Vue, Svelte and Astro files down into their embedded languages"
+        )]
+        embedded: bool,
+
+        #[arg(
+            long,
+            help = "Write one row per file, with its path and language, in csv and tsv reports"
+        )]
+        csv_files: bool,
+
+        #[arg(
+            long,
+            help = "List the files of each language in collapsible sections of markdown reports"
+        )]
+        markdown_files: bool,
+
+        #[arg(
+            short,
+            long,
+            value_delimiter = ',',
+            help = "Only report these language kinds: programming, markup, style, data, config, prose"
+        )]
+        kinds: Option<Vec<String>>,
+    },
+    #[command(visible_alias = "dependencies")]
+    Deps {
+        #[arg(help = "Directory path to analyze")]
+        dir: String,
+
+        #[arg(
+            short,
+            long,
+            help = "Exclude path patterns (can be used multiple times)"
+        )]
+        exclude: Option<Vec<String>>,
+
+        #[arg(
+            short,
+            long,
+            help = reporter_help::<DependencyReport>()
+        )]
+        reporter: Option<Vec<String>>,
+
+        #[arg(short, long, help = "Config file path")]
+        config: Option<String>,
+    },
+    Stack {
+        #[arg(help = "Directory path to analyze")]
+        dir: String,
+
+        #[arg(
+            short,
+            long,
+            help = "Exclude path patterns (can be used multiple times)"
+        )]
+        exclude: Option<Vec<String>>,
+
+        #[arg(
+            short,
+            long,
+            help = reporter_help::<StackReport>()
+        )]
+        reporter: Option<Vec<String>>,
+
+        #[arg(short, long, help = "Config file path")]
+        config: Option<String>,
+    },
+    #[command(visible_alias = "licenses")]
+    License {
+        #[arg(help = "Directory path to analyze")]
+        dir: String,
+
+        #[arg(
+            short,
+            long,
+            help = "Exclude path patterns (can be used multiple times)"
+        )]
+        exclude: Option<Vec<String>>,
+
+        #[arg(
+            short,
+            long,
+            help = reporter_help::<LicenseReport>()
+        )]
+        reporter: Option<Vec<String>>,
 
         #[arg(short, long, help = "Config file path")]
         config: Option<String>,
@@ -37,3 +173,11 @@ impl Cli {
         Ok(Self::parse())
     }
 }
+
+/// Lists the formats of the report's reporter registry, which library users can extend.
+fn reporter_help<R: BuiltinReporters>() -> String {
+    format!(
+        "Output format: {}, optionally written to a file with format=path (can be used multiple times) [default: table]",
+        ReporterRegistry::<R>::new().names().join(", ")
+    )
+}
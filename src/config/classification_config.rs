@@ -0,0 +1,102 @@
+/// Path globs of third-party code checked into a repository, relative to the scanned
+/// directory.
+const VENDORED_PATTERNS: &[&str] = &[
+    "**/vendor/**",
+    "**/vendors/**",
+    "**/third_party/**",
+    "**/third-party/**",
+    "**/thirdparty/**",
+    "**/node_modules/**",
+    "**/bower_components/**",
+    "**/jspm_packages/**",
+    "**/Pods/**",
+    "**/Carthage/**",
+    "**/*.min.js",
+    "**/*.min.css",
+    "**/*-min.js",
+    "**/*.bundle.js",
+];
+
+/// Path globs of files produced by code generators and package managers.
+const GENERATED_PATTERNS: &[&str] = &[
+    "**/*.pb.go",
+    "**/*.pb.cc",
+    "**/*.pb.h",
+    "**/*_pb2.py",
+    "**/*_pb2_grpc.py",
+    "**/*_pb.js",
+    "**/*_pb.d.ts",
+    "**/*_generated.rs",
+    "**/*_generated.go",
+    "**/*.generated.*",
+    "**/*.g.dart",
+    "**/*.freezed.dart",
+    "**/*.designer.cs",
+    "**/*.Designer.cs",
+    "**/zz_generated*.go",
+    "**/*.lock",
+    "**/package-lock.json",
+    "**/npm-shrinkwrap.json",
+    "**/pnpm-lock.yaml",
+    "**/go.sum",
+];
+
+/// Path globs of test sources, following the conventions of each ecosystem.
+const TEST_PATTERNS: &[&str] = &[
+    "**/test/**",
+    "**/tests/**",
+    "**/__tests__/**",
+    "**/spec/**",
+    "**/*_test.go",
+    "**/*.{test,spec}.{js,jsx,mjs,cjs,ts,tsx,mts,cts}",
+    "**/test_*.py",
+    "**/*_test.py",
+    "**/*_spec.rb",
+    "**/*_test.rb",
+    "**/*{Test,Tests}.{java,kt,scala,cs}",
+    "**/*_test.{dart,exs}",
+];
+
+/// Path globs of test fixtures, test data and examples. They take precedence over test
+/// patterns, so `tests/fixtures/` is not counted as test code. The singular `example/` is
+/// left out because it is a common Java package name.
+const FIXTURE_PATTERNS: &[&str] = &[
+    "**/fixtures/**",
+    "**/fixture/**",
+    "**/__fixtures__/**",
+    "**/testdata/**",
+    "**/examples/**",
+];
+
+/// Regexes matched against the head of a file that mark it as generated.
+const GENERATED_MARKERS: &[&str] = &[
+    r"(?m)^\s*// Code generated .* DO NOT EDIT\.?\s*$",
+    r"@generated\b",
+    r"<auto-generated",
+    r"(?i)\bautomatically generated\b.*\bdo not (edit|modify)\b",
+    r"Generated by the protocol buffer compiler\.\s+DO NOT EDIT",
+];
+
+pub struct ClassificationConfig;
+
+impl ClassificationConfig {
+    pub fn vendored_patterns() -> &'static [&'static str] {
+        VENDORED_PATTERNS
+    }
+
+    pub fn generated_patterns() -> &'static [&'static str] {
+        GENERATED_PATTERNS
+    }
+
+    pub fn test_patterns() -> &'static [&'static str] {
+        TEST_PATTERNS
+    }
+
+    pub fn fixture_patterns() -> &'static [&'static str] {
+        FIXTURE_PATTERNS
+    }
+
+    pub fn generated_markers() -> &'static [&'static str] {
+        GENERATED_MARKERS
+    }
+}
@@ -0,0 +1,240 @@
+use crate::entity::{DetectionRule, Ecosystem};
+
+const CATEGORY_RELATIONAL: &str = "relational";
+const CATEGORY_DOCUMENT: &str = "document";
+const CATEGORY_KEY_VALUE: &str = "key-value";
+const CATEGORY_SEARCH: &str = "search";
+const CATEGORY_MIGRATIONS: &str = "migrations";
+
+const COMPOSE_FILES: &str = "**/{docker-compose,compose}*.{yml,yaml}";
+const PRISMA_SCHEMAS: &str = "**/*.prisma";
+/// Database configuration of Rails applications, with the ActiveRecord adapter in use.
+const RAILS_DATABASE_CONFIG: &str = "**/config/database.yml";
+const CARGO_MANIFESTS: &str = "**/Cargo.toml";
+
+const DATASTORE_RULES: &[DetectionRule] = &[
+    DetectionRule {
+        name: "PostgreSQL",
+        category: CATEGORY_RELATIONAL,
+        dependencies: &[
+            (Ecosystem::Npm, "pg"),
+            (Ecosystem::Npm, "postgres"),
+            (Ecosystem::Npm, "pg-promise"),
+            (Ecosystem::Pypi, "psycopg*"),
+            (Ecosystem::Pypi, "asyncpg"),
+            (Ecosystem::Go, "github.com/lib/pq"),
+            (Ecosystem::Go, "github.com/jackc/pgx*"),
+            (Ecosystem::Cargo, "postgres"),
+            (Ecosystem::Cargo, "tokio-postgres"),
+            (Ecosystem::Rubygems, "pg"),
+            (Ecosystem::Maven, "org.postgresql:postgresql"),
+            (Ecosystem::Hex, "postgrex"),
+            (Ecosystem::Pub, "postgres"),
+        ],
+        files: &[],
+        imports: &[],
+        contents: &[
+            (
+                COMPOSE_FILES,
+                r#"(?m)^\s*image:\s*["']?(?:[\w.-]+/)*(?:postgres(?:ql)?|postgis)(?:[:@"']|\s*$)"#,
+            ),
+            (
+                PRISMA_SCHEMAS,
+                r#"(?m)^\s*provider\s*=\s*"postgres(?:ql)?""#,
+            ),
+            (
+                RAILS_DATABASE_CONFIG,
+                r"(?m)^\s*adapter:\s*(?:postgresql|postgis)\s*$",
+            ),
+            (
+                CARGO_MANIFESTS,
+                r#"(?m)^(?:sqlx|diesel)\s*=\s*\{[^}]*["']postgres["']"#,
+            ),
+        ],
+    },
+    DetectionRule {
+        name: "MySQL",
+        category: CATEGORY_RELATIONAL,
+        dependencies: &[
+            (Ecosystem::Npm, "mysql"),
+            (Ecosystem::Npm, "mysql2"),
+            (Ecosystem::Pypi, "mysqlclient"),
+            (Ecosystem::Pypi, "pymysql"),
+            (Ecosystem::Pypi, "mysql-connector-python"),
+            (Ecosystem::Go, "github.com/go-sql-driver/mysql"),
+            (Ecosystem::Cargo, "mysql"),
+            (Ecosystem::Cargo, "mysql_async"),
+            (Ecosystem::Rubygems, "mysql2"),
+            (Ecosystem::Rubygems, "trilogy"),
+            (Ecosystem::Maven, "com.mysql:mysql-connector-j"),
+            (Ecosystem::Maven, "mysql:mysql-connector-java"),
+            (Ecosystem::Maven, "org.mariadb.jdbc:mariadb-java-client"),
+            (Ecosystem::Hex, "myxql"),
+        ],
+        files: &[],
+        imports: &[],
+        contents: &[
+            (
+                COMPOSE_FILES,
+                r#"(?m)^\s*image:\s*["']?(?:[\w.-]+/)*(?:mysql|mariadb)(?:[:@"']|\s*$)"#,
+            ),
+            (PRISMA_SCHEMAS, r#"(?m)^\s*provider\s*=\s*"mysql""#),
+            (
+                RAILS_DATABASE_CONFIG,
+                r"(?m)^\s*adapter:\s*(?:mysql2|trilogy)\s*$",
+            ),
+            (
+                CARGO_MANIFESTS,
+                r#"(?m)^(?:sqlx|diesel)\s*=\s*\{[^}]*["']mysql["']"#,
+            ),
+        ],
+    },
+    DetectionRule {
+        name: "SQLite",
+        category: CATEGORY_RELATIONAL,
+        dependencies: &[
+            (Ecosystem::Npm, "sqlite3"),
+            (Ecosystem::Npm, "better-sqlite3"),
+            (Ecosystem::Go, "github.com/mattn/go-sqlite3"),
+            (Ecosystem::Go, "modernc.org/sqlite"),
+            (Ecosystem::Cargo, "rusqlite"),
+            (Ecosystem::Rubygems, "sqlite3"),
+            (Ecosystem::Maven, "org.xerial:sqlite-jdbc"),
+            (Ecosystem::Hex, "ecto_sqlite3"),
+            (Ecosystem::Pub, "sqflite"),
+        ],
+        files: &[],
+        imports: &[(Ecosystem::Pypi, r"(?m)^\s*import\s+sqlite3\b")],
+        contents: &[
+            (PRISMA_SCHEMAS, r#"(?m)^\s*provider\s*=\s*"sqlite""#),
+            (RAILS_DATABASE_CONFIG, r"(?m)^\s*adapter:\s*sqlite3\s*$"),
+            (
+                CARGO_MANIFESTS,
+                r#"(?m)^(?:sqlx|diesel)\s*=\s*\{[^}]*["']sqlite["']"#,
+            ),
+        ],
+    },
+    DetectionRule {
+        name: "SQL Server",
+        category: CATEGORY_RELATIONAL,
+        dependencies: &[
+            (Ecosystem::Npm, "mssql"),
+            (Ecosystem::Npm, "tedious"),
+            (Ecosystem::Pypi, "pymssql"),
+            (Ecosystem::Go, "github.com/microsoft/go-mssqldb"),
+            (Ecosystem::Go, "github.com/denisenkom/go-mssqldb"),
+            (Ecosystem::Cargo, "tiberius"),
+            (Ecosystem::Maven, "com.microsoft.sqlserver:mssql-jdbc"),
+        ],
+        files: &[],
+        imports: &[],
+        contents: &[
+            (
+                COMPOSE_FILES,
+                r#"(?m)^\s*image:\s*["']?(?:[\w.-]+/)*mssql/server(?:[:@"']|\s*$)"#,
+            ),
+            (PRISMA_SCHEMAS, r#"(?m)^\s*provider\s*=\s*"sqlserver""#),
+            (RAILS_DATABASE_CONFIG, r"(?m)^\s*adapter:\s*sqlserver\s*$"),
+        ],
+    },
+    DetectionRule {
+        name: "MongoDB",
+        category: CATEGORY_DOCUMENT,
+        dependencies: &[
+            (Ecosystem::Npm, "mongodb"),
+            (Ecosystem::Npm, "mongoose"),
+            (Ecosystem::Pypi, "pymongo"),
+            (Ecosystem::Pypi, "motor"),
+            (Ecosystem::Pypi, "mongoengine"),
+            (Ecosystem::Go, "go.mongodb.org/mongo-driver*"),
+            (Ecosystem::Cargo, "mongodb"),
+            (Ecosystem::Rubygems, "mongo"),
+            (Ecosystem::Rubygems, "mongoid"),
+            (Ecosystem::Maven, "org.mongodb:*"),
+            (
+                Ecosystem::Maven,
+                "org.springframework.boot:spring-boot-starter-data-mongodb*",
+            ),
+            (Ecosystem::Packagist, "mongodb/mongodb"),
+            (Ecosystem::Hex, "mongodb_driver"),
+        ],
+        files: &[],
+        imports: &[],
+        contents: &[
+            (
+                COMPOSE_FILES,
+                r#"(?m)^\s*image:\s*["']?(?:[\w.-]+/)*mongo(?:db)?(?:[:@"']|\s*$)"#,
+            ),
+            (PRISMA_SCHEMAS, r#"(?m)^\s*provider\s*=\s*"mongodb""#),
+        ],
+    },
+    DetectionRule {
+        name: "Redis",
+        category: CATEGORY_KEY_VALUE,
+        dependencies: &[
+            (Ecosystem::Npm, "redis"),
+            (Ecosystem::Npm, "ioredis"),
+            (Ecosystem::Pypi, "redis"),
+            (Ecosystem::Go, "github.com/redis/go-redis*"),
+            (Ecosystem::Go, "github.com/go-redis/redis*"),
+            (Ecosystem::Go, "github.com/gomodule/redigo"),
+            (Ecosystem::Cargo, "redis"),
+            (Ecosystem::Rubygems, "redis"),
+            (Ecosystem::Maven, "redis.clients:jedis"),
+            (Ecosystem::Maven, "io.lettuce:lettuce-core"),
+            (
+                Ecosystem::Maven,
+                "org.springframework.boot:spring-boot-starter-data-redis*",
+            ),
+            (Ecosystem::Packagist, "predis/predis"),
+            (Ecosystem::Hex, "redix"),
+        ],
+        files: &[],
+        imports: &[],
+        contents: &[(
+            COMPOSE_FILES,
+            r#"(?m)^\s*image:\s*["']?(?:[\w.-]+/)*redis(?:-stack(?:-server)?)?(?:[:@"']|\s*$)"#,
+        )],
+    },
+    DetectionRule {
+        name: "Elasticsearch",
+        category: CATEGORY_SEARCH,
+        dependencies: &[
+            (Ecosystem::Npm, "@elastic/elasticsearch"),
+            (Ecosystem::Pypi, "elasticsearch*"),
+            (Ecosystem::Go, "github.com/elastic/go-elasticsearch*"),
+            (Ecosystem::Cargo, "elasticsearch"),
+            (Ecosystem::Rubygems, "elasticsearch*"),
+            (Ecosystem::Maven, "co.elastic.clients:*"),
+            (Ecosystem::Maven, "org.elasticsearch.client:*"),
+        ],
+        files: &[],
+        imports: &[],
+        contents: &[(
+            COMPOSE_FILES,
+            r#"(?m)^\s*image:\s*["']?(?:[\w.-]+/)*elasticsearch(?:[:@"']|\s*$)"#,
+        )],
+    },
+    DetectionRule {
+        name: "Schema migrations",
+        category: CATEGORY_MIGRATIONS,
+        dependencies: &[],
+        files: &[
+            "**/db/migrate/*.rb",
+            "**/migrations/**/*.sql",
+            "**/db/migration/*.sql",
+            "**/alembic.ini",
+            "**/migrations/0*.py",
+        ],
+        imports: &[],
+        contents: &[],
+    },
+];
+
+pub struct DatastoreConfig;
+
+impl DatastoreConfig {
+    pub fn rules() -> &'static [DetectionRule] {
+        DATASTORE_RULES
+    }
+}
@@ -0,0 +1,298 @@
+use crate::entity::{DetectionRule, Ecosystem};
+
+const CATEGORY_FRONTEND: &str = "frontend";
+const CATEGORY_BACKEND: &str = "backend";
+const CATEGORY_FULLSTACK: &str = "fullstack";
+const CATEGORY_MOBILE: &str = "mobile";
+const CATEGORY_DESKTOP: &str = "desktop";
+
+const FRAMEWORK_RULES: &[DetectionRule] = &[
+    DetectionRule {
+        name: "React",
+        category: CATEGORY_FRONTEND,
+        dependencies: &[(Ecosystem::Npm, "react")],
+        files: &[],
+        imports: &[(
+            Ecosystem::Npm,
+            r#"(?:\bfrom\s*|\brequire\s*\(\s*)["']react["']"#,
+        )],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Vue.js",
+        category: CATEGORY_FRONTEND,
+        dependencies: &[(Ecosystem::Npm, "vue")],
+        files: &["**/vue.config.js"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Angular",
+        category: CATEGORY_FRONTEND,
+        dependencies: &[(Ecosystem::Npm, "@angular/core")],
+        files: &["**/angular.json"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Svelte",
+        category: CATEGORY_FRONTEND,
+        dependencies: &[(Ecosystem::Npm, "svelte")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Next.js",
+        category: CATEGORY_FULLSTACK,
+        dependencies: &[(Ecosystem::Npm, "next")],
+        files: &[
+            "**/next.config.js",
+            "**/next.config.mjs",
+            "**/next.config.ts",
+        ],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Nuxt",
+        category: CATEGORY_FULLSTACK,
+        dependencies: &[(Ecosystem::Npm, "nuxt")],
+        files: &["**/nuxt.config.js", "**/nuxt.config.ts"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "SvelteKit",
+        category: CATEGORY_FULLSTACK,
+        dependencies: &[(Ecosystem::Npm, "@sveltejs/kit")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Astro",
+        category: CATEGORY_FULLSTACK,
+        dependencies: &[(Ecosystem::Npm, "astro")],
+        files: &["**/astro.config.mjs", "**/astro.config.ts"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Remix",
+        category: CATEGORY_FULLSTACK,
+        dependencies: &[(Ecosystem::Npm, "@remix-run/*")],
+        files: &["**/remix.config.js"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Gatsby",
+        category: CATEGORY_FRONTEND,
+        dependencies: &[(Ecosystem::Npm, "gatsby")],
+        files: &["**/gatsby-config.js", "**/gatsby-config.ts"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Express",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Npm, "express")],
+        files: &[],
+        imports: &[(
+            Ecosystem::Npm,
+            r#"(?:\bfrom\s*|\brequire\s*\(\s*)["']express["']"#,
+        )],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "NestJS",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Npm, "@nestjs/core")],
+        files: &["**/nest-cli.json"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Fastify",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Npm, "fastify")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "React Native",
+        category: CATEGORY_MOBILE,
+        dependencies: &[(Ecosystem::Npm, "react-native")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Electron",
+        category: CATEGORY_DESKTOP,
+        dependencies: &[(Ecosystem::Npm, "electron")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Django",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Pypi, "django")],
+        files: &["**/manage.py"],
+        imports: &[(Ecosystem::Pypi, r"(?m)^\s*(?:from|import)\s+django\b")],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Flask",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Pypi, "flask")],
+        files: &[],
+        imports: &[(Ecosystem::Pypi, r"(?m)^\s*(?:from|import)\s+flask\b")],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "FastAPI",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Pypi, "fastapi")],
+        files: &[],
+        imports: &[(Ecosystem::Pypi, r"(?m)^\s*(?:from|import)\s+fastapi\b")],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Ruby on Rails",
+        category: CATEGORY_FULLSTACK,
+        dependencies: &[(Ecosystem::Rubygems, "rails")],
+        files: &["**/config/routes.rb", "**/bin/rails"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Sinatra",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Rubygems, "sinatra")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Spring Boot",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Maven, "org.springframework.boot:*")],
+        files: &[],
+        imports: &[(
+            Ecosystem::Maven,
+            r"(?m)^\s*import\s+org\.springframework\.boot\.",
+        )],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Quarkus",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Maven, "io.quarkus:*")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Laravel",
+        category: CATEGORY_FULLSTACK,
+        dependencies: &[(Ecosystem::Packagist, "laravel/framework")],
+        files: &["**/artisan"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Symfony",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Packagist, "symfony/framework-bundle")],
+        files: &["**/symfony.lock"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Gin",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Go, "github.com/gin-gonic/gin")],
+        files: &[],
+        imports: &[(Ecosystem::Go, r#""github\.com/gin-gonic/gin""#)],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Echo",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Go, "github.com/labstack/echo*")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Fiber",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Go, "github.com/gofiber/fiber*")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Actix Web",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Cargo, "actix-web")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Axum",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Cargo, "axum")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Rocket",
+        category: CATEGORY_BACKEND,
+        dependencies: &[(Ecosystem::Cargo, "rocket")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Tauri",
+        category: CATEGORY_DESKTOP,
+        dependencies: &[
+            (Ecosystem::Cargo, "tauri"),
+            (Ecosystem::Npm, "@tauri-apps/api"),
+        ],
+        files: &["**/tauri.conf.json"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Phoenix",
+        category: CATEGORY_FULLSTACK,
+        dependencies: &[(Ecosystem::Hex, "phoenix")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Flutter",
+        category: CATEGORY_MOBILE,
+        dependencies: &[(Ecosystem::Pub, "flutter")],
+        files: &[],
+        imports: &[],
+        contents: &[],
+    },
+];
+
+pub struct FrameworkConfig;
+
+impl FrameworkConfig {
+    pub fn rules() -> &'static [DetectionRule] {
+        FRAMEWORK_RULES
+    }
+}
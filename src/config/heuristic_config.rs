@@ -0,0 +1,94 @@
+use crate::entity::{Heuristic, HeuristicRule};
+
+const H: Heuristic = Heuristic {
+    exts: &["h"],
+    rules: &[
+        HeuristicRule {
+            language: "Objective-C",
+            patterns: &[
+                r"(?m)^\s*@(interface|implementation|protocol|end)\b",
+                r"(?m)^\s*#import\s",
+            ],
+        },
+        HeuristicRule {
+            language: "C++",
+            patterns: &[
+                r"(?m)^\s*(class|namespace)\s+\w+",
+                r"(?m)^\s*template\s*<",
+                r"(?m)^\s*#include\s*<(iostream|string|vector|map|memory|algorithm)>",
+                r"\bstd::",
+            ],
+        },
+    ],
+    fallback: "C",
+};
+
+const M: Heuristic = Heuristic {
+    exts: &["m"],
+    rules: &[
+        HeuristicRule {
+            language: "Objective-C",
+            patterns: &[
+                r"(?m)^\s*@(interface|implementation|protocol|end)\b",
+                r"(?m)^\s*#(import|include)\s",
+            ],
+        },
+        HeuristicRule {
+            language: "Mercury",
+            patterns: &[r"(?m)^\s*:-\s*(module|interface|implementation)\b"],
+        },
+        HeuristicRule {
+            language: "MATLAB",
+            patterns: &[r"(?m)^\s*%", r"(?m)^\s*function\b", r"(?m)^\s*end\s*;?\s*$"],
+        },
+    ],
+    fallback: "Objective-C",
+};
+
+const PL: Heuristic = Heuristic {
+    exts: &["pl"],
+    rules: &[
+        HeuristicRule {
+            language: "Perl",
+            patterns: &[
+                r"\A#!.*\bperl",
+                r"(?m)^\s*use\s+(strict|warnings)\b",
+                r"(?m)^\s*(my|our)\s+[$@%]",
+                r"(?m)^\s*sub\s+\w+",
+            ],
+        },
+        HeuristicRule {
+            language: "Prolog",
+            patterns: &[r"(?m)^\s*:-", r"(?m)^[a-z]\w*(\(.*\))?\s*:-"],
+        },
+    ],
+    fallback: "Perl",
+};
+
+const R: Heuristic = Heuristic {
+    exts: &["r"],
+    rules: &[HeuristicRule {
+        language: "Rebol",
+        patterns: &[r"(?i)\bREBOL\s*\["],
+    }],
+    fallback: "R",
+};
+
+const TS: Heuristic = Heuristic {
+    exts: &["ts"],
+    rules: &[HeuristicRule {
+        language: "Qt Translation",
+        patterns: &[r"\A\s*<\?xml", r"(?m)^\s*<TS\b"],
+    }],
+    fallback: "TypeScript",
+};
+
+const SUPPORTED_HEURISTICS: &[Heuristic] = &[H, M, PL, R, TS];
+
+pub struct HeuristicConfig;
+
+impl HeuristicConfig {
+    pub fn heuristics() -> &'static [Heuristic] {
+        SUPPORTED_HEURISTICS
+    }
+}
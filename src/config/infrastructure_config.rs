@@ -0,0 +1,206 @@
+use crate::entity::{DetectionRule, Ecosystem};
+
+const CATEGORY_CI: &str = "ci";
+const CATEGORY_CONTAINER: &str = "container";
+const CATEGORY_ORCHESTRATION: &str = "orchestration";
+const CATEGORY_IAC: &str = "iac";
+
+const YAML_FILES: &str = "**/*.{yml,yaml}";
+
+/// A Kubernetes object: top-level `apiVersion` and `kind` keys, in either order.
+const KUBERNETES_OBJECT: &str = r"(?ms)^apiVersion:\s*\S+\s*$.*?^kind:\s*[A-Z]\w*\s*$|^kind:\s*[A-Z]\w*\s*$.*?^apiVersion:\s*\S+\s*$";
+
+const INFRASTRUCTURE_RULES: &[DetectionRule] = &[
+    DetectionRule {
+        name: "GitHub Actions",
+        category: CATEGORY_CI,
+        dependencies: &[],
+        files: &[".github/workflows/*.yml", ".github/workflows/*.yaml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "GitLab CI",
+        category: CATEGORY_CI,
+        dependencies: &[],
+        files: &["**/.gitlab-ci.yml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "CircleCI",
+        category: CATEGORY_CI,
+        dependencies: &[],
+        files: &[".circleci/config.yml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Jenkins",
+        category: CATEGORY_CI,
+        dependencies: &[],
+        files: &["**/Jenkinsfile"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Travis CI",
+        category: CATEGORY_CI,
+        dependencies: &[],
+        files: &[".travis.yml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Azure Pipelines",
+        category: CATEGORY_CI,
+        dependencies: &[],
+        files: &["**/azure-pipelines.yml", "**/azure-pipelines.yaml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Bitbucket Pipelines",
+        category: CATEGORY_CI,
+        dependencies: &[],
+        files: &["bitbucket-pipelines.yml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Buildkite",
+        category: CATEGORY_CI,
+        dependencies: &[],
+        files: &[".buildkite/pipeline.yml", ".buildkite/pipeline.yaml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Docker",
+        category: CATEGORY_CONTAINER,
+        dependencies: &[],
+        files: &["**/Dockerfile", "**/Dockerfile.*", "**/*.dockerfile"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Docker Compose",
+        category: CATEGORY_CONTAINER,
+        dependencies: &[],
+        files: &[
+            "**/docker-compose.yml",
+            "**/docker-compose.yaml",
+            "**/docker-compose.*.yml",
+            "**/compose.yml",
+            "**/compose.yaml",
+        ],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Kubernetes",
+        category: CATEGORY_ORCHESTRATION,
+        dependencies: &[],
+        files: &["**/kustomization.yaml", "**/kustomization.yml"],
+        imports: &[],
+        contents: &[(YAML_FILES, KUBERNETES_OBJECT)],
+    },
+    DetectionRule {
+        name: "Helm",
+        category: CATEGORY_ORCHESTRATION,
+        dependencies: &[],
+        files: &["**/Chart.yaml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Skaffold",
+        category: CATEGORY_ORCHESTRATION,
+        dependencies: &[],
+        files: &["**/skaffold.yaml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Terraform",
+        category: CATEGORY_IAC,
+        dependencies: &[],
+        files: &["**/*.tf", "**/*.tf.json", "**/.terraform.lock.hcl"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Terragrunt",
+        category: CATEGORY_IAC,
+        dependencies: &[],
+        files: &["**/terragrunt.hcl"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Pulumi",
+        category: CATEGORY_IAC,
+        dependencies: &[
+            (Ecosystem::Npm, "@pulumi/*"),
+            (Ecosystem::Pypi, "pulumi"),
+            (Ecosystem::Go, "github.com/pulumi/pulumi/sdk*"),
+        ],
+        files: &["**/Pulumi.yaml", "**/Pulumi.yml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "AWS CDK",
+        category: CATEGORY_IAC,
+        dependencies: &[
+            (Ecosystem::Npm, "aws-cdk-lib"),
+            (Ecosystem::Pypi, "aws-cdk-lib"),
+        ],
+        files: &["**/cdk.json"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "AWS CloudFormation",
+        category: CATEGORY_IAC,
+        dependencies: &[],
+        files: &[],
+        imports: &[],
+        contents: &[
+            (YAML_FILES, r"(?m)^AWSTemplateFormatVersion:"),
+            ("**/*.json", r#""AWSTemplateFormatVersion"\s*:"#),
+        ],
+    },
+    DetectionRule {
+        name: "Serverless Framework",
+        category: CATEGORY_IAC,
+        dependencies: &[],
+        files: &["**/serverless.yml", "**/serverless.yaml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Ansible",
+        category: CATEGORY_IAC,
+        dependencies: &[],
+        files: &["**/ansible.cfg", "**/roles/*/tasks/main.yml"],
+        imports: &[],
+        contents: &[(YAML_FILES, r"(?m)^-\s+hosts:\s*\S+")],
+    },
+    DetectionRule {
+        name: "Vagrant",
+        category: CATEGORY_IAC,
+        dependencies: &[],
+        files: &["**/Vagrantfile"],
+        imports: &[],
+        contents: &[],
+    },
+];
+
+pub struct InfrastructureConfig;
+
+impl InfrastructureConfig {
+    pub fn rules() -> &'static [DetectionRule] {
+        INFRASTRUCTURE_RULES
+    }
+}
@@ -1,197 +1,782 @@
-use crate::entity::Language;
+use crate::entity::{
+    CommentSyntax, File, Language, LanguageDefinition, LanguageKind, Result, TechScanError,
+};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::{HashMap, HashSet};
+
+/// Compile-time row of the built-in language table.
+#[derive(Debug, Clone, Copy)]
+struct LanguageDef {
+    name: &'static str,
+    exts: &'static [&'static str],
+    kind: LanguageKind,
+    comment: CommentDef,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CommentDef {
+    line: &'static [&'static str],
+    block: &'static [(&'static str, &'static str)],
+    nested: bool,
+    /// Whether block comments only open and close with a marker at the start of a line,
+    /// as Perl's POD and Ruby's `=begin`/`=end` do.
+    block_at_line_start: bool,
+}
+
+impl LanguageDef {
+    fn to_language(self) -> Language {
+        Language {
+            name: self.name.to_string(),
+            exts: self.exts.iter().map(|ext| ext.to_string()).collect(),
+            kind: self.kind,
+            comment: CommentSyntax {
+                line: self.comment.line.iter().map(|m| m.to_string()).collect(),
+                block: self
+                    .comment
+                    .block
+                    .iter()
+                    .map(|(start, end)| (start.to_string(), end.to_string()))
+                    .collect(),
+                nested: self.comment.nested,
+                block_at_line_start: self.comment.block_at_line_start,
+            },
+        }
+    }
+}
+
+const C_STYLE_COMMENT: CommentDef = CommentDef {
+    line: &["//"],
+    block: &[("/*", "*/")],
+    nested: false,
+    block_at_line_start: false,
+};
 
-const ASTRO: Language = Language {
+const C_STYLE_NESTED_COMMENT: CommentDef = CommentDef {
+    line: &["//"],
+    block: &[("/*", "*/")],
+    nested: true,
+    block_at_line_start: false,
+};
+
+const CSS_STYLE_COMMENT: CommentDef = CommentDef {
+    line: &[],
+    block: &[("/*", "*/")],
+    nested: false,
+    block_at_line_start: false,
+};
+
+const HASH_COMMENT: CommentDef = CommentDef {
+    line: &["#"],
+    block: &[],
+    nested: false,
+    block_at_line_start: false,
+};
+
+const HTML_STYLE_COMMENT: CommentDef = CommentDef {
+    line: &[],
+    block: &[("<!--", "-->")],
+    nested: false,
+    block_at_line_start: false,
+};
+
+const COMPONENT_COMMENT: CommentDef = CommentDef {
+    line: &["//"],
+    block: &[("<!--", "-->"), ("/*", "*/")],
+    nested: false,
+    block_at_line_start: false,
+};
+
+const ASTRO: LanguageDef = LanguageDef {
     name: "Astro",
     exts: &["astro"],
+    kind: LanguageKind::Markup,
+    comment: COMPONENT_COMMENT,
 };
 
-const C: Language = Language {
+const C: LanguageDef = LanguageDef {
     name: "C",
-    exts: &["c"],
+    exts: &["c", "h"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_COMMENT,
 };
 
-const COBOL: Language = Language {
+const CMAKE: LanguageDef = LanguageDef {
+    name: "CMake",
+    exts: &["cmake"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &["#"],
+        block: &[("#[[", "]]")],
+        nested: false,
+        block_at_line_start: false,
+    },
+};
+
+const COBOL: LanguageDef = LanguageDef {
     name: "COBOL",
     exts: &["cbl", "cob", "cobol"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &["*>"],
+        block: &[],
+        nested: false,
+        block_at_line_start: false,
+    },
 };
 
-const CPP: Language = Language {
+const CPP: LanguageDef = LanguageDef {
     name: "C++",
-    exts: &["cpp", "cc", "cxx"],
+    exts: &["cpp", "cc", "cxx", "hpp", "hh", "hxx"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_COMMENT,
 };
 
-const CSHARP: Language = Language {
+const CSHARP: LanguageDef = LanguageDef {
     name: "C#",
     exts: &["cs"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_COMMENT,
 };
 
-const CSS: Language = Language {
+const CSS: LanguageDef = LanguageDef {
     name: "CSS",
     exts: &["css"],
+    kind: LanguageKind::Style,
+    comment: CSS_STYLE_COMMENT,
 };
 
-const DART: Language = Language {
+const DART: LanguageDef = LanguageDef {
     name: "Dart",
     exts: &["dart"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_NESTED_COMMENT,
+};
+
+const DOCKERFILE: LanguageDef = LanguageDef {
+    name: "Dockerfile",
+    exts: &["dockerfile"],
+    kind: LanguageKind::Programming,
+    comment: HASH_COMMENT,
 };
 
-const ELIXIR: Language = Language {
+const ELIXIR: LanguageDef = LanguageDef {
     name: "Elixir",
     exts: &["ex", "exs"],
+    kind: LanguageKind::Programming,
+    comment: HASH_COMMENT,
 };
 
-const GO: Language = Language {
+const GO: LanguageDef = LanguageDef {
     name: "Go",
     exts: &["go"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_COMMENT,
 };
 
-const HASKELL: Language = Language {
+const GROOVY: LanguageDef = LanguageDef {
+    name: "Groovy",
+    exts: &["groovy", "gvy", "gradle"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_COMMENT,
+};
+
+const HASKELL: LanguageDef = LanguageDef {
     name: "Haskell",
     exts: &["hs"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &["--"],
+        block: &[("{-", "-}")],
+        nested: true,
+        block_at_line_start: false,
+    },
 };
 
-const HTML: Language = Language {
+const HTML: LanguageDef = LanguageDef {
     name: "HTML",
     exts: &["html", "htm"],
+    kind: LanguageKind::Markup,
+    comment: HTML_STYLE_COMMENT,
 };
 
-const JAVA: Language = Language {
+const INI: LanguageDef = LanguageDef {
+    name: "INI",
+    exts: &["ini", "cfg"],
+    kind: LanguageKind::Config,
+    comment: CommentDef {
+        line: &[";", "#"],
+        block: &[],
+        nested: false,
+        block_at_line_start: false,
+    },
+};
+
+const JAVA: LanguageDef = LanguageDef {
     name: "Java",
     exts: &["java"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_COMMENT,
 };
 
-const JAVASCRIPT: Language = Language {
+const JAVASCRIPT: LanguageDef = LanguageDef {
     name: "JavaScript",
     exts: &["js", "mjs", "cjs", "jsx"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_COMMENT,
+};
+
+const JSON: LanguageDef = LanguageDef {
+    name: "JSON",
+    exts: &["json", "jsonc", "json5", "geojson", "webmanifest"],
+    kind: LanguageKind::Data,
+    comment: C_STYLE_COMMENT,
 };
 
-const KOTLIN: Language = Language {
+const KOTLIN: LanguageDef = LanguageDef {
     name: "Kotlin",
     exts: &["kt", "kts"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_NESTED_COMMENT,
 };
 
-const LUA: Language = Language {
+const LUA: LanguageDef = LanguageDef {
     name: "Lua",
     exts: &["lua"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &["--"],
+        block: &[("--[[", "]]")],
+        nested: false,
+        block_at_line_start: false,
+    },
+};
+
+const MAKEFILE: LanguageDef = LanguageDef {
+    name: "Makefile",
+    exts: &["mk", "mak"],
+    kind: LanguageKind::Programming,
+    comment: HASH_COMMENT,
 };
 
-const OBJECTIVE_C: Language = Language {
+const MARKDOWN: LanguageDef = LanguageDef {
+    name: "Markdown",
+    exts: &["md", "markdown", "mdown", "mkd"],
+    kind: LanguageKind::Prose,
+    comment: HTML_STYLE_COMMENT,
+};
+
+const MATLAB: LanguageDef = LanguageDef {
+    name: "MATLAB",
+    exts: &["m"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &["%"],
+        block: &[("%{", "%}")],
+        nested: false,
+        block_at_line_start: false,
+    },
+};
+
+const MERCURY: LanguageDef = LanguageDef {
+    name: "Mercury",
+    exts: &["m", "moo"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &["%"],
+        block: &[("/*", "*/")],
+        nested: false,
+        block_at_line_start: false,
+    },
+};
+
+const OBJECTIVE_C: LanguageDef = LanguageDef {
     name: "Objective-C",
     exts: &["m", "mm"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_COMMENT,
 };
 
-const PERL: Language = Language {
+const PERL: LanguageDef = LanguageDef {
     name: "Perl",
     exts: &["pl", "pm"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &["#"],
+        block: &[("=*", "=cut")],
+        nested: false,
+        block_at_line_start: true,
+    },
 };
 
-const PHP: Language = Language {
+const PHP: LanguageDef = LanguageDef {
     name: "PHP",
     exts: &["php"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &["//", "#"],
+        block: &[("/*", "*/")],
+        nested: false,
+        block_at_line_start: false,
+    },
+};
+
+const PROLOG: LanguageDef = LanguageDef {
+    name: "Prolog",
+    exts: &["pl", "pro", "prolog"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &["%"],
+        block: &[("/*", "*/")],
+        nested: false,
+        block_at_line_start: false,
+    },
+};
+
+const PROTOCOL_BUFFER: LanguageDef = LanguageDef {
+    name: "Protocol Buffer",
+    exts: &["proto"],
+    kind: LanguageKind::Data,
+    comment: C_STYLE_COMMENT,
 };
 
-const PYTHON: Language = Language {
+const PYTHON: LanguageDef = LanguageDef {
     name: "Python",
     exts: &["py"],
+    kind: LanguageKind::Programming,
+    comment: HASH_COMMENT,
 };
 
-const R: Language = Language {
+const QT_TRANSLATION: LanguageDef = LanguageDef {
+    name: "Qt Translation",
+    exts: &["ts"],
+    kind: LanguageKind::Data,
+    comment: HTML_STYLE_COMMENT,
+};
+
+const R: LanguageDef = LanguageDef {
     name: "R",
     exts: &["r", "R"],
+    kind: LanguageKind::Programming,
+    comment: HASH_COMMENT,
+};
+
+const RESTRUCTURED_TEXT: LanguageDef = LanguageDef {
+    name: "reStructuredText",
+    exts: &["rst", "rest"],
+    kind: LanguageKind::Prose,
+    comment: CommentDef {
+        line: &[".."],
+        block: &[],
+        nested: false,
+        block_at_line_start: false,
+    },
+};
+
+const REBOL: LanguageDef = LanguageDef {
+    name: "Rebol",
+    exts: &["r", "reb", "rebol"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &[";"],
+        block: &[],
+        nested: false,
+        block_at_line_start: false,
+    },
 };
 
-const RUBY: Language = Language {
+const RUBY: LanguageDef = LanguageDef {
     name: "Ruby",
-    exts: &["rb"],
+    exts: &["rb", "rake", "gemspec"],
+    kind: LanguageKind::Programming,
+    comment: CommentDef {
+        line: &["#"],
+        block: &[("=begin", "=end")],
+        nested: false,
+        block_at_line_start: true,
+    },
 };
 
-const SCALA: Language = Language {
+const SCALA: LanguageDef = LanguageDef {
     name: "Scala",
     exts: &["scala", "sc"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_NESTED_COMMENT,
 };
 
-const RUST: Language = Language {
+const RUST: LanguageDef = LanguageDef {
     name: "Rust",
     exts: &["rs"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_NESTED_COMMENT,
 };
 
-const SCSS: Language = Language {
+const SCSS: LanguageDef = LanguageDef {
     name: "SCSS",
     exts: &["scss", "sass"],
+    kind: LanguageKind::Style,
+    comment: C_STYLE_COMMENT,
 };
 
-const SHELL: Language = Language {
+const SHELL: LanguageDef = LanguageDef {
     name: "Shell",
     exts: &["sh"],
+    kind: LanguageKind::Programming,
+    comment: HASH_COMMENT,
 };
 
-const SVELTE: Language = Language {
+const STARLARK: LanguageDef = LanguageDef {
+    name: "Starlark",
+    exts: &["bzl", "star"],
+    kind: LanguageKind::Programming,
+    comment: HASH_COMMENT,
+};
+
+const SVELTE: LanguageDef = LanguageDef {
     name: "Svelte",
     exts: &["svelte"],
+    kind: LanguageKind::Markup,
+    comment: COMPONENT_COMMENT,
 };
 
-const SWIFT: Language = Language {
+const SWIFT: LanguageDef = LanguageDef {
     name: "Swift",
     exts: &["swift"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_NESTED_COMMENT,
 };
 
-const TYPESCRIPT: Language = Language {
+const TOML: LanguageDef = LanguageDef {
+    name: "TOML",
+    exts: &["toml"],
+    kind: LanguageKind::Config,
+    comment: HASH_COMMENT,
+};
+
+const TYPESCRIPT: LanguageDef = LanguageDef {
     name: "TypeScript",
     exts: &["ts", "mts", "cts", "tsx"],
+    kind: LanguageKind::Programming,
+    comment: C_STYLE_COMMENT,
+};
+
+const XML: LanguageDef = LanguageDef {
+    name: "XML",
+    exts: &["xml", "xsd", "xsl", "xslt", "plist", "csproj", "svg"],
+    kind: LanguageKind::Data,
+    comment: HTML_STYLE_COMMENT,
+};
+
+const YAML: LanguageDef = LanguageDef {
+    name: "YAML",
+    exts: &["yaml", "yml"],
+    kind: LanguageKind::Config,
+    comment: HASH_COMMENT,
 };
 
-const VUE: Language = Language {
+const VUE: LanguageDef = LanguageDef {
     name: "Vue",
     exts: &["vue"],
+    kind: LanguageKind::Markup,
+    comment: COMPONENT_COMMENT,
 };
 
-const SUPPORTER_LANGUAGES: &[Language] = &[
+const SUPPORTED_LANGUAGES: &[LanguageDef] = &[
     ASTRO,
     C,
+    CMAKE,
     CPP,
     CSHARP,
     COBOL,
     CSS,
     DART,
+    DOCKERFILE,
     ELIXIR,
     GO,
+    GROOVY,
     HASKELL,
     HTML,
+    INI,
     JAVA,
     JAVASCRIPT,
+    JSON,
     KOTLIN,
     LUA,
+    MAKEFILE,
+    MARKDOWN,
     OBJECTIVE_C,
     PERL,
     PHP,
+    PROTOCOL_BUFFER,
     PYTHON,
     R,
+    RESTRUCTURED_TEXT,
     RUBY,
     RUST,
     SCALA,
     SCSS,
     SHELL,
+    STARLARK,
     SVELTE,
     SWIFT,
+    TOML,
     TYPESCRIPT,
     VUE,
+    XML,
+    YAML,
+    // Languages sharing an extension with a more common one above. The extension table
+    // returns the first match, so files with a shared extension only reach these
+    // through the content heuristics.
+    MATLAB,
+    MERCURY,
+    PROLOG,
+    QT_TRANSLATION,
+    REBOL,
 ];
 
-pub struct LanguageConfig;
+/// File name patterns for files that are identified by name rather than extension.
+/// Patterns are globs matched against the file name only, checked in order.
+const SUPPORTED_FILENAMES: &[(&str, LanguageDef)] = &[
+    ("CMakeLists.txt", CMAKE),
+    ("Containerfile", DOCKERFILE),
+    ("Dockerfile", DOCKERFILE),
+    ("Dockerfile.*", DOCKERFILE),
+    ("*.Dockerfile", DOCKERFILE),
+    ("Jenkinsfile", GROOVY),
+    ("Jenkinsfile.*", GROOVY),
+    ("GNUmakefile", MAKEFILE),
+    ("Makefile", MAKEFILE),
+    ("makefile", MAKEFILE),
+    ("Makefile.*", MAKEFILE),
+    (".editorconfig", INI),
+    (".gitconfig", INI),
+    ("Pipfile", TOML),
+    ("Brewfile", RUBY),
+    ("Gemfile", RUBY),
+    ("Guardfile", RUBY),
+    ("Podfile", RUBY),
+    ("Rakefile", RUBY),
+    ("Vagrantfile", RUBY),
+    (".bash_profile", SHELL),
+    (".bashrc", SHELL),
+    (".profile", SHELL),
+    (".zprofile", SHELL),
+    (".zshrc", SHELL),
+    ("BUILD", STARLARK),
+    ("BUILD.bazel", STARLARK),
+    ("MODULE.bazel", STARLARK),
+    ("WORKSPACE", STARLARK),
+    ("WORKSPACE.bazel", STARLARK),
+    ("Tiltfile", STARLARK),
+];
+
+/// Interpreter names found in shebangs, and file type names found in Vim/Emacs modelines.
+/// Version suffixes such as `python3.12` are stripped before lookup.
+const SUPPORTED_INTERPRETERS: &[(&str, LanguageDef)] = &[
+    ("bash", SHELL),
+    ("dash", SHELL),
+    ("ksh", SHELL),
+    ("sh", SHELL),
+    ("shell-script", SHELL),
+    ("zsh", SHELL),
+    ("cperl", PERL),
+    ("perl", PERL),
+    ("python", PYTHON),
+    ("ruby", RUBY),
+    ("node", JAVASCRIPT),
+    ("nodejs", JAVASCRIPT),
+    ("javascript", JAVASCRIPT),
+    ("js", JAVASCRIPT),
+    ("deno", TYPESCRIPT),
+    ("ts-node", TYPESCRIPT),
+    ("typescript", TYPESCRIPT),
+    ("php", PHP),
+    ("lua", LUA),
+    ("Rscript", R),
+    ("elixir", ELIXIR),
+    ("groovy", GROOVY),
+    ("make", MAKEFILE),
+    ("runghc", HASKELL),
+    ("runhaskell", HASKELL),
+    ("scala", SCALA),
+    ("swift", SWIFT),
+];
+
+/// The language table used for detection: the built-in languages merged with the
+/// `languages` definitions of the config file.
+#[derive(Debug)]
+pub struct LanguageConfig {
+    languages: Vec<Language>,
+    ext_index: HashMap<String, usize>,
+    filename_languages: Vec<usize>,
+    filename_glob_set: GlobSet,
+    overridden_exts: HashSet<String>,
+}
+
+impl Default for LanguageConfig {
+    fn default() -> Self {
+        Self::new(&[]).expect("built-in language table must be valid")
+    }
+}
 
 impl LanguageConfig {
-    pub fn detect_language(ext: &str) -> Option<Language> {
-        SUPPORTER_LANGUAGES
+    pub fn new(definitions: &[LanguageDefinition]) -> Result<Self> {
+        let mut languages: Vec<Language> = SUPPORTED_LANGUAGES
+            .iter()
+            .map(|def| def.to_language())
+            .collect();
+        let mut user_filenames: Vec<(String, String)> = Vec::new();
+        let mut overridden_exts = HashSet::new();
+
+        for definition in definitions {
+            if definition.name.trim().is_empty() {
+                return Err(TechScanError::ValidationError(
+                    "Language name must not be empty".to_string(),
+                ));
+            }
+
+            if definition.disabled {
+                languages.retain(|lang| lang.name != definition.name);
+                continue;
+            }
+
+            let exts: Vec<String> = definition
+                .exts
+                .iter()
+                .map(|ext| ext.trim_start_matches('.').to_string())
+                .collect();
+
+            for lang in languages.iter_mut().filter(|l| l.name != definition.name) {
+                lang.exts
+                    .retain(|ext| !exts.iter().any(|e| e.eq_ignore_ascii_case(ext)));
+            }
+
+            let index = match languages.iter().position(|l| l.name == definition.name) {
+                Some(index) => index,
+                None => {
+                    languages.push(Language::new(&definition.name, &[]));
+                    languages.len() - 1
+                }
+            };
+            let lang = &mut languages[index];
+
+            for ext in exts {
+                // Compound extensions such as `tmpl.go` cannot be looked up by the last
+                // extension, so they are matched as file name patterns instead.
+                if ext.contains('.') {
+                    user_filenames.push((format!("*.{}", ext), definition.name.clone()));
+                }
+                overridden_exts.insert(ext.to_lowercase());
+                if !lang.exts.contains(&ext) {
+                    lang.exts.push(ext);
+                }
+            }
+
+            if let Some(kind) = definition.kind {
+                lang.kind = kind;
+            }
+            if let Some(line) = &definition.line_comments {
+                lang.comment.line = line.clone();
+            }
+            if let Some(block) = &definition.block_comments {
+                lang.comment.block = block.clone();
+            }
+            if let Some(nested) = definition.nested_comments {
+                lang.comment.nested = nested;
+            }
+            if let Some(at_line_start) = definition.block_comments_at_line_start {
+                lang.comment.block_at_line_start = at_line_start;
+            }
+
+            for pattern in &definition.filenames {
+                user_filenames.push((pattern.clone(), definition.name.clone()));
+            }
+        }
+
+        let mut ext_index = HashMap::new();
+        for (index, lang) in languages.iter().enumerate() {
+            for ext in &lang.exts {
+                ext_index.entry(ext.to_lowercase()).or_insert(index);
+            }
+        }
+
+        // User patterns come first so that they win over the built-in ones.
+        let filename_patterns = user_filenames.into_iter().chain(
+            SUPPORTED_FILENAMES
+                .iter()
+                .map(|(pattern, def)| (pattern.to_string(), def.name.to_string())),
+        );
+
+        let mut glob_builder = GlobSetBuilder::new();
+        let mut filename_languages = Vec::new();
+        for (pattern, name) in filename_patterns {
+            let Some(index) = languages.iter().position(|l| l.name == name) else {
+                continue;
+            };
+            let glob = Glob::new(&pattern).map_err(|e| {
+                TechScanError::ValidationError(format!(
+                    "Invalid filename pattern '{}': {}",
+                    pattern, e
+                ))
+            })?;
+            glob_builder.add(glob);
+            filename_languages.push(index);
+        }
+        let filename_glob_set = glob_builder.build().map_err(|e| {
+            TechScanError::ValidationError(format!("Failed to build filename patterns: {}", e))
+        })?;
+
+        Ok(Self {
+            languages,
+            ext_index,
+            filename_languages,
+            filename_glob_set,
+            overridden_exts,
+        })
+    }
+
+    pub fn detect_language(&self, ext: &str) -> Option<&Language> {
+        self.ext_index.get(ext).map(|index| &self.languages[*index])
+    }
+
+    pub fn detect_language_by_filename(&self, filename: &str) -> Option<&Language> {
+        self.filename_glob_set
+            .matches(filename)
+            .into_iter()
+            .min()
+            .map(|index| &self.languages[self.filename_languages[index]])
+    }
+
+    /// Detects the language of a file from its name, then from its extension.
+    pub fn detect_file_language(&self, file: &File) -> Option<&Language> {
+        self.detect_language_by_filename(&file.name).or_else(|| {
+            file.ext
+                .as_ref()
+                .and_then(|ext| self.detect_language(&ext.to_lowercase()))
+        })
+    }
+
+    pub fn detect_language_by_interpreter(&self, interpreter: &str) -> Option<&Language> {
+        let name = interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+        SUPPORTED_INTERPRETERS
             .iter()
-            .find(|lang| lang.exts.contains(&ext))
-            .copied()
+            .find(|(interpreter_name, _)| *interpreter_name == name)
+            .and_then(|(_, def)| self.get_language_by_name(def.name))
     }
 
-    pub fn get_language_by_name(name: &str) -> Option<Language> {
-        SUPPORTER_LANGUAGES
+    pub fn get_language_by_name(&self, name: &str) -> Option<&Language> {
+        self.languages.iter().find(|lang| lang.name == name)
+    }
+
+    pub fn get_language_by_name_ignore_case(&self, name: &str) -> Option<&Language> {
+        self.languages
             .iter()
-            .find(|lang| lang.name == name)
-            .copied()
+            .find(|lang| lang.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns whether `ext` was assigned to a language by the config file, in which case
+    /// it is not subject to content heuristics.
+    pub fn is_overridden_ext(&self, ext: &str) -> bool {
+        self.overridden_exts.contains(ext)
     }
 }
 
@@ -204,16 +789,36 @@ mod tests {
 
         #[test]
         fn should_detect_rust() {
-            let result = LanguageConfig::detect_language("rs");
+            let config = LanguageConfig::default();
+            let result = config.detect_language("rs");
             assert!(result.is_some());
             assert_eq!(result.unwrap().name, "Rust");
         }
 
+        #[test]
+        fn should_detect_data_and_config_languages() {
+            let config = LanguageConfig::default();
+            for (ext, name, kind) in [
+                ("json", "JSON", LanguageKind::Data),
+                ("xml", "XML", LanguageKind::Data),
+                ("proto", "Protocol Buffer", LanguageKind::Data),
+                ("yml", "YAML", LanguageKind::Config),
+                ("toml", "TOML", LanguageKind::Config),
+                ("ini", "INI", LanguageKind::Config),
+                ("md", "Markdown", LanguageKind::Prose),
+            ] {
+                let result = config.detect_language(ext).unwrap();
+                assert_eq!(result.name, name);
+                assert_eq!(result.kind, kind);
+            }
+        }
+
         #[test]
         fn should_detect_multiple_variants() {
             let extensions = ["ts", "mts", "cts", "tsx"];
+            let config = LanguageConfig::default();
             for ext in extensions {
-                let result = LanguageConfig::detect_language(ext);
+                let result = config.detect_language(ext);
                 assert!(result.is_some());
                 assert_eq!(result.unwrap().name, "TypeScript");
             }
@@ -221,30 +826,237 @@ mod tests {
 
         #[test]
         fn should_return_none_for_unsupported_extension() {
-            let result = LanguageConfig::detect_language("xyz");
+            let config = LanguageConfig::default();
+            let result = config.detect_language("xyz");
             assert!(result.is_none());
         }
 
         #[test]
         fn should_return_none_for_empty_extension() {
-            let result = LanguageConfig::detect_language("");
+            let config = LanguageConfig::default();
+            let result = config.detect_language("");
+            assert!(result.is_none());
+        }
+    }
+
+    mod detect_language_by_filename {
+        use super::*;
+
+        #[test]
+        fn should_detect_build_files() {
+            let cases = [
+                ("Dockerfile", "Dockerfile"),
+                ("Dockerfile.dev", "Dockerfile"),
+                ("api.Dockerfile", "Dockerfile"),
+                ("Makefile", "Makefile"),
+                ("Jenkinsfile", "Groovy"),
+                ("Gemfile", "Ruby"),
+                ("Rakefile", "Ruby"),
+                ("CMakeLists.txt", "CMake"),
+                ("BUILD.bazel", "Starlark"),
+                ("WORKSPACE", "Starlark"),
+            ];
+            let config = LanguageConfig::default();
+            for (filename, expected) in cases {
+                let result = config.detect_language_by_filename(filename);
+                assert!(result.is_some(), "{} should be detected", filename);
+                assert_eq!(result.unwrap().name, expected);
+            }
+        }
+
+        #[test]
+        fn should_be_case_sensitive() {
+            let config = LanguageConfig::default();
+            let result = config.detect_language_by_filename("GEMFILE");
+            assert!(result.is_none());
+        }
+
+        #[test]
+        fn should_return_none_for_unknown_filename() {
+            let config = LanguageConfig::default();
+            let result = config.detect_language_by_filename("README");
+            assert!(result.is_none());
+        }
+    }
+
+    mod detect_language_by_interpreter {
+        use super::*;
+
+        #[test]
+        fn should_detect_interpreters() {
+            let cases = [
+                ("python3", "Python"),
+                ("python3.12", "Python"),
+                ("node", "JavaScript"),
+                ("ruby", "Ruby"),
+                ("bash", "Shell"),
+                ("zsh", "Shell"),
+                ("perl", "Perl"),
+            ];
+            let config = LanguageConfig::default();
+            for (interpreter, expected) in cases {
+                let result = config.detect_language_by_interpreter(interpreter);
+                assert!(result.is_some(), "{} should be detected", interpreter);
+                assert_eq!(result.unwrap().name, expected);
+            }
+        }
+
+        #[test]
+        fn should_return_none_for_unknown_interpreter() {
+            let config = LanguageConfig::default();
+            let result = config.detect_language_by_interpreter("awk");
             assert!(result.is_none());
         }
     }
 
+    mod new {
+        use super::*;
+
+        #[test]
+        fn should_add_new_language() {
+            let definitions = vec![LanguageDefinition {
+                name: "PDL".to_string(),
+                exts: vec!["pdl".to_string()],
+                line_comments: Some(vec!["#".to_string()]),
+                ..Default::default()
+            }];
+            let config = LanguageConfig::new(&definitions).unwrap();
+
+            let result = config.detect_language("pdl").unwrap();
+            assert_eq!(result.name, "PDL");
+            assert_eq!(result.comment.line, vec!["#".to_string()]);
+        }
+
+        #[test]
+        fn should_set_language_kind() {
+            let definitions = vec![
+                LanguageDefinition {
+                    name: "PDL".to_string(),
+                    exts: vec!["pdl".to_string()],
+                    ..Default::default()
+                },
+                LanguageDefinition {
+                    name: "HCL".to_string(),
+                    exts: vec!["hcl".to_string()],
+                    kind: Some(LanguageKind::Config),
+                    ..Default::default()
+                },
+            ];
+            let config = LanguageConfig::new(&definitions).unwrap();
+
+            assert_eq!(
+                config.detect_language("pdl").unwrap().kind,
+                LanguageKind::Programming
+            );
+            assert_eq!(
+                config.detect_language("hcl").unwrap().kind,
+                LanguageKind::Config
+            );
+        }
+
+        #[test]
+        fn should_remap_extension_to_existing_language() {
+            let definitions = vec![LanguageDefinition {
+                name: "C++".to_string(),
+                exts: vec!["h".to_string()],
+                ..Default::default()
+            }];
+            let config = LanguageConfig::new(&definitions).unwrap();
+
+            assert_eq!(config.detect_language("h").unwrap().name, "C++");
+            assert!(!config
+                .get_language_by_name("C")
+                .unwrap()
+                .exts
+                .contains(&"h".to_string()));
+            assert!(config.is_overridden_ext("h"));
+            assert!(!config.is_overridden_ext("c"));
+        }
+
+        #[test]
+        fn should_match_compound_extension_by_filename() {
+            let definitions = vec![LanguageDefinition {
+                name: "Go Template".to_string(),
+                exts: vec![".tmpl.go".to_string()],
+                ..Default::default()
+            }];
+            let config = LanguageConfig::new(&definitions).unwrap();
+
+            let result = config.detect_language_by_filename("page.tmpl.go").unwrap();
+            assert_eq!(result.name, "Go Template");
+            assert_eq!(config.detect_language("go").unwrap().name, "Go");
+        }
+
+        #[test]
+        fn should_add_filename_patterns() {
+            let definitions = vec![LanguageDefinition {
+                name: "Ruby".to_string(),
+                filenames: vec!["Fastfile".to_string()],
+                ..Default::default()
+            }];
+            let config = LanguageConfig::new(&definitions).unwrap();
+
+            let result = config.detect_language_by_filename("Fastfile").unwrap();
+            assert_eq!(result.name, "Ruby");
+        }
+
+        #[test]
+        fn should_disable_builtin_language() {
+            let definitions = vec![LanguageDefinition {
+                name: "COBOL".to_string(),
+                disabled: true,
+                ..Default::default()
+            }];
+            let config = LanguageConfig::new(&definitions).unwrap();
+
+            assert!(config.get_language_by_name("COBOL").is_none());
+            assert!(config.detect_language("cbl").is_none());
+        }
+
+        #[test]
+        fn should_reject_empty_name() {
+            let definitions = vec![LanguageDefinition::default()];
+            let result = LanguageConfig::new(&definitions);
+
+            assert!(matches!(result, Err(TechScanError::ValidationError(_))));
+        }
+
+        #[test]
+        fn should_reject_invalid_filename_pattern() {
+            let definitions = vec![LanguageDefinition {
+                name: "Broken".to_string(),
+                filenames: vec!["[".to_string()],
+                ..Default::default()
+            }];
+            let result = LanguageConfig::new(&definitions);
+
+            assert!(matches!(result, Err(TechScanError::ValidationError(_))));
+        }
+    }
+
     mod get_language_by_name {
         use super::*;
 
         #[test]
         fn should_return_language_when_found() {
-            let result = LanguageConfig::get_language_by_name("Rust");
+            let config = LanguageConfig::default();
+            let result = config.get_language_by_name("Rust");
             assert!(result.is_some());
             assert_eq!(result.unwrap().name, "Rust");
         }
 
+        #[test]
+        fn should_find_language_ignoring_case() {
+            let config = LanguageConfig::default();
+            let result = config.get_language_by_name_ignore_case("javascript");
+            assert_eq!(result.unwrap().name, "JavaScript");
+            assert!(config.get_language_by_name("javascript").is_none());
+        }
+
         #[test]
         fn should_return_none_when_not_found() {
-            let result = LanguageConfig::get_language_by_name("UnknownLanguage");
+            let config = LanguageConfig::default();
+            let result = config.get_language_by_name("UnknownLanguage");
             assert!(result.is_none());
         }
     }
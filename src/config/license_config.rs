@@ -0,0 +1,57 @@
+/// License texts bundled for offline matching, keyed by SPDX identifier. The GPL family is
+/// identified without the `-only`/`-or-later` suffix, since the text is the same.
+const LICENSE_TEXTS: &[(&str, &str)] = &[
+    ("MIT", include_str!("licenses/MIT.license")),
+    ("Apache-2.0", include_str!("licenses/Apache-2.0.license")),
+    (
+        "BSD-2-Clause",
+        include_str!("licenses/BSD-2-Clause.license"),
+    ),
+    (
+        "BSD-3-Clause",
+        include_str!("licenses/BSD-3-Clause.license"),
+    ),
+    ("ISC", include_str!("licenses/ISC.license")),
+    ("MPL-2.0", include_str!("licenses/MPL-2.0.license")),
+    ("GPL-2.0", include_str!("licenses/GPL-2.0.license")),
+    ("GPL-3.0", include_str!("licenses/GPL-3.0.license")),
+    ("LGPL-2.1", include_str!("licenses/LGPL-2.1.license")),
+    ("LGPL-3.0", include_str!("licenses/LGPL-3.0.license")),
+    ("Unlicense", include_str!("licenses/Unlicense.license")),
+    ("CC0-1.0", include_str!("licenses/CC0-1.0.license")),
+];
+
+/// Licenses whose terms do not extend to the work they are combined with: permissive
+/// licenses, and weak copyleft licenses that only cover the files or library they apply to.
+/// Lower-cased, without the `-only`/`-or-later` suffixes.
+const NON_RECIPROCAL_LICENSES: &[&str] = &[
+    "0bsd",
+    "apache-2.0",
+    "bsd-2-clause",
+    "bsd-3-clause",
+    "bsl-1.0",
+    "cc0-1.0",
+    "isc",
+    "mit",
+    "mit-0",
+    "unlicense",
+    "zlib",
+    "lgpl-2.1",
+    "lgpl-3.0",
+    "mpl-2.0",
+    "epl-2.0",
+];
+
+pub struct LicenseConfig;
+
+impl LicenseConfig {
+    pub fn texts() -> &'static [(&'static str, &'static str)] {
+        LICENSE_TEXTS
+    }
+
+    /// Whether code under the license `base_id` can be part of a project under any license.
+    /// Strong copyleft licenses, such as the GPL, and unknown licenses cannot.
+    pub fn is_non_reciprocal(base_id: &str) -> bool {
+        NON_RECIPROCAL_LICENSES.contains(&base_id)
+    }
+}
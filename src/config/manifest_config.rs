@@ -0,0 +1,112 @@
+use crate::entity::{LockfileFormat, ManifestFormat};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::sync::OnceLock;
+
+/// File name patterns of the supported package manifests, matched against the file name.
+const SUPPORTED_MANIFESTS: &[(&str, ManifestFormat)] = &[
+    ("package.json", ManifestFormat::PackageJson),
+    ("Cargo.toml", ManifestFormat::CargoToml),
+    ("go.mod", ManifestFormat::GoMod),
+    ("requirements*.txt", ManifestFormat::Requirements),
+    ("pyproject.toml", ManifestFormat::Pyproject),
+    ("Gemfile", ManifestFormat::Gemfile),
+    ("composer.json", ManifestFormat::ComposerJson),
+    ("pom.xml", ManifestFormat::PomXml),
+    ("build.gradle", ManifestFormat::Gradle),
+    ("build.gradle.kts", ManifestFormat::Gradle),
+    ("pubspec.yaml", ManifestFormat::Pubspec),
+    ("mix.exs", ManifestFormat::MixExs),
+];
+
+/// File names of the supported lockfiles.
+const SUPPORTED_LOCKFILES: &[(&str, LockfileFormat)] = &[
+    ("package-lock.json", LockfileFormat::PackageLock),
+    ("npm-shrinkwrap.json", LockfileFormat::PackageLock),
+    ("yarn.lock", LockfileFormat::YarnLock),
+    ("pnpm-lock.yaml", LockfileFormat::PnpmLock),
+    ("Cargo.lock", LockfileFormat::CargoLock),
+    ("go.sum", LockfileFormat::GoSum),
+    ("poetry.lock", LockfileFormat::PoetryLock),
+    ("Gemfile.lock", LockfileFormat::GemfileLock),
+    ("composer.lock", LockfileFormat::ComposerLock),
+];
+
+fn manifest_glob_set() -> &'static GlobSet {
+    static GLOB_SET: OnceLock<GlobSet> = OnceLock::new();
+    GLOB_SET.get_or_init(|| {
+        let mut builder = GlobSetBuilder::new();
+        for (pattern, _) in SUPPORTED_MANIFESTS {
+            builder.add(Glob::new(pattern).expect("built-in manifest globs must be valid"));
+        }
+        builder
+            .build()
+            .expect("built-in manifest globs must be valid")
+    })
+}
+
+pub struct ManifestConfig;
+
+impl ManifestConfig {
+    pub fn detect_format(file_name: &str) -> Option<ManifestFormat> {
+        manifest_glob_set()
+            .matches(file_name)
+            .first()
+            .map(|&index| SUPPORTED_MANIFESTS[index].1)
+    }
+
+    pub fn detect_lockfile_format(file_name: &str) -> Option<LockfileFormat> {
+        SUPPORTED_LOCKFILES
+            .iter()
+            .find(|(name, _)| *name == file_name)
+            .map(|(_, format)| *format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod detect_format {
+        use super::*;
+
+        #[test]
+        fn test_detect_supported_manifests() {
+            assert_eq!(
+                ManifestConfig::detect_format("package.json"),
+                Some(ManifestFormat::PackageJson)
+            );
+            assert_eq!(
+                ManifestConfig::detect_format("requirements-dev.txt"),
+                Some(ManifestFormat::Requirements)
+            );
+            assert_eq!(
+                ManifestConfig::detect_format("build.gradle.kts"),
+                Some(ManifestFormat::Gradle)
+            );
+        }
+
+        #[test]
+        fn test_detect_unsupported_files() {
+            assert_eq!(ManifestConfig::detect_format("package-lock.json"), None);
+            assert_eq!(ManifestConfig::detect_format("Gemfile.lock"), None);
+            assert_eq!(ManifestConfig::detect_format("notes.txt"), None);
+        }
+    }
+
+    mod detect_lockfile_format {
+        use super::*;
+
+        #[test]
+        fn test_detect_supported_lockfiles() {
+            assert_eq!(
+                ManifestConfig::detect_lockfile_format("yarn.lock"),
+                Some(LockfileFormat::YarnLock)
+            );
+            assert_eq!(
+                ManifestConfig::detect_lockfile_format("Gemfile.lock"),
+                Some(LockfileFormat::GemfileLock)
+            );
+            assert_eq!(ManifestConfig::detect_lockfile_format("package.json"), None);
+        }
+    }
+}
@@ -1,5 +1,28 @@
+pub mod classification_config;
+pub mod datastore_config;
+pub mod framework_config;
+pub mod heuristic_config;
+pub mod infrastructure_config;
 pub mod language_config;
+pub mod license_config;
+pub mod manifest_config;
 pub mod reporter_format;
+pub mod runtime_config;
+pub mod sfc_config;
+pub mod tooling_config;
 
+pub use classification_config::ClassificationConfig;
+pub use datastore_config::DatastoreConfig;
+pub use framework_config::FrameworkConfig;
+pub use heuristic_config::HeuristicConfig;
+pub use infrastructure_config::InfrastructureConfig;
 pub use language_config::LanguageConfig;
-pub use reporter_format::{REPORTER_FORMAT_JSON, REPORTER_FORMAT_TABLE};
+pub use license_config::LicenseConfig;
+pub use manifest_config::ManifestConfig;
+pub use reporter_format::{
+    REPORTER_FORMAT_CSV, REPORTER_FORMAT_CYCLONEDX, REPORTER_FORMAT_JSON, REPORTER_FORMAT_MARKDOWN,
+    REPORTER_FORMAT_SPDX, REPORTER_FORMAT_TABLE, REPORTER_FORMAT_TSV,
+};
+pub use runtime_config::RuntimeConfig;
+pub use sfc_config::SfcConfig;
+pub use tooling_config::ToolingConfig;
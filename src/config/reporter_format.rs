@@ -1,2 +1,7 @@
 pub const REPORTER_FORMAT_JSON: &str = "json";
 pub const REPORTER_FORMAT_TABLE: &str = "table";
+pub const REPORTER_FORMAT_CYCLONEDX: &str = "cyclonedx";
+pub const REPORTER_FORMAT_SPDX: &str = "spdx";
+pub const REPORTER_FORMAT_CSV: &str = "csv";
+pub const REPORTER_FORMAT_TSV: &str = "tsv";
+pub const REPORTER_FORMAT_MARKDOWN: &str = "markdown";
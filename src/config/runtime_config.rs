@@ -0,0 +1,47 @@
+/// Official base images and the runtime they provide. Images are matched without their
+/// registry and `library/` prefix.
+const DOCKER_IMAGES: &[(&str, &str)] = &[
+    ("node", "node"),
+    ("python", "python"),
+    ("ruby", "ruby"),
+    ("golang", "go"),
+    ("rust", "rust"),
+    ("openjdk", "java"),
+    ("eclipse-temurin", "java"),
+    ("amazoncorretto", "java"),
+    ("php", "php"),
+    ("elixir", "elixir"),
+    ("erlang", "erlang"),
+    ("dart", "dart"),
+    ("swift", "swift"),
+    ("denoland/deno", "deno"),
+    ("oven/bun", "bun"),
+    ("dotnet/sdk", "dotnet"),
+    ("dotnet/aspnet", "dotnet"),
+    ("dotnet/runtime", "dotnet"),
+];
+
+/// asdf and mise plugin names that differ from the runtime name used in reports.
+const TOOL_ALIASES: &[(&str, &str)] = &[("nodejs", "node"), ("golang", "go")];
+
+pub struct RuntimeConfig;
+
+impl RuntimeConfig {
+    pub fn runtime_for_image(image: &str) -> Option<&'static str> {
+        let image = image
+            .trim_start_matches("docker.io/")
+            .trim_start_matches("mcr.microsoft.com/")
+            .trim_start_matches("library/");
+        DOCKER_IMAGES
+            .iter()
+            .find(|(name, _)| *name == image)
+            .map(|(_, runtime)| *runtime)
+    }
+
+    pub fn runtime_for_tool(tool: &str) -> &str {
+        TOOL_ALIASES
+            .iter()
+            .find(|(name, _)| *name == tool)
+            .map_or(tool, |(_, runtime)| runtime)
+    }
+}
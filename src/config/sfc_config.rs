@@ -0,0 +1,40 @@
+use crate::entity::SfcSyntax;
+
+const ASTRO: SfcSyntax = SfcSyntax {
+    language: "Astro",
+    script_lang: "ts",
+    style_lang: "css",
+    template_lang: None,
+    frontmatter_lang: Some("ts"),
+    markup_lang: Some("html"),
+};
+
+const SVELTE: SfcSyntax = SfcSyntax {
+    language: "Svelte",
+    script_lang: "js",
+    style_lang: "css",
+    template_lang: None,
+    frontmatter_lang: None,
+    markup_lang: Some("html"),
+};
+
+const VUE: SfcSyntax = SfcSyntax {
+    language: "Vue",
+    script_lang: "js",
+    style_lang: "css",
+    template_lang: Some("html"),
+    frontmatter_lang: None,
+    markup_lang: None,
+};
+
+const SUPPORTED_SFC_SYNTAXES: &[SfcSyntax] = &[ASTRO, SVELTE, VUE];
+
+pub struct SfcConfig;
+
+impl SfcConfig {
+    pub fn find(language: &str) -> Option<&'static SfcSyntax> {
+        SUPPORTED_SFC_SYNTAXES
+            .iter()
+            .find(|syntax| syntax.language == language)
+    }
+}
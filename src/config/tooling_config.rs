@@ -0,0 +1,157 @@
+use crate::entity::{DetectionRule, Ecosystem};
+
+const CATEGORY_TEST: &str = "test";
+const CATEGORY_LINT: &str = "lint";
+const CATEGORY_FORMAT: &str = "format";
+const CATEGORY_EDITOR: &str = "editor";
+
+const PYPROJECT: &str = "**/pyproject.toml";
+
+const TOOLING_RULES: &[DetectionRule] = &[
+    DetectionRule {
+        name: "Jest",
+        category: CATEGORY_TEST,
+        dependencies: &[(Ecosystem::Npm, "jest")],
+        files: &["**/jest.config.{js,cjs,mjs,ts,json}"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Vitest",
+        category: CATEGORY_TEST,
+        dependencies: &[(Ecosystem::Npm, "vitest")],
+        files: &["**/vitest.config.{js,mjs,ts,mts}", "**/vitest.workspace.*"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Mocha",
+        category: CATEGORY_TEST,
+        dependencies: &[(Ecosystem::Npm, "mocha")],
+        files: &["**/.mocharc.{js,cjs,json,jsonc,yml,yaml}"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "pytest",
+        category: CATEGORY_TEST,
+        dependencies: &[(Ecosystem::Pypi, "pytest")],
+        files: &["**/pytest.ini", "**/conftest.py"],
+        imports: &[],
+        contents: &[
+            (PYPROJECT, r"(?m)^\[tool\.pytest\.ini_options\]"),
+            ("**/{setup.cfg,tox.ini}", r"(?m)^\[(?:tool:)?pytest\]"),
+        ],
+    },
+    DetectionRule {
+        name: "RSpec",
+        category: CATEGORY_TEST,
+        dependencies: &[(Ecosystem::Rubygems, "rspec*")],
+        files: &["**/.rspec", "**/spec/spec_helper.rb"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "JUnit",
+        category: CATEGORY_TEST,
+        dependencies: &[
+            (Ecosystem::Maven, "junit:junit"),
+            (Ecosystem::Maven, "org.junit.jupiter:*"),
+            (Ecosystem::Maven, "org.junit:*"),
+        ],
+        files: &[],
+        imports: &[(
+            Ecosystem::Maven,
+            r"(?m)^\s*import\s+(?:static\s+)?org\.junit\.",
+        )],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Go test",
+        category: CATEGORY_TEST,
+        dependencies: &[],
+        files: &["**/*_test.go"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "ESLint",
+        category: CATEGORY_LINT,
+        dependencies: &[(Ecosystem::Npm, "eslint")],
+        files: &["**/.eslintrc", "**/.eslintrc.*", "**/eslint.config.*"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Biome",
+        category: CATEGORY_LINT,
+        dependencies: &[(Ecosystem::Npm, "@biomejs/biome")],
+        files: &["**/biome.json", "**/biome.jsonc"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "RuboCop",
+        category: CATEGORY_LINT,
+        dependencies: &[(Ecosystem::Rubygems, "rubocop*")],
+        files: &["**/.rubocop.yml"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Clippy",
+        category: CATEGORY_LINT,
+        dependencies: &[],
+        files: &["**/clippy.toml", "**/.clippy.toml"],
+        imports: &[],
+        contents: &[("**/Cargo.toml", r"(?m)^\[(?:workspace\.)?lints\.clippy\]")],
+    },
+    DetectionRule {
+        name: "golangci-lint",
+        category: CATEGORY_LINT,
+        dependencies: &[],
+        files: &["**/.golangci.{yml,yaml,toml,json}"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Ruff",
+        category: CATEGORY_LINT,
+        dependencies: &[(Ecosystem::Pypi, "ruff")],
+        files: &["**/ruff.toml", "**/.ruff.toml"],
+        imports: &[],
+        contents: &[(PYPROJECT, r"(?m)^\[tool\.ruff\b")],
+    },
+    DetectionRule {
+        name: "Prettier",
+        category: CATEGORY_FORMAT,
+        dependencies: &[(Ecosystem::Npm, "prettier")],
+        files: &["**/.prettierrc", "**/.prettierrc.*", "**/prettier.config.*"],
+        imports: &[],
+        contents: &[],
+    },
+    DetectionRule {
+        name: "Black",
+        category: CATEGORY_FORMAT,
+        dependencies: &[(Ecosystem::Pypi, "black")],
+        files: &[],
+        imports: &[],
+        contents: &[(PYPROJECT, r"(?m)^\[tool\.black\]")],
+    },
+    DetectionRule {
+        name: "EditorConfig",
+        category: CATEGORY_EDITOR,
+        dependencies: &[],
+        files: &["**/.editorconfig"],
+        imports: &[],
+        contents: &[],
+    },
+];
+
+pub struct ToolingConfig;
+
+impl ToolingConfig {
+    pub fn rules() -> &'static [DetectionRule] {
+        TOOLING_RULES
+    }
+}
@@ -0,0 +1,183 @@
+//! Reporters replaced by the `Reporter` implementations of each format, kept so code written
+//! against earlier releases still builds. They only live in the library, as the binary
+//! selects formats through a `ReporterRegistry`.
+
+use crate::entity::{DependencyReport, LanguageReport, LicenseReport, StackReport};
+use crate::service::ReporterRegistry;
+use std::io::{self, Write};
+
+#[deprecated(
+    since = "1.1.0",
+    note = "use `ReporterRegistry::<LanguageReport>::new()` instead"
+)]
+pub struct LanguageReporter;
+
+#[allow(deprecated)]
+impl Default for LanguageReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(deprecated)]
+impl LanguageReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn validate_format(format: &str) -> io::Result<()> {
+        ReporterRegistry::<LanguageReport>::new()
+            .validate_format(format)
+            .map(|_| ())
+    }
+
+    pub fn output(
+        &self,
+        report: &LanguageReport,
+        format: &str,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        ReporterRegistry::new().output(report, format, writer)
+    }
+}
+
+#[deprecated(
+    since = "1.1.0",
+    note = "use `ReporterRegistry::<DependencyReport>::new()` instead"
+)]
+pub struct DependencyReporter;
+
+#[allow(deprecated)]
+impl Default for DependencyReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(deprecated)]
+impl DependencyReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn validate_format(format: &str) -> io::Result<()> {
+        ReporterRegistry::<DependencyReport>::new()
+            .validate_format(format)
+            .map(|_| ())
+    }
+
+    pub fn output(
+        &self,
+        report: &DependencyReport,
+        format: &str,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        ReporterRegistry::new().output(report, format, writer)
+    }
+}
+
+#[deprecated(
+    since = "1.1.0",
+    note = "use `ReporterRegistry::<StackReport>::new()` instead"
+)]
+pub struct StackReporter;
+
+#[allow(deprecated)]
+impl Default for StackReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(deprecated)]
+impl StackReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn validate_format(format: &str) -> io::Result<()> {
+        ReporterRegistry::<StackReport>::new()
+            .validate_format(format)
+            .map(|_| ())
+    }
+
+    pub fn output(
+        &self,
+        report: &StackReport,
+        format: &str,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        ReporterRegistry::new().output(report, format, writer)
+    }
+}
+
+#[deprecated(
+    since = "1.1.0",
+    note = "use `ReporterRegistry::<LicenseReport>::new()` instead"
+)]
+pub struct LicenseReporter;
+
+#[allow(deprecated)]
+impl Default for LicenseReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[allow(deprecated)]
+impl LicenseReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn validate_format(format: &str) -> io::Result<()> {
+        ReporterRegistry::<LicenseReport>::new()
+            .validate_format(format)
+            .map(|_| ())
+    }
+
+    pub fn output(
+        &self,
+        report: &LicenseReport,
+        format: &str,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        ReporterRegistry::new().output(report, format, writer)
+    }
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_format_forwards_to_registry() {
+        assert!(LanguageReporter::validate_format("markdown").is_ok());
+        assert!(DependencyReporter::validate_format("cyclonedx").is_ok());
+        assert_eq!(
+            StackReporter::validate_format("xml")
+                .unwrap_err()
+                .to_string(),
+            "Unsupported reporter format: 'xml'. Supported formats: table, json."
+        );
+    }
+
+    #[test]
+    fn test_output_forwards_to_registry() {
+        let report = LicenseReport {
+            dir: "/test/path".to_string(),
+            project_license: None,
+            licenses: vec![],
+        };
+
+        let mut output = Vec::new();
+        LicenseReporter::new()
+            .output(&report, "json", &mut output)
+            .expect("JSON output should succeed");
+
+        let parsed: serde_json::Value =
+            serde_json::from_slice(&output).expect("Generated JSON is invalid");
+        assert_eq!(parsed["dir"], "/test/path");
+    }
+}
@@ -1,7 +1,37 @@
-use serde::Deserialize;
+use crate::entity::LanguageDefinition;
+use serde::{Deserialize, Deserializer};
 
 #[derive(Deserialize, Default)]
 pub struct AppConfig {
     pub exclude: Option<Vec<String>>,
-    pub reporter: Option<String>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub reporter: Option<Vec<String>>,
+    pub weight: Option<String>,
+    pub sniff_content: Option<bool>,
+    pub include_vendored: Option<bool>,
+    pub embedded: Option<bool>,
+    pub csv_files: Option<bool>,
+    pub markdown_files: Option<bool>,
+    pub kinds: Option<Vec<String>>,
+    pub languages: Option<Vec<LanguageDefinition>>,
+}
+
+/// Accepts a single value as well as a list, so that `reporter: json` keeps working next to
+/// `reporter: [table, json=report.json]`.
+fn one_or_many<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Vec<String>>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(
+        Option::<OneOrMany>::deserialize(deserializer)?.map(|value| match value {
+            OneOrMany::One(value) => vec![value],
+            OneOrMany::Many(values) => values,
+        }),
+    )
 }
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// A direct dependency declared in a manifest. `version` is the constraint as written,
+/// e.g. `^1.2.0` or `>=2,<3`, and is `None` for path, git or unversioned dependencies.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dependency {
+    pub name: String,
+    pub version: Option<String>,
+    pub scope: DependencyScope,
+}
+
+impl Dependency {
+    pub fn new(name: &str, version: Option<&str>, scope: DependencyScope) -> Self {
+        Self {
+            name: name.to_string(),
+            version: version
+                .map(str::trim)
+                .filter(|version| !version.is_empty())
+                .map(str::to_string),
+            scope,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyScope {
+    Runtime,
+    Development,
+    Build,
+    Peer,
+    Optional,
+}
+
+impl DependencyScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DependencyScope::Runtime => "runtime",
+            DependencyScope::Development => "development",
+            DependencyScope::Build => "build",
+            DependencyScope::Peer => "peer",
+            DependencyScope::Optional => "optional",
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::entity::{Dependency, Ecosystem, ResolvedDependency};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DependencyReport {
+    pub dir: String,
+    pub manifests: Vec<Manifest>,
+    pub lockfiles: Vec<Lockfile>,
+    /// Languages of the source files, by file name and extension, most files first. Data,
+    /// config and prose languages are left out.
+    pub languages: Vec<String>,
+}
+
+/// A package manifest and the direct dependencies it declares.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Manifest {
+    pub path: String,
+    pub ecosystem: Ecosystem,
+    pub dependencies: Vec<Dependency>,
+}
+
+/// A lockfile and the exact versions it resolves, direct and transitive.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Lockfile {
+    pub path: String,
+    pub ecosystem: Ecosystem,
+    pub dependencies: Vec<ResolvedDependency>,
+}
@@ -0,0 +1,9 @@
+use crate::entity::LanguageDefinition;
+
+#[derive(Debug, Default)]
+pub struct DependencyScannerOptions {
+    pub exclude: Vec<String>,
+    /// Language definitions from the config file, used to detect the languages recorded
+    /// in SBOMs.
+    pub languages: Vec<LanguageDefinition>,
+}
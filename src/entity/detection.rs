@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+/// A technology found in a scanned tree, with the evidence that triggered it so the
+/// result can be audited.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Detection {
+    pub name: String,
+    pub category: String,
+    pub evidence: Vec<Evidence>,
+}
+
+/// A single match of a detection rule. `detail` is the matched dependency name, file
+/// pattern, or the import statement or line of content that matched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Evidence {
+    pub kind: EvidenceKind,
+    pub path: String,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvidenceKind {
+    Dependency,
+    File,
+    Import,
+    Content,
+}
+
+impl EvidenceKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EvidenceKind::Dependency => "dependency",
+            EvidenceKind::File => "file",
+            EvidenceKind::Import => "import",
+            EvidenceKind::Content => "content",
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::entity::Ecosystem;
+
+/// Detects `name` when a manifest declares one of `dependencies`, a file path matches one
+/// of `files` (globs relative to the scanned directory), a source file of an ecosystem
+/// matches one of its `imports` (regular expressions) or a file matching the glob of a
+/// `contents` entry matches its regular expression. Dependency names ending in `*` match by prefix.
+#[derive(Debug, Clone, Copy)]
+pub struct DetectionRule {
+    pub name: &'static str,
+    pub category: &'static str,
+    pub dependencies: &'static [(Ecosystem, &'static str)],
+    pub files: &'static [&'static str],
+    pub imports: &'static [(Ecosystem, &'static str)],
+    pub contents: &'static [(&'static str, &'static str)],
+}
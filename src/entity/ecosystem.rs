@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Package registry a manifest pulls its dependencies from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Ecosystem {
+    Npm,
+    Cargo,
+    Go,
+    Pypi,
+    Rubygems,
+    Packagist,
+    Maven,
+    Pub,
+    Hex,
+}
+
+impl Ecosystem {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Ecosystem::Npm => "npm",
+            Ecosystem::Cargo => "cargo",
+            Ecosystem::Go => "go",
+            Ecosystem::Pypi => "pypi",
+            Ecosystem::Rubygems => "rubygems",
+            Ecosystem::Packagist => "packagist",
+            Ecosystem::Maven => "maven",
+            Ecosystem::Pub => "pub",
+            Ecosystem::Hex => "hex",
+        }
+    }
+
+    /// Builds the package URL (purl) of a package, e.g. `pkg:npm/%40types/node@20.1.0` or
+    /// `pkg:maven/org.slf4j/slf4j-api@2.0.9`.
+    pub fn purl(&self, name: &str, version: Option<&str>) -> String {
+        let (purl_type, name) = match self {
+            Ecosystem::Npm => ("npm", name.replace('@', "%40")),
+            Ecosystem::Cargo => ("cargo", name.to_string()),
+            Ecosystem::Go => ("golang", name.to_string()),
+            Ecosystem::Pypi => ("pypi", self.normalize_name(name)),
+            Ecosystem::Rubygems => ("gem", name.to_string()),
+            Ecosystem::Packagist => ("composer", name.to_string()),
+            Ecosystem::Maven => ("maven", name.replacen(':', "/", 1)),
+            Ecosystem::Pub => ("pub", name.to_string()),
+            Ecosystem::Hex => ("hex", name.to_string()),
+        };
+
+        match version {
+            Some(version) => format!("pkg:{}/{}@{}", purl_type, name, version.replace('+', "%2B")),
+            None => format!("pkg:{}/{}", purl_type, name),
+        }
+    }
+
+    /// Extensions of the source files that import packages of this ecosystem.
+    pub fn source_extensions(&self) -> &'static [&'static str] {
+        match self {
+            Ecosystem::Npm => &[
+                "js", "jsx", "mjs", "cjs", "ts", "tsx", "vue", "svelte", "astro",
+            ],
+            Ecosystem::Cargo => &["rs"],
+            Ecosystem::Go => &["go"],
+            Ecosystem::Pypi => &["py"],
+            Ecosystem::Rubygems => &["rb"],
+            Ecosystem::Packagist => &["php"],
+            Ecosystem::Maven => &["java", "kt", "scala"],
+            Ecosystem::Pub => &["dart"],
+            Ecosystem::Hex => &["ex", "exs"],
+        }
+    }
+
+    /// Normalizes a package name for comparison. Python package names compare
+    /// case-insensitively and treat `-`, `_` and `.` alike.
+    pub fn normalize_name(&self, name: &str) -> String {
+        match self {
+            Ecosystem::Pypi => name.to_lowercase().replace(['_', '.'], "-"),
+            _ => name.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Ecosystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod purl {
+        use super::*;
+
+        #[test]
+        fn test_purl_per_ecosystem() {
+            assert_eq!(
+                Ecosystem::Npm.purl("@types/node", Some("20.1.0")),
+                "pkg:npm/%40types/node@20.1.0"
+            );
+            assert_eq!(
+                Ecosystem::Go.purl("github.com/gin-gonic/gin", Some("v1.9.1")),
+                "pkg:golang/github.com/gin-gonic/gin@v1.9.1"
+            );
+            assert_eq!(
+                Ecosystem::Pypi.purl("Django_Rest", Some("3.15.1")),
+                "pkg:pypi/django-rest@3.15.1"
+            );
+            assert_eq!(
+                Ecosystem::Maven.purl("org.slf4j:slf4j-api", Some("2.0.9")),
+                "pkg:maven/org.slf4j/slf4j-api@2.0.9"
+            );
+            assert_eq!(
+                Ecosystem::Cargo.purl("serde", Some("1.0.0+build")),
+                "pkg:cargo/serde@1.0.0%2Bbuild"
+            );
+            assert_eq!(Ecosystem::Rubygems.purl("rails", None), "pkg:gem/rails");
+        }
+    }
+}
@@ -4,6 +4,7 @@ use std::path::Path;
 
 pub struct File {
     pub path: String,
+    pub name: String,
     pub ext: Option<String>,
 }
 
@@ -17,6 +18,10 @@ impl File {
         let path = path.as_ref();
         File {
             path: path.to_string_lossy().into_owned(),
+            name: path
+                .file_name()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default(),
             ext: path
                 .extension()
                 .and_then(|s| s.to_str())
@@ -37,6 +42,7 @@ mod tests {
             let file = File::from_path("/test/file.rs");
 
             assert_eq!(file.path, "/test/file.rs");
+            assert_eq!(file.name, "file.rs");
             assert_eq!(file.ext, Some("rs".to_string()));
         }
 
@@ -45,6 +51,7 @@ mod tests {
             let file = File::from_path("/test/file");
 
             assert_eq!(file.path, "/test/file");
+            assert_eq!(file.name, "file");
             assert_eq!(file.ext, None);
         }
 
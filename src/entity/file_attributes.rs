@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+/// Linguist attributes assigned to a file by `.gitattributes`.
+/// `None` means the attribute is unspecified.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FileAttributes {
+    pub language: Option<String>,
+    pub vendored: Option<bool>,
+    pub generated: Option<bool>,
+    pub documentation: Option<bool>,
+}
+
+impl FileAttributes {
+    /// Returns why the file is left out of the language statistics, if it is.
+    pub fn classification(&self) -> Option<FileClassification> {
+        if self.vendored == Some(true) {
+            Some(FileClassification::Vendored)
+        } else if self.generated == Some(true) {
+            Some(FileClassification::Generated)
+        } else if self.documentation == Some(true) {
+            Some(FileClassification::Documentation)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileClassification {
+    Vendored,
+    Generated,
+    Documentation,
+}
+
+impl FileClassification {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileClassification::Vendored => "vendored",
+            FileClassification::Generated => "generated",
+            FileClassification::Documentation => "documentation",
+        }
+    }
+}
+
+/// A file left out of the language statistics, with the language it was detected as.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassifiedFile {
+    pub path: String,
+    pub language: Option<String>,
+    pub classification: FileClassification,
+}
@@ -0,0 +1,15 @@
+/// Content rules that disambiguate files whose extension is shared by several languages.
+#[derive(Debug, Clone, Copy)]
+pub struct Heuristic {
+    pub exts: &'static [&'static str],
+    pub rules: &'static [HeuristicRule],
+    pub fallback: &'static str,
+}
+
+/// Classifies a file as `language` when any of `patterns` (regular expressions) matches its
+/// content. Rules are evaluated in order and the first match wins.
+#[derive(Debug, Clone, Copy)]
+pub struct HeuristicRule {
+    pub language: &'static str,
+    pub patterns: &'static [&'static str],
+}
@@ -1,9 +1,32 @@
+use crate::entity::LanguageKind;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Language {
-    pub name: &'static str,
-    pub exts: &'static [&'static str],
+    pub name: String,
+    pub exts: Vec<String>,
+    pub kind: LanguageKind,
+    pub comment: CommentSyntax,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CommentSyntax {
+    pub line: Vec<String>,
+    pub block: Vec<(String, String)>,
+    pub nested: bool,
+    /// Whether block comment markers only count at the start of a line.
+    pub block_at_line_start: bool,
+}
+
+impl Language {
+    pub fn new(name: &str, exts: &[&str]) -> Self {
+        Self {
+            name: name.to_string(),
+            exts: exts.iter().map(|ext| ext.to_string()).collect(),
+            kind: LanguageKind::default(),
+            comment: CommentSyntax::default(),
+        }
+    }
 }
 
 impl Serialize for Language {
@@ -12,9 +35,10 @@ impl Serialize for Language {
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("Language", 2)?;
-        state.serialize_field("name", self.name)?;
-        state.serialize_field("exts", self.exts)?;
+        let mut state = serializer.serialize_struct("Language", 3)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("exts", &self.exts)?;
+        state.serialize_field("kind", &self.kind)?;
         state.end()
     }
 }
@@ -37,40 +61,31 @@ mod tests {
 
         #[test]
         fn test_serialize_single_extension() {
-            let lang = Language {
-                name: "Python",
-                exts: &["py"],
-            };
+            let lang = Language::new("Python", &["py"]);
 
             let json = serde_json::to_string(&lang).unwrap();
-            let expected = r#"{"name":"Python","exts":["py"]}"#;
+            let expected = r#"{"name":"Python","exts":["py"],"kind":"programming"}"#;
 
             assert_eq!(json, expected);
         }
 
         #[test]
         fn test_serialize_multiple_extension() {
-            let lang = Language {
-                name: "JavaScript",
-                exts: &["js", "jsx"],
-            };
+            let lang = Language::new("JavaScript", &["js", "jsx"]);
 
             let json = serde_json::to_string(&lang).unwrap();
-            let expected = r#"{"name":"JavaScript","exts":["js","jsx"]}"#;
+            let expected = r#"{"name":"JavaScript","exts":["js","jsx"],"kind":"programming"}"#;
 
             assert_eq!(json, expected);
         }
 
         #[test]
         fn test_serialize_pretty() {
-            let lang = Language {
-                name: "HTML",
-                exts: &["html", "htm"],
-            };
+            let mut lang = Language::new("HTML", &["html", "htm"]);
+            lang.kind = LanguageKind::Markup;
 
             let json = serde_json::to_string_pretty(&lang).unwrap();
-            let expected =
-                "{\n  \"name\": \"HTML\",\n  \"exts\": [\n    \"html\",\n    \"htm\"\n  ]\n}";
+            let expected = "{\n  \"name\": \"HTML\",\n  \"exts\": [\n    \"html\",\n    \"htm\"\n  ],\n  \"kind\": \"markup\"\n}";
 
             assert_eq!(json, expected);
         }
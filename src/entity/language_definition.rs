@@ -0,0 +1,22 @@
+use crate::entity::LanguageKind;
+use serde::Deserialize;
+
+/// A `languages` entry of the config file.
+///
+/// An entry whose `name` matches a built-in language extends it, and its extensions are
+/// taken away from every other language. Any other name defines a new language.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct LanguageDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub exts: Vec<String>,
+    #[serde(default)]
+    pub filenames: Vec<String>,
+    pub kind: Option<LanguageKind>,
+    pub line_comments: Option<Vec<String>>,
+    pub block_comments: Option<Vec<(String, String)>>,
+    pub nested_comments: Option<bool>,
+    pub block_comments_at_line_start: Option<bool>,
+    #[serde(default)]
+    pub disabled: bool,
+}
@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io;
+
+pub const KIND_PROGRAMMING: &str = "programming";
+pub const KIND_MARKUP: &str = "markup";
+pub const KIND_STYLE: &str = "style";
+pub const KIND_DATA: &str = "data";
+pub const KIND_CONFIG: &str = "config";
+pub const KIND_PROSE: &str = "prose";
+
+/// Category of a language, used to group and filter the report.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum LanguageKind {
+    #[default]
+    Programming,
+    Markup,
+    Style,
+    Data,
+    Config,
+    Prose,
+}
+
+impl LanguageKind {
+    pub fn parse(value: &str) -> io::Result<Self> {
+        match value {
+            KIND_PROGRAMMING => Ok(LanguageKind::Programming),
+            KIND_MARKUP => Ok(LanguageKind::Markup),
+            KIND_STYLE => Ok(LanguageKind::Style),
+            KIND_DATA => Ok(LanguageKind::Data),
+            KIND_CONFIG => Ok(LanguageKind::Config),
+            KIND_PROSE => Ok(LanguageKind::Prose),
+            _ => Err(io::Error::other(format!(
+                "Unsupported kind: '{}'. Supported kinds: {}, {}, {}, {}, {}, {}.",
+                value,
+                KIND_PROGRAMMING,
+                KIND_MARKUP,
+                KIND_STYLE,
+                KIND_DATA,
+                KIND_CONFIG,
+                KIND_PROSE
+            ))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LanguageKind::Programming => KIND_PROGRAMMING,
+            LanguageKind::Markup => KIND_MARKUP,
+            LanguageKind::Style => KIND_STYLE,
+            LanguageKind::Data => KIND_DATA,
+            LanguageKind::Config => KIND_CONFIG,
+            LanguageKind::Prose => KIND_PROSE,
+        }
+    }
+
+    /// Data, config and prose files are reported but left out of the percentages, so that
+    /// fixtures and manifests do not inflate the share of the code itself.
+    pub fn counts_toward_percentage(&self) -> bool {
+        matches!(
+            self,
+            LanguageKind::Programming | LanguageKind::Markup | LanguageKind::Style
+        )
+    }
+}
+
+impl fmt::Display for LanguageKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_parse_supported_kinds() {
+            assert_eq!(
+                LanguageKind::parse("programming").unwrap(),
+                LanguageKind::Programming
+            );
+            assert_eq!(LanguageKind::parse("markup").unwrap(), LanguageKind::Markup);
+            assert_eq!(LanguageKind::parse("style").unwrap(), LanguageKind::Style);
+            assert_eq!(LanguageKind::parse("data").unwrap(), LanguageKind::Data);
+            assert_eq!(LanguageKind::parse("config").unwrap(), LanguageKind::Config);
+            assert_eq!(LanguageKind::parse("prose").unwrap(), LanguageKind::Prose);
+        }
+
+        #[test]
+        fn test_parse_unsupported_kind() {
+            let result = LanguageKind::parse("binary");
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "Unsupported kind: 'binary'. Supported kinds: programming, markup, style, data, config, prose."
+            );
+        }
+    }
+
+    mod counts_toward_percentage {
+        use super::*;
+
+        #[test]
+        fn test_counts_toward_percentage() {
+            assert!(LanguageKind::Programming.counts_toward_percentage());
+            assert!(LanguageKind::Style.counts_toward_percentage());
+            assert!(!LanguageKind::Data.counts_toward_percentage());
+            assert!(!LanguageKind::Config.counts_toward_percentage());
+            assert!(!LanguageKind::Prose.counts_toward_percentage());
+        }
+    }
+}
@@ -1,11 +1,18 @@
-use crate::entity::Language;
+use crate::entity::{
+    ClassifiedFile, FileClassification, Language, LanguageKind, LineStats, SourceRole, SourceStats,
+    Weight,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct LanguageReport {
     pub dir: String,
     pub total_file_count: u64,
+    pub weight: Weight,
+    /// Kinds of languages the report was filtered to. Empty means every kind.
+    pub kinds: Vec<LanguageKind>,
     pub languages: Vec<LanguageReportItem>,
+    pub classified_files: Vec<ClassifiedFile>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,4 +20,291 @@ pub struct LanguageReportItem {
     pub language: Language,
     pub file_count: u64,
     pub file_paths: Vec<String>,
+    pub code_lines: u64,
+    pub comment_lines: u64,
+    pub blank_lines: u64,
+    pub bytes: u64,
+    pub content_detected_file_paths: Vec<String>,
+    pub embedded_languages: Vec<EmbeddedLanguageItem>,
+    pub production: SourceStats,
+    pub test: SourceStats,
+    pub fixture: SourceStats,
+}
+
+/// Lines of a language embedded in single-file components, such as the `<script lang="ts">`
+/// blocks of Vue files.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmbeddedLanguageItem {
+    pub language: String,
+    pub block_count: u64,
+    pub code_lines: u64,
+    pub comment_lines: u64,
+    pub blank_lines: u64,
+}
+
+impl LanguageReport {
+    /// Returns the label and value of each row of the scan summary. Counts of content
+    /// detected and classified files are only included when there are any.
+    pub fn summary(&self) -> Vec<(&'static str, String)> {
+        let detected_files_count: u64 = self.languages.iter().map(|item| item.file_count).sum();
+        let excluded_files_count = self.total_file_count.saturating_sub(detected_files_count);
+
+        let mut rows = vec![
+            ("Directory", self.dir.clone()),
+            ("Total Files", self.total_file_count.to_string()),
+            ("Language Files", detected_files_count.to_string()),
+            ("Excluded Files", excluded_files_count.to_string()),
+            ("Weight", self.weight.as_str().to_string()),
+        ];
+
+        let content_detected_files_count: usize = self
+            .languages
+            .iter()
+            .map(|item| item.content_detected_file_paths.len())
+            .sum();
+        if content_detected_files_count > 0 {
+            rows.push((
+                "Content Detected Files",
+                content_detected_files_count.to_string(),
+            ));
+        }
+
+        for (label, classification) in [
+            ("Vendored Files", FileClassification::Vendored),
+            ("Generated Files", FileClassification::Generated),
+            ("Documentation Files", FileClassification::Documentation),
+        ] {
+            let count = self
+                .classified_files
+                .iter()
+                .filter(|file| file.classification == classification)
+                .count();
+            if count > 0 {
+                rows.push((label, count.to_string()));
+            }
+        }
+
+        rows
+    }
+
+    /// Returns the share of `item` in the languages that count toward the percentage,
+    /// under the report's weight, or `None` for data, config and prose languages.
+    pub fn percentage(&self, item: &LanguageReportItem) -> Option<f64> {
+        if !self.counts_toward_percentage(item.language.kind) {
+            return None;
+        }
+        let weighted_total: u64 = self
+            .languages
+            .iter()
+            .filter(|item| self.counts_toward_percentage(item.language.kind))
+            .map(|item| item.weighted_value(self.weight))
+            .sum();
+        Some(Self::calculate_percentage(
+            item.weighted_value(self.weight),
+            weighted_total,
+        ))
+    }
+
+    /// Data, config and prose languages only count when the report was filtered to such
+    /// kinds alone, for example with `--kinds data`.
+    fn counts_toward_percentage(&self, kind: LanguageKind) -> bool {
+        kind.counts_toward_percentage()
+            || (!self.kinds.is_empty()
+                && self
+                    .kinds
+                    .iter()
+                    .all(|kind| !kind.counts_toward_percentage()))
+    }
+
+    fn calculate_percentage(value: u64, detected_total: u64) -> f64 {
+        if detected_total > 0 {
+            (value as f64 / detected_total as f64) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+impl LanguageReportItem {
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            file_count: 0,
+            file_paths: Vec::new(),
+            code_lines: 0,
+            comment_lines: 0,
+            blank_lines: 0,
+            bytes: 0,
+            content_detected_file_paths: Vec::new(),
+            embedded_languages: Vec::new(),
+            production: SourceStats::default(),
+            test: SourceStats::default(),
+            fixture: SourceStats::default(),
+        }
+    }
+
+    pub fn add_file(&mut self, path: &str, role: SourceRole, line_stats: &LineStats, bytes: u64) {
+        let role_stats = match role {
+            SourceRole::Production => &mut self.production,
+            SourceRole::Test => &mut self.test,
+            SourceRole::Fixture => &mut self.fixture,
+        };
+        role_stats.file_count += 1;
+        role_stats.code_lines += line_stats.code;
+
+        self.file_count += 1;
+        self.file_paths.push(path.to_string());
+        self.code_lines += line_stats.code;
+        self.comment_lines += line_stats.comment;
+        self.blank_lines += line_stats.blank;
+        self.bytes += bytes;
+    }
+
+    pub fn add_embedded(&mut self, language: &str, line_stats: &LineStats) {
+        let index = match self
+            .embedded_languages
+            .iter()
+            .position(|item| item.language == language)
+        {
+            Some(index) => index,
+            None => {
+                self.embedded_languages.push(EmbeddedLanguageItem {
+                    language: language.to_string(),
+                    block_count: 0,
+                    code_lines: 0,
+                    comment_lines: 0,
+                    blank_lines: 0,
+                });
+                self.embedded_languages.len() - 1
+            }
+        };
+
+        let item = &mut self.embedded_languages[index];
+        item.block_count += 1;
+        item.code_lines += line_stats.code;
+        item.comment_lines += line_stats.comment;
+        item.blank_lines += line_stats.blank;
+    }
+
+    /// Returns the test code lines per production code line, or `None` without production
+    /// code.
+    pub fn test_ratio(&self) -> Option<f64> {
+        (self.production.code_lines > 0)
+            .then(|| self.test.code_lines as f64 / self.production.code_lines as f64)
+    }
+
+    /// Returns the amount this language contributes under `weight`.
+    /// Line weighting counts code lines only.
+    pub fn weighted_value(&self, weight: Weight) -> u64 {
+        match weight {
+            Weight::Files => self.file_count,
+            Weight::Bytes => self.bytes,
+            Weight::Lines => self.code_lines,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod summary {
+        use super::*;
+
+        #[test]
+        fn test_summary_counts_excluded_and_classified_files() {
+            let mut item = LanguageReportItem::new(Language::new("Rust", &["rs"]));
+            item.file_count = 3;
+            let report = LanguageReport {
+                dir: "/test/path".to_string(),
+                total_file_count: 5,
+                weight: Weight::Files,
+                kinds: vec![],
+                languages: vec![item],
+                classified_files: vec![ClassifiedFile {
+                    path: "vendor/a.rs".to_string(),
+                    language: Some("Rust".to_string()),
+                    classification: FileClassification::Vendored,
+                }],
+            };
+
+            assert_eq!(
+                report.summary(),
+                vec![
+                    ("Directory", "/test/path".to_string()),
+                    ("Total Files", "5".to_string()),
+                    ("Language Files", "3".to_string()),
+                    ("Excluded Files", "2".to_string()),
+                    ("Weight", "files".to_string()),
+                    ("Vendored Files", "1".to_string()),
+                ]
+            );
+        }
+    }
+
+    mod percentage {
+        use super::*;
+
+        fn create_report(kinds: Vec<LanguageKind>) -> LanguageReport {
+            let mut json = LanguageReportItem::new(Language {
+                kind: LanguageKind::Data,
+                ..Language::new("JSON", &["json"])
+            });
+            json.file_count = 3;
+            let mut yaml = LanguageReportItem::new(Language {
+                kind: LanguageKind::Data,
+                ..Language::new("YAML", &["yaml"])
+            });
+            yaml.file_count = 1;
+
+            LanguageReport {
+                dir: "/test/path".to_string(),
+                total_file_count: 4,
+                weight: Weight::Files,
+                kinds,
+                languages: vec![json, yaml],
+                classified_files: vec![],
+            }
+        }
+
+        #[test]
+        fn test_data_languages_do_not_count_by_default() {
+            let report = create_report(vec![]);
+
+            assert_eq!(report.percentage(&report.languages[0]), None);
+        }
+
+        #[test]
+        fn test_data_languages_count_with_kinds_data() {
+            let report = create_report(vec![LanguageKind::Data]);
+
+            assert_eq!(report.percentage(&report.languages[0]), Some(75.0));
+            assert_eq!(report.percentage(&report.languages[1]), Some(25.0));
+        }
+
+        #[test]
+        fn test_data_languages_do_not_count_with_programming_kinds() {
+            let report = create_report(vec![LanguageKind::Data, LanguageKind::Programming]);
+
+            assert_eq!(report.percentage(&report.languages[0]), None);
+        }
+    }
+
+    mod calculate_percentage {
+        use super::*;
+
+        #[test]
+        fn test_calculate_percentage() {
+            assert_eq!(LanguageReport::calculate_percentage(30, 100), 30.0);
+            assert!(
+                (LanguageReport::calculate_percentage(1, 3) - 33.333333333333336).abs() < 0.0001
+            );
+
+            assert_eq!(LanguageReport::calculate_percentage(5, 0), 0.0);
+
+            assert_eq!(LanguageReport::calculate_percentage(0, 100), 0.0);
+
+            assert_eq!(LanguageReport::calculate_percentage(50, 50), 100.0);
+        }
+    }
 }
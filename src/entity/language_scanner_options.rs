@@ -1,4 +1,13 @@
+use crate::entity::{LanguageDefinition, LanguageKind, Weight};
+
 #[derive(Debug, Default)]
 pub struct LanguageScannerOptions {
     pub exclude: Vec<String>,
+    pub weight: Weight,
+    pub sniff_content: bool,
+    pub include_vendored: bool,
+    pub embedded: bool,
+    /// Kinds of languages to report. Empty means every kind.
+    pub kinds: Vec<LanguageKind>,
+    pub languages: Vec<LanguageDefinition>,
 }
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LicenseReport {
+    pub dir: String,
+    /// The license of the license files at the top of the scanned directory. Several
+    /// license files, such as `LICENSE-MIT` and `LICENSE-APACHE`, are read as a choice.
+    pub project_license: Option<String>,
+    pub licenses: Vec<DetectedLicense>,
+}
+
+/// A license found in a license file or an SPDX header. `license` is the SPDX identifier
+/// or expression, or `None` for a license file that matches no bundled license.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DetectedLicense {
+    pub path: String,
+    pub source: LicenseSource,
+    pub license: Option<String>,
+    /// Text similarity to the matched license, from 0 to 1. Only set for license files.
+    pub similarity: Option<f64>,
+    pub vendored: bool,
+    /// Whether the license expression does not fit the project license, with every part of
+    /// an `AND` and one alternative of an `OR` having to be a project license or a
+    /// permissive or weak copyleft license, which any project can include.
+    pub conflict: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LicenseSource {
+    File,
+    Header,
+}
+
+impl LicenseSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LicenseSource::File => "file",
+            LicenseSource::Header => "header",
+        }
+    }
+}
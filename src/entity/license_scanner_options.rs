@@ -0,0 +1,4 @@
+#[derive(Debug, Default)]
+pub struct LicenseScannerOptions {
+    pub exclude: Vec<String>,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineStats {
+    pub code: u64,
+    pub comment: u64,
+    pub blank: u64,
+}
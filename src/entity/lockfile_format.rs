@@ -0,0 +1,29 @@
+use crate::entity::Ecosystem;
+
+/// Lockfile formats understood by the dependency scanner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockfileFormat {
+    PackageLock,
+    YarnLock,
+    PnpmLock,
+    CargoLock,
+    GoSum,
+    PoetryLock,
+    GemfileLock,
+    ComposerLock,
+}
+
+impl LockfileFormat {
+    pub fn ecosystem(&self) -> Ecosystem {
+        match self {
+            LockfileFormat::PackageLock | LockfileFormat::YarnLock | LockfileFormat::PnpmLock => {
+                Ecosystem::Npm
+            }
+            LockfileFormat::CargoLock => Ecosystem::Cargo,
+            LockfileFormat::GoSum => Ecosystem::Go,
+            LockfileFormat::PoetryLock => Ecosystem::Pypi,
+            LockfileFormat::GemfileLock => Ecosystem::Rubygems,
+            LockfileFormat::ComposerLock => Ecosystem::Packagist,
+        }
+    }
+}
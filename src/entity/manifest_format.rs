@@ -0,0 +1,33 @@
+use crate::entity::Ecosystem;
+
+/// File formats understood by the dependency scanner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManifestFormat {
+    PackageJson,
+    CargoToml,
+    GoMod,
+    Requirements,
+    Pyproject,
+    Gemfile,
+    ComposerJson,
+    PomXml,
+    Gradle,
+    Pubspec,
+    MixExs,
+}
+
+impl ManifestFormat {
+    pub fn ecosystem(&self) -> Ecosystem {
+        match self {
+            ManifestFormat::PackageJson => Ecosystem::Npm,
+            ManifestFormat::CargoToml => Ecosystem::Cargo,
+            ManifestFormat::GoMod => Ecosystem::Go,
+            ManifestFormat::Requirements | ManifestFormat::Pyproject => Ecosystem::Pypi,
+            ManifestFormat::Gemfile => Ecosystem::Rubygems,
+            ManifestFormat::ComposerJson => Ecosystem::Packagist,
+            ManifestFormat::PomXml | ManifestFormat::Gradle => Ecosystem::Maven,
+            ManifestFormat::Pubspec => Ecosystem::Pub,
+            ManifestFormat::MixExs => Ecosystem::Hex,
+        }
+    }
+}
@@ -1,15 +1,61 @@
 pub mod app_config;
+pub mod dependency;
+pub mod dependency_report;
+pub mod dependency_scanner_options;
+pub mod detection;
+pub mod detection_rule;
+pub mod ecosystem;
 pub mod error;
 pub mod file;
+pub mod file_attributes;
+pub mod heuristic;
 pub mod language;
+pub mod language_definition;
+pub mod language_kind;
 pub mod language_report;
 pub mod language_scanner_options;
+pub mod license_report;
+pub mod license_scanner_options;
+pub mod line_stats;
+pub mod lockfile_format;
+pub mod manifest_format;
+pub mod reporter_target;
+pub mod resolved_dependency;
 pub mod result;
+pub mod runtime_version;
+pub mod sfc_syntax;
+pub mod source_role;
+pub mod stack_report;
+pub mod stack_scanner_options;
+pub mod weight;
 
 pub use app_config::AppConfig;
+pub use dependency::{Dependency, DependencyScope};
+pub use dependency_report::{DependencyReport, Lockfile, Manifest};
+pub use dependency_scanner_options::DependencyScannerOptions;
+pub use detection::{Detection, Evidence, EvidenceKind};
+pub use detection_rule::DetectionRule;
+pub use ecosystem::Ecosystem;
 pub use error::TechScanError;
 pub use file::File;
-pub use language::Language;
+pub use file_attributes::{ClassifiedFile, FileAttributes, FileClassification};
+pub use heuristic::{Heuristic, HeuristicRule};
+pub use language::{CommentSyntax, Language};
+pub use language_definition::LanguageDefinition;
+pub use language_kind::LanguageKind;
 pub use language_report::{LanguageReport, LanguageReportItem};
 pub use language_scanner_options::LanguageScannerOptions;
+pub use license_report::{DetectedLicense, LicenseReport, LicenseSource};
+pub use license_scanner_options::LicenseScannerOptions;
+pub use line_stats::LineStats;
+pub use lockfile_format::LockfileFormat;
+pub use manifest_format::ManifestFormat;
+pub use reporter_target::ReporterTarget;
+pub use resolved_dependency::ResolvedDependency;
 pub use result::Result;
+pub use runtime_version::{ProjectRuntimes, RuntimeVersion};
+pub use sfc_syntax::SfcSyntax;
+pub use source_role::{SourceRole, SourceStats};
+pub use stack_report::StackReport;
+pub use stack_scanner_options::StackScannerOptions;
+pub use weight::Weight;
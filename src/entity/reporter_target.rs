@@ -0,0 +1,88 @@
+use crate::config::REPORTER_FORMAT_TABLE;
+
+/// A report format and where to write it: the file at `path`, or stdout when there is none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReporterTarget {
+    pub format: String,
+    pub path: Option<String>,
+}
+
+impl ReporterTarget {
+    /// Parses a `--reporter` value of the form `format[=path]`.
+    pub fn parse(value: &str) -> Self {
+        match value.split_once('=') {
+            Some((format, path)) if !path.is_empty() => Self {
+                format: format.to_string(),
+                path: Some(path.to_string()),
+            },
+            Some((format, _)) => Self {
+                format: format.to_string(),
+                path: None,
+            },
+            None => Self {
+                format: value.to_string(),
+                path: None,
+            },
+        }
+    }
+
+    /// Parses every `--reporter` value. Without any, a table is written to stdout.
+    pub fn parse_all(values: Option<&[String]>) -> Vec<Self> {
+        match values {
+            Some(values) if !values.is_empty() => {
+                values.iter().map(|value| Self::parse(value)).collect()
+            }
+            _ => vec![Self {
+                format: REPORTER_FORMAT_TABLE.to_string(),
+                path: None,
+            }],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_format_only() {
+            assert_eq!(
+                ReporterTarget::parse("json"),
+                ReporterTarget {
+                    format: "json".to_string(),
+                    path: None,
+                }
+            );
+        }
+
+        #[test]
+        fn test_format_and_path() {
+            assert_eq!(
+                ReporterTarget::parse("json=reports/report.json"),
+                ReporterTarget {
+                    format: "json".to_string(),
+                    path: Some("reports/report.json".to_string()),
+                }
+            );
+            assert_eq!(ReporterTarget::parse("table=").path, None);
+        }
+    }
+
+    mod parse_all {
+        use super::*;
+
+        #[test]
+        fn test_default_table() {
+            assert_eq!(
+                ReporterTarget::parse_all(None),
+                vec![ReporterTarget {
+                    format: "table".to_string(),
+                    path: None,
+                }]
+            );
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// A package pinned by a lockfile. `direct` is true when the project itself declares the
+/// package, false when it is only pulled in by other packages.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ResolvedDependency {
+    pub name: String,
+    pub version: String,
+    pub direct: bool,
+}
+
+impl ResolvedDependency {
+    pub fn new(name: &str, version: &str, direct: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            version: version.to_string(),
+            direct,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// Runtime and toolchain versions pinned in one project root, i.e. the directory holding
+/// the version files.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectRuntimes {
+    pub root: String,
+    pub runtimes: Vec<RuntimeVersion>,
+}
+
+/// A pinned runtime version such as `node` `18.17.0`, with the file that pins it.
+/// `version` is kept as written, e.g. `>=18`, `lts/hydrogen` or `3.12-slim`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuntimeVersion {
+    pub runtime: String,
+    pub version: String,
+    pub source: String,
+}
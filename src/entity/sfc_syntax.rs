@@ -0,0 +1,14 @@
+/// Block layout of a single-file component language. Languages are given as extensions or
+/// names and are used for blocks without a `lang` attribute.
+#[derive(Debug, Clone, Copy)]
+pub struct SfcSyntax {
+    pub language: &'static str,
+    pub script_lang: &'static str,
+    pub style_lang: &'static str,
+    /// Language of `<template>` blocks, or `None` when templates are plain markup.
+    pub template_lang: Option<&'static str>,
+    /// Language of a leading `---` fenced frontmatter block, if the syntax has one.
+    pub frontmatter_lang: Option<&'static str>,
+    /// Language of the markup outside any block, or `None` when it is not counted.
+    pub markup_lang: Option<&'static str>,
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// What a source file is for, judged from the path conventions of its ecosystem.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum SourceRole {
+    #[default]
+    Production,
+    Test,
+    /// Test fixtures, test data and examples.
+    Fixture,
+}
+
+/// Files and code lines of a language that play one source role.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceStats {
+    pub file_count: u64,
+    pub code_lines: u64,
+}
@@ -0,0 +1,12 @@
+use crate::entity::{Detection, ProjectRuntimes};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StackReport {
+    pub dir: String,
+    pub frameworks: Vec<Detection>,
+    pub infrastructure: Vec<Detection>,
+    pub tooling: Vec<Detection>,
+    pub datastores: Vec<Detection>,
+    pub runtimes: Vec<ProjectRuntimes>,
+}
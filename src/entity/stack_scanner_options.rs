@@ -0,0 +1,4 @@
+#[derive(Debug, Default)]
+pub struct StackScannerOptions {
+    pub exclude: Vec<String>,
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io;
+
+pub const WEIGHT_FILES: &str = "files";
+pub const WEIGHT_BYTES: &str = "bytes";
+pub const WEIGHT_LINES: &str = "lines";
+
+/// Basis used for the language percentage and sort order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Weight {
+    #[default]
+    Files,
+    Bytes,
+    Lines,
+}
+
+impl Weight {
+    pub fn parse(value: &str) -> io::Result<Self> {
+        match value {
+            WEIGHT_FILES => Ok(Weight::Files),
+            WEIGHT_BYTES => Ok(Weight::Bytes),
+            WEIGHT_LINES => Ok(Weight::Lines),
+            _ => Err(io::Error::other(format!(
+                "Unsupported weight: '{}'. Supported weights: {}, {}, {}.",
+                value, WEIGHT_FILES, WEIGHT_BYTES, WEIGHT_LINES
+            ))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Weight::Files => WEIGHT_FILES,
+            Weight::Bytes => WEIGHT_BYTES,
+            Weight::Lines => WEIGHT_LINES,
+        }
+    }
+}
+
+impl fmt::Display for Weight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_parse_supported_weights() {
+            assert_eq!(Weight::parse("files").unwrap(), Weight::Files);
+            assert_eq!(Weight::parse("bytes").unwrap(), Weight::Bytes);
+            assert_eq!(Weight::parse("lines").unwrap(), Weight::Lines);
+        }
+
+        #[test]
+        fn test_parse_unsupported_weight() {
+            let result = Weight::parse("tokens");
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().to_string(),
+                "Unsupported weight: 'tokens'. Supported weights: files, bytes, lines."
+            );
+        }
+    }
+
+    mod serialize {
+        use super::*;
+
+        #[test]
+        fn test_serialize_lowercase() {
+            let json = serde_json::to_string(&Weight::Bytes).unwrap();
+            assert_eq!(json, r#""bytes""#);
+        }
+    }
+}
@@ -1,14 +1,31 @@
 pub mod config;
+mod deprecated;
 pub mod entity;
 pub mod service;
 
 pub use entity::{
-    File, Language, LanguageReport, LanguageReportItem, LanguageScannerOptions, Result,
-    TechScanError,
+    CommentSyntax, Dependency, DependencyReport, DependencyScannerOptions, DependencyScope,
+    DetectedLicense, Detection, Ecosystem, Evidence, EvidenceKind, File, Language,
+    LanguageDefinition, LanguageReport, LanguageReportItem, LanguageScannerOptions, LicenseReport,
+    LicenseScannerOptions, LicenseSource, LineStats, Lockfile, Manifest, ProjectRuntimes,
+    ReporterTarget, ResolvedDependency, Result, RuntimeVersion, StackReport, StackScannerOptions,
+    TechScanError, Weight,
 };
 
-pub use config::{LanguageConfig, REPORTER_FORMAT_JSON, REPORTER_FORMAT_TABLE};
+pub use config::{
+    LanguageConfig, REPORTER_FORMAT_CSV, REPORTER_FORMAT_CYCLONEDX, REPORTER_FORMAT_JSON,
+    REPORTER_FORMAT_MARKDOWN, REPORTER_FORMAT_SPDX, REPORTER_FORMAT_TABLE, REPORTER_FORMAT_TSV,
+};
+
+pub use service::{
+    BuiltinReporters, DependencyJsonReporter, DependencySbomReporter, DependencyScanner,
+    DependencyTableReporter, LanguageCsvReporter, LanguageJsonReporter, LanguageMarkdownReporter,
+    LanguageScanner, LanguageTableReporter, LicenseJsonReporter, LicenseScanner,
+    LicenseTableReporter, LineCounter, Reporter, ReporterRegistry, SbomBuilder, StackJsonReporter,
+    StackScanner, StackTableReporter,
+};
 
-pub use service::{LanguageReporter, LanguageScanner};
+#[allow(deprecated)]
+pub use deprecated::{DependencyReporter, LanguageReporter, LicenseReporter, StackReporter};
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
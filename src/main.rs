@@ -4,9 +4,18 @@ mod entity;
 mod service;
 
 use crate::cli::{Cli, Commands};
-use crate::config::REPORTER_FORMAT_TABLE;
-use crate::entity::LanguageScannerOptions;
-use crate::service::{ConfigBuilder, LanguageReporter, LanguageScanner};
+use crate::entity::{
+    AppConfig, DependencyReport, DependencyScannerOptions, LanguageKind, LanguageReport,
+    LanguageScannerOptions, LicenseReport, LicenseScannerOptions, ReporterTarget, StackReport,
+    StackScannerOptions, Weight,
+};
+use crate::service::{
+    ConfigBuilder, DependencyScanner, LanguageCsvReporter, LanguageMarkdownReporter,
+    LanguageScanner, LicenseScanner, Logger, ReporterRegistry, StackScanner,
+};
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
 
 fn main() {
     let cli = Cli::new().unwrap_or_else(|e| {
@@ -14,25 +23,81 @@ fn main() {
         std::process::exit(1);
     });
 
-    match &cli.command {
+    Logger::init(Logger::level(cli.quiet, cli.verbose));
+
+    match cli.command {
         Commands::Language {
             dir,
             exclude,
             reporter,
             config,
+            weight,
+            sniff_content,
+            no_sniff_content,
+            include_vendored,
+            embedded,
+            csv_files,
+            markdown_files,
+            kinds,
+        } => {
+            let cli_config = AppConfig {
+                exclude,
+                reporter,
+                weight,
+                sniff_content: (sniff_content || no_sniff_content).then_some(sniff_content),
+                include_vendored: include_vendored.then_some(true),
+                embedded: embedded.then_some(true),
+                csv_files: csv_files.then_some(true),
+                markdown_files: markdown_files.then_some(true),
+                kinds,
+                languages: None,
+            };
+            handle_language_command(&dir, &config, cli_config);
+        }
+        Commands::Deps {
+            dir,
+            exclude,
+            reporter,
+            config,
         } => {
-            handle_language_command(dir, exclude, reporter, config);
+            let cli_config = AppConfig {
+                exclude,
+                reporter,
+                ..Default::default()
+            };
+            handle_deps_command(&dir, &config, cli_config);
+        }
+        Commands::Stack {
+            dir,
+            exclude,
+            reporter,
+            config,
+        } => {
+            let cli_config = AppConfig {
+                exclude,
+                reporter,
+                ..Default::default()
+            };
+            handle_stack_command(&dir, &config, cli_config);
+        }
+        Commands::License {
+            dir,
+            exclude,
+            reporter,
+            config,
+        } => {
+            let cli_config = AppConfig {
+                exclude,
+                reporter,
+                ..Default::default()
+            };
+            handle_license_command(&dir, &config, cli_config);
         }
     }
 }
 
-fn handle_language_command(
-    dir: &str,
-    exclude: &Option<Vec<String>>,
-    reporter: &Option<String>,
-    config: &Option<String>,
-) {
-    let config_builder = ConfigBuilder::from_cli_args(exclude, reporter)
+fn handle_language_command(dir: &str, config: &Option<String>, cli_config: AppConfig) {
+    let config_builder = ConfigBuilder::from_cli_args(cli_config)
         .merge_file_config(config)
         .unwrap_or_else(|error_msg| {
             eprintln!("Error: {}", error_msg);
@@ -41,18 +106,47 @@ fn handle_language_command(
 
     let final_config = config_builder.build();
 
-    let reporter_format = final_config
-        .reporter
+    let mut registry = ReporterRegistry::<LanguageReport>::new();
+    if final_config.csv_files.unwrap_or(false) {
+        registry.register(Box::new(LanguageCsvReporter::csv().with_file_rows()));
+        registry.register(Box::new(LanguageCsvReporter::tsv().with_file_rows()));
+    }
+    if final_config.markdown_files.unwrap_or(false) {
+        registry.register(Box::new(LanguageMarkdownReporter::new().with_file_lists()));
+    }
+
+    let targets = ReporterTarget::parse_all(final_config.reporter.as_deref());
+
+    validate_targets(&registry, &targets);
+
+    let weight = final_config
+        .weight
         .as_deref()
-        .unwrap_or(REPORTER_FORMAT_TABLE);
+        .map_or(Ok(Weight::default()), Weight::parse)
+        .unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
 
-    LanguageReporter::validate_format(reporter_format).unwrap_or_else(|e| {
-        eprintln!("Error: {}", e);
-        std::process::exit(1);
-    });
+    let kinds = final_config
+        .kinds
+        .unwrap_or_default()
+        .iter()
+        .map(|kind| LanguageKind::parse(kind))
+        .collect::<std::io::Result<Vec<_>>>()
+        .unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        });
 
     let opts = LanguageScannerOptions {
         exclude: final_config.exclude.unwrap_or_default(),
+        weight,
+        sniff_content: final_config.sniff_content.unwrap_or(false),
+        include_vendored: final_config.include_vendored.unwrap_or(false),
+        embedded: final_config.embedded.unwrap_or(false),
+        kinds,
+        languages: final_config.languages.unwrap_or_default(),
     };
 
     let scanner = LanguageScanner::new(dir, Some(opts)).unwrap_or_else(|e| {
@@ -60,7 +154,7 @@ fn handle_language_command(
         std::process::exit(1);
     });
 
-    println!("Processing directory: {}", dir);
+    log::info!("Processing directory: {}", dir);
 
     let files = scanner.scan().unwrap_or_else(|e| {
         eprintln!("Error scanning directory: {}", e);
@@ -69,12 +163,167 @@ fn handle_language_command(
 
     let report = scanner.analyze(files);
 
-    let reporter = LanguageReporter::new();
+    write_reports(&registry, &targets, &report);
+}
 
-    reporter
-        .output(&report, reporter_format)
-        .unwrap_or_else(|e| {
-            eprintln!("Error outputting report: {}", e);
+fn handle_deps_command(dir: &str, config: &Option<String>, cli_config: AppConfig) {
+    let config_builder = ConfigBuilder::from_cli_args(cli_config)
+        .merge_file_config(config)
+        .unwrap_or_else(|error_msg| {
+            eprintln!("Error: {}", error_msg);
+            std::process::exit(1);
+        });
+
+    let final_config = config_builder.build();
+
+    let registry = ReporterRegistry::<DependencyReport>::new();
+    let targets = ReporterTarget::parse_all(final_config.reporter.as_deref());
+    validate_targets(&registry, &targets);
+
+    let opts = DependencyScannerOptions {
+        exclude: final_config.exclude.unwrap_or_default(),
+        languages: final_config.languages.unwrap_or_default(),
+    };
+
+    let scanner = DependencyScanner::new(dir, Some(opts)).unwrap_or_else(|e| {
+        eprintln!("Error initializing scanner: {}", e);
+        std::process::exit(1);
+    });
+
+    log::info!("Processing directory: {}", dir);
+
+    let files = scanner.scan().unwrap_or_else(|e| {
+        eprintln!("Error scanning directory: {}", e);
+        std::process::exit(1);
+    });
+
+    let report = scanner.analyze(files);
+
+    write_reports(&registry, &targets, &report);
+}
+
+fn handle_stack_command(dir: &str, config: &Option<String>, cli_config: AppConfig) {
+    let config_builder = ConfigBuilder::from_cli_args(cli_config)
+        .merge_file_config(config)
+        .unwrap_or_else(|error_msg| {
+            eprintln!("Error: {}", error_msg);
+            std::process::exit(1);
+        });
+
+    let final_config = config_builder.build();
+
+    let registry = ReporterRegistry::<StackReport>::new();
+    let targets = ReporterTarget::parse_all(final_config.reporter.as_deref());
+    validate_targets(&registry, &targets);
+
+    let opts = StackScannerOptions {
+        exclude: final_config.exclude.unwrap_or_default(),
+    };
+
+    let scanner = StackScanner::new(dir, Some(opts)).unwrap_or_else(|e| {
+        eprintln!("Error initializing scanner: {}", e);
+        std::process::exit(1);
+    });
+
+    log::info!("Processing directory: {}", dir);
+
+    let files = scanner.scan().unwrap_or_else(|e| {
+        eprintln!("Error scanning directory: {}", e);
+        std::process::exit(1);
+    });
+
+    let report = scanner.analyze(files);
+
+    write_reports(&registry, &targets, &report);
+}
+
+fn handle_license_command(dir: &str, config: &Option<String>, cli_config: AppConfig) {
+    let config_builder = ConfigBuilder::from_cli_args(cli_config)
+        .merge_file_config(config)
+        .unwrap_or_else(|error_msg| {
+            eprintln!("Error: {}", error_msg);
             std::process::exit(1);
         });
+
+    let final_config = config_builder.build();
+
+    let registry = ReporterRegistry::<LicenseReport>::new();
+    let targets = ReporterTarget::parse_all(final_config.reporter.as_deref());
+    validate_targets(&registry, &targets);
+
+    let opts = LicenseScannerOptions {
+        exclude: final_config.exclude.unwrap_or_default(),
+    };
+
+    let scanner = LicenseScanner::new(dir, Some(opts)).unwrap_or_else(|e| {
+        eprintln!("Error initializing scanner: {}", e);
+        std::process::exit(1);
+    });
+
+    log::info!("Processing directory: {}", dir);
+
+    let files = scanner.scan().unwrap_or_else(|e| {
+        eprintln!("Error scanning directory: {}", e);
+        std::process::exit(1);
+    });
+
+    let report = scanner.analyze(files);
+
+    write_reports(&registry, &targets, &report);
+}
+
+/// Exits with an error when a target asks for a format the registry does not have.
+fn validate_targets<R>(registry: &ReporterRegistry<R>, targets: &[ReporterTarget]) {
+    for target in targets {
+        let reporter = registry
+            .validate_format(&target.format)
+            .unwrap_or_else(|e| {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            });
+        let Some(path) = &target.path else {
+            continue;
+        };
+        let extension = Path::new(path).extension().and_then(|ext| ext.to_str());
+        if extension.is_some_and(|ext| !ext.eq_ignore_ascii_case(reporter.file_extension())) {
+            log::debug!(
+                "Writing {} report to {}, although {} reports usually end in .{}",
+                target.format,
+                path,
+                target.format,
+                reporter.file_extension()
+            );
+        }
+    }
+}
+
+/// Writes the report once per target, to its file or to stdout.
+fn write_reports<R>(registry: &ReporterRegistry<R>, targets: &[ReporterTarget], report: &R) {
+    let output = |format: &str, writer: &mut dyn Write| registry.output(report, format, writer);
+    for target in targets {
+        let Some(path) = &target.path else {
+            output(&target.format, &mut io::stdout().lock()).unwrap_or_else(|e| {
+                eprintln!("Error outputting report: {}", e);
+                std::process::exit(1);
+            });
+            continue;
+        };
+
+        let parent = Path::new(path)
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty());
+        parent
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::File::create(path))
+            .and_then(|file| {
+                let mut writer = BufWriter::new(file);
+                output(&target.format, &mut writer)?;
+                writer.flush()
+            })
+            .unwrap_or_else(|e| {
+                eprintln!("Error writing report to {}: {}", path, e);
+                std::process::exit(1);
+            });
+        log::info!("Wrote {} report to {}", target.format, path);
+    }
 }
@@ -1,17 +1,35 @@
-use crate::entity::AppConfig;
+use crate::entity::{AppConfig, LanguageDefinition};
 use config::{Config, ConfigError, File};
 
 #[derive(Debug)]
 pub struct ConfigBuilder {
     pub exclude: Option<Vec<String>>,
-    pub reporter: Option<String>,
+    pub reporter: Option<Vec<String>>,
+    pub weight: Option<String>,
+    pub sniff_content: Option<bool>,
+    pub include_vendored: Option<bool>,
+    pub embedded: Option<bool>,
+    pub csv_files: Option<bool>,
+    pub markdown_files: Option<bool>,
+    pub kinds: Option<Vec<String>>,
+    pub languages: Option<Vec<LanguageDefinition>>,
 }
 
 impl ConfigBuilder {
-    pub fn from_cli_args(exclude: &Option<Vec<String>>, reporter: &Option<String>) -> Self {
+    /// Starts from the options given on the command line. Languages can only be defined
+    /// in a config file.
+    pub fn from_cli_args(cli_config: AppConfig) -> Self {
         Self {
-            exclude: exclude.clone(),
-            reporter: reporter.clone(),
+            exclude: cli_config.exclude,
+            reporter: cli_config.reporter,
+            weight: cli_config.weight,
+            sniff_content: cli_config.sniff_content,
+            include_vendored: cli_config.include_vendored,
+            embedded: cli_config.embedded,
+            csv_files: cli_config.csv_files,
+            markdown_files: cli_config.markdown_files,
+            kinds: cli_config.kinds,
+            languages: None,
         }
     }
 
@@ -27,6 +45,28 @@ impl ConfigBuilder {
             if self.reporter.is_none() {
                 self.reporter = file_config.reporter;
             }
+            if self.weight.is_none() {
+                self.weight = file_config.weight;
+            }
+            if self.sniff_content.is_none() {
+                self.sniff_content = file_config.sniff_content;
+            }
+            if self.include_vendored.is_none() {
+                self.include_vendored = file_config.include_vendored;
+            }
+            if self.embedded.is_none() {
+                self.embedded = file_config.embedded;
+            }
+            if self.csv_files.is_none() {
+                self.csv_files = file_config.csv_files;
+            }
+            if self.markdown_files.is_none() {
+                self.markdown_files = file_config.markdown_files;
+            }
+            if self.kinds.is_none() {
+                self.kinds = file_config.kinds;
+            }
+            self.languages = file_config.languages;
         }
         Ok(self)
     }
@@ -35,6 +75,14 @@ impl ConfigBuilder {
         AppConfig {
             exclude: self.exclude,
             reporter: self.reporter,
+            weight: self.weight,
+            sniff_content: self.sniff_content,
+            include_vendored: self.include_vendored,
+            embedded: self.embedded,
+            csv_files: self.csv_files,
+            markdown_files: self.markdown_files,
+            kinds: self.kinds,
+            languages: self.languages,
         }
     }
 
@@ -57,9 +105,13 @@ mod tests {
         #[test]
         fn test_from_cli_args_with_values() {
             let exclude = Some(vec!["*.log".to_string(), "exclude_dir".to_string()]);
-            let reporter = Some("json".to_string());
+            let reporter = Some(vec!["json".to_string()]);
 
-            let builder = ConfigBuilder::from_cli_args(&exclude, &reporter);
+            let builder = ConfigBuilder::from_cli_args(AppConfig {
+                exclude: exclude.clone(),
+                reporter: reporter.clone(),
+                ..Default::default()
+            });
 
             assert_eq!(builder.exclude, exclude);
             assert_eq!(builder.reporter, reporter);
@@ -67,7 +119,7 @@ mod tests {
 
         #[test]
         fn test_from_cli_args_with_none() {
-            let builder = ConfigBuilder::from_cli_args(&None, &None);
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
 
             assert_eq!(builder.exclude, None);
             assert_eq!(builder.reporter, None);
@@ -80,17 +132,32 @@ mod tests {
         #[test]
         fn test_build_with_values() {
             let exclude = Some(vec!["*.tmp".to_string()]);
-            let reporter = Some("json".to_string());
+            let reporter = Some(vec!["json".to_string()]);
 
             let builder = ConfigBuilder {
                 exclude: exclude.clone(),
                 reporter: reporter.clone(),
+                weight: Some("bytes".to_string()),
+                sniff_content: Some(true),
+                include_vendored: Some(true),
+                embedded: Some(true),
+                csv_files: Some(true),
+                markdown_files: Some(true),
+                kinds: Some(vec!["programming".to_string()]),
+                languages: None,
             };
 
             let config = builder.build();
 
             assert_eq!(config.exclude, exclude);
             assert_eq!(config.reporter, reporter);
+            assert_eq!(config.weight, Some("bytes".to_string()));
+            assert_eq!(config.sniff_content, Some(true));
+            assert_eq!(config.include_vendored, Some(true));
+            assert_eq!(config.embedded, Some(true));
+            assert_eq!(config.csv_files, Some(true));
+            assert_eq!(config.markdown_files, Some(true));
+            assert_eq!(config.kinds, Some(vec!["programming".to_string()]));
         }
 
         #[test]
@@ -98,12 +165,27 @@ mod tests {
             let builder = ConfigBuilder {
                 exclude: None,
                 reporter: None,
+                weight: None,
+                sniff_content: None,
+                include_vendored: None,
+                embedded: None,
+                csv_files: None,
+                markdown_files: None,
+                kinds: None,
+                languages: None,
             };
 
             let config = builder.build();
 
             assert_eq!(config.exclude, None);
             assert_eq!(config.reporter, None);
+            assert_eq!(config.weight, None);
+            assert_eq!(config.sniff_content, None);
+            assert_eq!(config.include_vendored, None);
+            assert_eq!(config.embedded, None);
+            assert_eq!(config.csv_files, None);
+            assert_eq!(config.markdown_files, None);
+            assert_eq!(config.kinds, None);
         }
     }
 
@@ -112,7 +194,7 @@ mod tests {
 
         #[test]
         fn test_merge_file_config_with_none() {
-            let builder = ConfigBuilder::from_cli_args(&None, &None);
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
             let result = builder.merge_file_config(&None);
 
             assert!(result.is_ok());
@@ -124,7 +206,7 @@ mod tests {
 
         #[test]
         fn test_merge_file_config_with_valid_json() {
-            let builder = ConfigBuilder::from_cli_args(&None, &None);
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
             let result = builder
                 .merge_file_config(&Some("tests/fixtures/cli/config/complete.json".to_string()));
 
@@ -135,12 +217,12 @@ mod tests {
                 builder.exclude,
                 Some(vec!["*.test.*".to_string(), "exclude_dir".to_string()])
             );
-            assert_eq!(builder.reporter, Some("json".to_string()));
+            assert_eq!(builder.reporter, Some(vec!["json".to_string()]));
         }
 
         #[test]
         fn test_merge_file_config_with_valid_json5() {
-            let builder = ConfigBuilder::from_cli_args(&None, &None);
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
             let result = builder.merge_file_config(&Some(
                 "tests/fixtures/cli/config/complete.json5".to_string(),
             ));
@@ -152,12 +234,12 @@ mod tests {
                 builder.exclude,
                 Some(vec!["*.test.*".to_string(), "exclude_dir".to_string()])
             );
-            assert_eq!(builder.reporter, Some("json".to_string()));
+            assert_eq!(builder.reporter, Some(vec!["json".to_string()]));
         }
 
         #[test]
         fn test_merge_file_config_with_valid_yaml() {
-            let builder = ConfigBuilder::from_cli_args(&None, &None);
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
             let result = builder
                 .merge_file_config(&Some("tests/fixtures/cli/config/complete.yaml".to_string()));
 
@@ -168,12 +250,12 @@ mod tests {
                 builder.exclude,
                 Some(vec!["*.test.*".to_string(), "exclude_dir".to_string()])
             );
-            assert_eq!(builder.reporter, Some("json".to_string()));
+            assert_eq!(builder.reporter, Some(vec!["json".to_string()]));
         }
 
         #[test]
         fn test_merge_file_config_with_valid_yml() {
-            let builder = ConfigBuilder::from_cli_args(&None, &None);
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
             let result = builder
                 .merge_file_config(&Some("tests/fixtures/cli/config/complete.yml".to_string()));
 
@@ -184,12 +266,12 @@ mod tests {
                 builder.exclude,
                 Some(vec!["*.test.*".to_string(), "exclude_dir".to_string()])
             );
-            assert_eq!(builder.reporter, Some("json".to_string()));
+            assert_eq!(builder.reporter, Some(vec!["json".to_string()]));
         }
 
         #[test]
         fn test_merge_file_config_with_valid_toml() {
-            let builder = ConfigBuilder::from_cli_args(&None, &None);
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
             let result = builder
                 .merge_file_config(&Some("tests/fixtures/cli/config/complete.toml".to_string()));
 
@@ -200,15 +282,19 @@ mod tests {
                 builder.exclude,
                 Some(vec!["*.test.*".to_string(), "exclude_dir".to_string()])
             );
-            assert_eq!(builder.reporter, Some("json".to_string()));
+            assert_eq!(builder.reporter, Some(vec!["json".to_string()]));
         }
 
         #[test]
         fn test_merge_file_config_cli_args_take_priority() {
             let cli_exclude = Some(vec!["*.cli".to_string()]);
-            let cli_reporter = Some("table".to_string());
+            let cli_reporter = Some(vec!["table".to_string()]);
 
-            let builder = ConfigBuilder::from_cli_args(&cli_exclude, &cli_reporter);
+            let builder = ConfigBuilder::from_cli_args(AppConfig {
+                exclude: cli_exclude.clone(),
+                reporter: cli_reporter.clone(),
+                ..Default::default()
+            });
             let result = builder
                 .merge_file_config(&Some("tests/fixtures/cli/config/complete.json".to_string()));
 
@@ -224,7 +310,11 @@ mod tests {
             let cli_exclude = Some(vec!["*.cli".to_string()]);
             let cli_reporter = None;
 
-            let builder = ConfigBuilder::from_cli_args(&cli_exclude, &cli_reporter);
+            let builder = ConfigBuilder::from_cli_args(AppConfig {
+                exclude: cli_exclude.clone(),
+                reporter: cli_reporter.clone(),
+                ..Default::default()
+            });
             let result = builder
                 .merge_file_config(&Some("tests/fixtures/cli/config/complete.json".to_string()));
 
@@ -232,12 +322,12 @@ mod tests {
 
             let builder = result.unwrap();
             assert_eq!(builder.exclude, cli_exclude);
-            assert_eq!(builder.reporter, Some("json".to_string()));
+            assert_eq!(builder.reporter, Some(vec!["json".to_string()]));
         }
 
         #[test]
         fn test_merge_file_config_with_partial_config_file() {
-            let builder = ConfigBuilder::from_cli_args(&None, &None);
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
             let result = builder.merge_file_config(&Some(
                 "tests/fixtures/cli/config/reporter_only.yaml".to_string(),
             ));
@@ -246,12 +336,134 @@ mod tests {
 
             let builder = result.unwrap();
             assert_eq!(builder.exclude, None);
-            assert_eq!(builder.reporter, Some("json".to_string()));
+            assert_eq!(builder.reporter, Some(vec!["json".to_string()]));
+        }
+
+        #[test]
+        fn test_merge_file_config_with_reporter_list() {
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
+            let result = builder
+                .merge_file_config(&Some("tests/fixtures/config/reporters.yaml".to_string()));
+
+            assert!(result.is_ok());
+
+            let builder = result.unwrap();
+            assert_eq!(
+                builder.reporter,
+                Some(vec!["table".to_string(), "json=report.json".to_string()])
+            );
+        }
+
+        #[test]
+        fn test_merge_file_config_with_weight() {
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
+            let result =
+                builder.merge_file_config(&Some("tests/fixtures/config/weight.yaml".to_string()));
+
+            assert!(result.is_ok());
+
+            let builder = result.unwrap();
+            assert_eq!(builder.weight, Some("lines".to_string()));
+            assert_eq!(builder.sniff_content, None);
+        }
+
+        #[test]
+        fn test_merge_file_config_with_full_config_file() {
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
+            let result =
+                builder.merge_file_config(&Some("tests/fixtures/config/full.yaml".to_string()));
+
+            assert!(result.is_ok());
+
+            let builder = result.unwrap();
+            assert_eq!(
+                builder.exclude,
+                Some(vec!["*.test.*".to_string(), "exclude_dir".to_string()])
+            );
+            assert_eq!(
+                builder.reporter,
+                Some(vec!["table".to_string(), "json=report.json".to_string()])
+            );
+            assert_eq!(builder.weight, Some("lines".to_string()));
+            assert_eq!(builder.sniff_content, Some(true));
+            assert_eq!(builder.include_vendored, Some(true));
+            assert_eq!(builder.embedded, Some(true));
+            assert_eq!(builder.csv_files, Some(true));
+            assert_eq!(builder.markdown_files, Some(true));
+            assert_eq!(
+                builder.kinds,
+                Some(vec!["programming".to_string(), "markup".to_string()])
+            );
+        }
+
+        #[test]
+        fn test_merge_file_config_cli_weight_takes_priority() {
+            let cli_weight = Some("bytes".to_string());
+
+            let builder = ConfigBuilder::from_cli_args(AppConfig {
+                weight: cli_weight.clone(),
+                ..Default::default()
+            });
+            let result =
+                builder.merge_file_config(&Some("tests/fixtures/config/weight.yaml".to_string()));
+
+            assert!(result.is_ok());
+
+            let builder = result.unwrap();
+            assert_eq!(builder.weight, cli_weight);
+        }
+
+        #[test]
+        fn test_merge_file_config_cli_disables_sniff_content() {
+            let builder = ConfigBuilder::from_cli_args(AppConfig {
+                sniff_content: Some(false),
+                ..Default::default()
+            });
+            let result =
+                builder.merge_file_config(&Some("tests/fixtures/config/full.yaml".to_string()));
+
+            assert!(result.is_ok());
+
+            let builder = result.unwrap();
+            assert_eq!(builder.sniff_content, Some(false));
+        }
+
+        #[test]
+        fn test_merge_file_config_with_languages() {
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
+            let result = builder
+                .merge_file_config(&Some("tests/fixtures/config/languages.yaml".to_string()));
+
+            assert!(result.is_ok());
+
+            let languages = result.unwrap().languages.unwrap();
+            assert_eq!(
+                languages,
+                vec![
+                    LanguageDefinition {
+                        name: "PDL".to_string(),
+                        exts: vec!["pdl".to_string()],
+                        line_comments: Some(vec!["#".to_string()]),
+                        block_comments: Some(vec![("/*".to_string(), "*/".to_string())]),
+                        ..Default::default()
+                    },
+                    LanguageDefinition {
+                        name: "C++".to_string(),
+                        exts: vec!["h".to_string()],
+                        ..Default::default()
+                    },
+                    LanguageDefinition {
+                        name: "COBOL".to_string(),
+                        disabled: true,
+                        ..Default::default()
+                    },
+                ]
+            );
         }
 
         #[test]
         fn test_merge_file_config_with_nonexistent_file() {
-            let builder = ConfigBuilder::from_cli_args(&None, &None);
+            let builder = ConfigBuilder::from_cli_args(AppConfig::default());
             let result = builder.merge_file_config(&Some("nonexistent/config.json".to_string()));
 
             assert!(result.is_err());
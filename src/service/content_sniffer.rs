@@ -0,0 +1,171 @@
+use crate::config::LanguageConfig;
+use crate::entity::Language;
+
+const MODELINE_SEARCH_LINES: usize = 5;
+
+pub struct ContentSniffer;
+
+impl ContentSniffer {
+    /// Detects a language from the head of a file, using the shebang on the first line
+    /// or a Vim/Emacs modeline within the first few lines.
+    pub fn sniff<'a>(head: &str, config: &'a LanguageConfig) -> Option<&'a Language> {
+        let mut lines = head.lines();
+        let first_line = lines.next()?;
+
+        if let Some(language) = Self::parse_shebang(first_line)
+            .and_then(|interpreter| config.detect_language_by_interpreter(&interpreter))
+        {
+            return Some(language);
+        }
+
+        std::iter::once(first_line)
+            .chain(lines)
+            .take(MODELINE_SEARCH_LINES)
+            .filter_map(Self::parse_modeline)
+            .find_map(|mode| Self::language_from_mode(&mode, config))
+    }
+
+    /// Returns the interpreter name of a shebang line, resolving `/usr/bin/env` indirection.
+    fn parse_shebang(line: &str) -> Option<String> {
+        let command = line.strip_prefix("#!")?;
+        let mut tokens = command.split_whitespace();
+        let program = Self::basename(tokens.next()?);
+
+        if program != "env" {
+            return Some(program.to_string());
+        }
+
+        tokens
+            .find(|token| !token.starts_with('-') && !token.contains('='))
+            .map(|token| Self::basename(token).to_string())
+    }
+
+    /// Returns the file type of a Vim (`vim: set ft=python:`) or Emacs (`-*- mode: ruby -*-`)
+    /// modeline.
+    fn parse_modeline(line: &str) -> Option<String> {
+        if let Some(start) = line.find("-*-") {
+            let rest = &line[start + 3..];
+            let body = &rest[..rest.find("-*-")?];
+            let mode = match body.split(';').find_map(|part| {
+                let (key, value) = part.split_once(':')?;
+                (key.trim().eq_ignore_ascii_case("mode")).then(|| value.trim())
+            }) {
+                Some(mode) => mode,
+                None if !body.contains(':') => body.trim(),
+                None => return None,
+            };
+            return (!mode.is_empty()).then(|| mode.to_string());
+        }
+
+        // As in Vim, the marker starts the line or follows whitespace, so that words such as
+        // `regex:` or `index:` are not taken for a modeline.
+        let marker_end = ["vim:", "vi:", "ex:"]
+            .iter()
+            .flat_map(|marker| {
+                line.match_indices(marker)
+                    .filter(|(pos, _)| {
+                        line[..*pos]
+                            .chars()
+                            .next_back()
+                            .is_none_or(char::is_whitespace)
+                    })
+                    .map(|(pos, _)| pos + marker.len())
+            })
+            .min()?;
+
+        line[marker_end..]
+            .split(|c: char| c.is_whitespace() || c == ':')
+            .find_map(|option| {
+                option
+                    .strip_prefix("filetype=")
+                    .or_else(|| option.strip_prefix("ft="))
+                    .or_else(|| option.strip_prefix("syntax="))
+                    .or_else(|| option.strip_prefix("syn="))
+            })
+            .filter(|mode| !mode.is_empty())
+            .map(|mode| mode.to_string())
+    }
+
+    /// Resolves a modeline file type by interpreter alias, then extension, then language name.
+    fn language_from_mode<'a>(mode: &str, config: &'a LanguageConfig) -> Option<&'a Language> {
+        let mode_lower = mode.to_lowercase();
+        config
+            .detect_language_by_interpreter(&mode_lower)
+            .or_else(|| config.detect_language(&mode_lower))
+            .or_else(|| config.get_language_by_name_ignore_case(mode))
+    }
+
+    fn basename(path: &str) -> &str {
+        path.rsplit('/').next().unwrap_or(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod sniff {
+        use super::*;
+
+        fn sniff(head: &str) -> Option<String> {
+            let config = LanguageConfig::default();
+            ContentSniffer::sniff(head, &config).map(|language| language.name.clone())
+        }
+
+        #[test]
+        fn test_sniff_env_shebang() {
+            let result = sniff("#!/usr/bin/env python3\nprint('hi')\n");
+            assert_eq!(result.unwrap(), "Python");
+        }
+
+        #[test]
+        fn test_sniff_env_shebang_with_flags() {
+            let result = sniff("#!/usr/bin/env -S node --no-warnings\n");
+            assert_eq!(result.unwrap(), "JavaScript");
+        }
+
+        #[test]
+        fn test_sniff_absolute_shebang() {
+            assert_eq!(sniff("#!/bin/bash\n").unwrap(), "Shell");
+            assert_eq!(sniff("#!/bin/zsh\n").unwrap(), "Shell");
+            assert_eq!(sniff("#!/usr/bin/perl -w\n").unwrap(), "Perl");
+            assert_eq!(sniff("#!/usr/local/bin/ruby\n").unwrap(), "Ruby");
+        }
+
+        #[test]
+        fn test_sniff_vim_modeline() {
+            let result = sniff("# vim: set ft=ruby:\nputs 1\n");
+            assert_eq!(result.unwrap(), "Ruby");
+
+            let result = sniff("\n\n# vi: filetype=sh\n");
+            assert_eq!(result.unwrap(), "Shell");
+        }
+
+        #[test]
+        fn test_sniff_vim_marker_inside_word() {
+            assert!(sniff("regex: ft=python\n").is_none());
+            assert!(sniff("  index: filetype=ruby\n").is_none());
+            assert!(sniff("#vim: ft=ruby\n").is_none());
+            assert_eq!(sniff("ex: ft=python\n").unwrap(), "Python");
+        }
+
+        #[test]
+        fn test_sniff_emacs_modeline() {
+            let result = sniff("# -*- mode: python; coding: utf-8 -*-\n");
+            assert_eq!(result.unwrap(), "Python");
+
+            let result = sniff(";; -*- lua -*-\n");
+            assert_eq!(result.unwrap(), "Lua");
+
+            let result = sniff("// -*- mode: rust -*-\n");
+            assert_eq!(result.unwrap(), "Rust");
+        }
+
+        #[test]
+        fn test_sniff_unknown_content() {
+            assert!(sniff("plain text\n").is_none());
+            assert!(sniff("#!/usr/bin/awk -f\n").is_none());
+            assert!(sniff("").is_none());
+        }
+    }
+}
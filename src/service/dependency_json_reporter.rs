@@ -0,0 +1,94 @@
+use crate::config::REPORTER_FORMAT_JSON;
+use crate::entity::DependencyReport;
+use crate::service::Reporter;
+use std::io::{self, Write};
+
+pub struct DependencyJsonReporter;
+
+impl Default for DependencyJsonReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DependencyJsonReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn to_json(&self, report: &DependencyReport) -> io::Result<String> {
+        serde_json::to_string_pretty(report)
+            .map_err(|e| io::Error::other(format!("JSON serialization error: {}", e)))
+    }
+}
+
+impl Reporter<DependencyReport> for DependencyJsonReporter {
+    fn name(&self) -> &str {
+        REPORTER_FORMAT_JSON
+    }
+
+    fn file_extension(&self) -> &str {
+        "json"
+    }
+
+    fn render(&self, report: &DependencyReport, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_json(report)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{
+        Dependency, DependencyScope, Ecosystem, Lockfile, Manifest, ResolvedDependency,
+    };
+
+    fn create_test_report() -> DependencyReport {
+        DependencyReport {
+            dir: "/test/path".to_string(),
+            manifests: vec![Manifest {
+                path: "/test/path/package.json".to_string(),
+                ecosystem: Ecosystem::Npm,
+                dependencies: vec![
+                    Dependency::new("react", Some("^18.2.0"), DependencyScope::Runtime),
+                    Dependency::new("local-lib", None, DependencyScope::Development),
+                ],
+            }],
+            lockfiles: vec![Lockfile {
+                path: "/test/path/package-lock.json".to_string(),
+                ecosystem: Ecosystem::Npm,
+                dependencies: vec![
+                    ResolvedDependency::new("lodash", "3.10.1", false),
+                    ResolvedDependency::new("react", "18.3.1", true),
+                ],
+            }],
+            languages: vec!["JavaScript".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_to_json_success() {
+        let reporter = DependencyJsonReporter::new();
+        let report = create_test_report();
+
+        let json_string = reporter.to_json(&report).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&json_string).expect("Generated JSON is invalid");
+
+        assert_eq!(parsed["manifests"][0]["ecosystem"], "npm");
+        assert_eq!(parsed["manifests"][0]["dependencies"][0]["name"], "react");
+        assert_eq!(
+            parsed["manifests"][0]["dependencies"][1]["version"],
+            serde_json::Value::Null
+        );
+        assert_eq!(
+            parsed["manifests"][0]["dependencies"][1]["scope"],
+            "development"
+        );
+        assert_eq!(
+            parsed["lockfiles"][0]["dependencies"][0]["version"],
+            "3.10.1"
+        );
+        assert_eq!(parsed["lockfiles"][0]["dependencies"][1]["direct"], true);
+    }
+}
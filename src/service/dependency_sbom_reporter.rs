@@ -0,0 +1,124 @@
+use crate::config::{REPORTER_FORMAT_CYCLONEDX, REPORTER_FORMAT_SPDX};
+use crate::entity::DependencyReport;
+use crate::service::{Reporter, SbomBuilder};
+use std::io::{self, Write};
+use std::time::SystemTime;
+
+enum SbomFormat {
+    CycloneDx,
+    Spdx,
+}
+
+/// Renders a dependency report as a CycloneDX or SPDX software bill of materials in JSON,
+/// stamped with the time it is rendered at.
+pub struct DependencySbomReporter {
+    format: SbomFormat,
+}
+
+impl DependencySbomReporter {
+    pub fn cyclonedx() -> Self {
+        Self {
+            format: SbomFormat::CycloneDx,
+        }
+    }
+
+    pub fn spdx() -> Self {
+        Self {
+            format: SbomFormat::Spdx,
+        }
+    }
+
+    fn to_sbom(&self, report: &DependencyReport, now: SystemTime) -> io::Result<String> {
+        let timestamp = SbomBuilder::timestamp(now);
+        let serialized = match self.format {
+            SbomFormat::CycloneDx => {
+                serde_json::to_string_pretty(&SbomBuilder::cyclonedx(report, &timestamp))
+            }
+            SbomFormat::Spdx => serde_json::to_string_pretty(&SbomBuilder::spdx(
+                report,
+                &timestamp,
+                &SbomBuilder::namespace(&report.dir, now),
+            )),
+        };
+        serialized.map_err(|e| io::Error::other(format!("JSON serialization error: {}", e)))
+    }
+}
+
+impl Reporter<DependencyReport> for DependencySbomReporter {
+    fn name(&self) -> &str {
+        match self.format {
+            SbomFormat::CycloneDx => REPORTER_FORMAT_CYCLONEDX,
+            SbomFormat::Spdx => REPORTER_FORMAT_SPDX,
+        }
+    }
+
+    fn file_extension(&self) -> &str {
+        "json"
+    }
+
+    fn render(&self, report: &DependencyReport, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_sbom(report, SystemTime::now())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{
+        Dependency, DependencyScope, Ecosystem, Lockfile, Manifest, ResolvedDependency,
+    };
+
+    fn create_test_report() -> DependencyReport {
+        DependencyReport {
+            dir: "/test/path".to_string(),
+            manifests: vec![Manifest {
+                path: "/test/path/package.json".to_string(),
+                ecosystem: Ecosystem::Npm,
+                dependencies: vec![
+                    Dependency::new("react", Some("^18.2.0"), DependencyScope::Runtime),
+                    Dependency::new("local-lib", None, DependencyScope::Development),
+                ],
+            }],
+            lockfiles: vec![Lockfile {
+                path: "/test/path/package-lock.json".to_string(),
+                ecosystem: Ecosystem::Npm,
+                dependencies: vec![
+                    ResolvedDependency::new("lodash", "3.10.1", false),
+                    ResolvedDependency::new("react", "18.3.1", true),
+                ],
+            }],
+            languages: vec!["JavaScript".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_to_cyclonedx_lists_lockfile_packages() {
+        let reporter = DependencySbomReporter::cyclonedx();
+        let report = create_test_report();
+
+        let bom_string = reporter.to_sbom(&report, SystemTime::UNIX_EPOCH).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&bom_string).expect("Generated JSON is invalid");
+
+        assert_eq!(parsed["metadata"]["timestamp"], "1970-01-01T00:00:00Z");
+        assert_eq!(parsed["components"][0]["purl"], "pkg:npm/lodash@3.10.1");
+        assert_eq!(parsed["components"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_to_spdx_describes_project() {
+        let reporter = DependencySbomReporter::spdx();
+        let report = create_test_report();
+
+        let document_string = reporter.to_sbom(&report, SystemTime::UNIX_EPOCH).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&document_string).expect("Generated JSON is invalid");
+
+        assert_eq!(parsed["dataLicense"], "CC0-1.0");
+        assert_eq!(parsed["relationships"][0]["relationshipType"], "DESCRIBES");
+        assert_eq!(
+            parsed["packages"][0]["comment"],
+            "Detected languages: JavaScript"
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use crate::config::{LanguageConfig, ManifestConfig};
+use crate::entity::{
+    DependencyReport, DependencyScannerOptions, File, FileAttributes, Lockfile, Manifest, Result,
+    TechScanError,
+};
+use crate::service::{FileClassifier, FileWalker, LockfileParser, ManifestParser};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct DependencyScanner {
+    dir: String,
+    opts: DependencyScannerOptions,
+    language_config: LanguageConfig,
+}
+
+impl DependencyScanner {
+    pub fn new(dir: &str, opts: Option<DependencyScannerOptions>) -> Result<Self> {
+        if !Path::new(dir).exists() {
+            return Err(TechScanError::DirectoryNotFound(dir.to_string()));
+        }
+
+        let opts = opts.unwrap_or_default();
+        let language_config = LanguageConfig::new(&opts.languages)?;
+
+        Ok(Self {
+            dir: dir.to_string(),
+            opts,
+            language_config,
+        })
+    }
+
+    pub fn scan(&self) -> Result<Vec<File>> {
+        FileWalker::walk(&self.dir, &self.opts.exclude)
+    }
+
+    /// Parses every manifest and lockfile among `files`. Files that cannot be read or
+    /// parsed are reported on stderr and skipped.
+    pub fn analyze(&self, files: Vec<File>) -> DependencyReport {
+        let mut manifests = Self::parse_manifests(&files);
+        let mut lockfiles: Vec<Lockfile> = files
+            .iter()
+            .filter_map(|file| {
+                let format = ManifestConfig::detect_lockfile_format(&file.name)?;
+                let content = Self::read(file)?;
+                let dependencies = LockfileParser::parse(format, &content)
+                    .map_err(|e| log::warn!("Error parsing lockfile {}: {}", file.path, e))
+                    .ok()?;
+
+                Some(Lockfile {
+                    path: file.path.clone(),
+                    ecosystem: format.ecosystem(),
+                    dependencies,
+                })
+            })
+            .collect();
+
+        for lockfile in &mut lockfiles {
+            Self::mark_declared_dependencies(lockfile, &manifests);
+        }
+
+        manifests.sort_by(|a, b| a.path.cmp(&b.path));
+        lockfiles.sort_by(|a, b| a.path.cmp(&b.path));
+
+        DependencyReport {
+            dir: self.dir.clone(),
+            manifests,
+            lockfiles,
+            languages: self.detect_languages(&files),
+        }
+    }
+
+    /// Parses every manifest among `files`, in walk order.
+    pub fn parse_manifests(files: &[File]) -> Vec<Manifest> {
+        files
+            .iter()
+            .filter_map(|file| {
+                let format = ManifestConfig::detect_format(&file.name)?;
+                let content = Self::read(file)?;
+                let dependencies = ManifestParser::parse(format, &content)
+                    .map_err(|e| log::warn!("Error parsing manifest {}: {}", file.path, e))
+                    .ok()?;
+
+                Some(Manifest {
+                    path: file.path.clone(),
+                    ecosystem: format.ecosystem(),
+                    dependencies,
+                })
+            })
+            .collect()
+    }
+
+    /// Detects languages from file names and extensions only, so that no source file has to
+    /// be read. Vendored and generated files are skipped.
+    fn detect_languages(&self, files: &[File]) -> Vec<String> {
+        let mut counts: HashMap<&str, u64> = HashMap::new();
+
+        for file in files {
+            let relative_path = Path::new(&file.path)
+                .strip_prefix(&self.dir)
+                .ok()
+                .and_then(|path| path.to_str())
+                .unwrap_or(&file.path);
+            if FileClassifier::classify_path(relative_path, &FileAttributes::default()).is_some() {
+                continue;
+            }
+            if let Some(language) = self.language_config.detect_file_language(file) {
+                if language.kind.counts_toward_percentage() {
+                    *counts.entry(language.name.as_str()).or_default() += 1;
+                }
+            }
+        }
+
+        let mut languages: Vec<(&str, u64)> = counts.into_iter().collect();
+        languages.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        languages
+            .into_iter()
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+
+    fn read(file: &File) -> Option<String> {
+        fs::read_to_string(&file.path)
+            .map_err(|e| log::warn!("Error reading file {}: {}", file.path, e))
+            .ok()
+    }
+
+    /// Marks packages declared by a manifest of the same ecosystem next to the lockfile as
+    /// direct, for lockfiles that do not record the project's own requirements.
+    fn mark_declared_dependencies(lockfile: &mut Lockfile, manifests: &[Manifest]) {
+        let dir = Path::new(&lockfile.path).parent();
+        let declared: HashSet<String> = manifests
+            .iter()
+            .filter(|manifest| {
+                manifest.ecosystem == lockfile.ecosystem
+                    && Path::new(&manifest.path).parent() == dir
+            })
+            .flat_map(|manifest| &manifest.dependencies)
+            .map(|dependency| lockfile.ecosystem.normalize_name(&dependency.name))
+            .collect();
+
+        for dependency in &mut lockfile.dependencies {
+            if declared.contains(&lockfile.ecosystem.normalize_name(&dependency.name)) {
+                dependency.direct = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{DependencyScope, Ecosystem, LanguageDefinition, LanguageKind};
+
+    #[test]
+    fn test_scanner_nonexistent_directory() {
+        let result = DependencyScanner::new("nonexistent_directory_12345", None);
+
+        assert!(matches!(result, Err(TechScanError::DirectoryNotFound(_))));
+    }
+
+    #[test]
+    fn test_analyze_finds_manifests() {
+        let scanner = DependencyScanner::new("tests/fixtures/deps", None)
+            .expect("DependencyScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let ecosystems: Vec<(&str, Ecosystem)> = report
+            .manifests
+            .iter()
+            .map(|manifest| (manifest.path.as_str(), manifest.ecosystem))
+            .collect();
+
+        assert_eq!(
+            ecosystems,
+            vec![
+                ("tests/fixtures/deps/Cargo.toml", Ecosystem::Cargo),
+                ("tests/fixtures/deps/Gemfile", Ecosystem::Rubygems),
+                (
+                    "tests/fixtures/deps/android/build.gradle.kts",
+                    Ecosystem::Maven
+                ),
+                ("tests/fixtures/deps/api/go.mod", Ecosystem::Go),
+                ("tests/fixtures/deps/app/mix.exs", Ecosystem::Hex),
+                ("tests/fixtures/deps/app/pubspec.yaml", Ecosystem::Pub),
+                (
+                    "tests/fixtures/deps/backend/composer.json",
+                    Ecosystem::Packagist
+                ),
+                ("tests/fixtures/deps/backend/pom.xml", Ecosystem::Maven),
+                ("tests/fixtures/deps/ml/pyproject.toml", Ecosystem::Pypi),
+                (
+                    "tests/fixtures/deps/ml/requirements-dev.txt",
+                    Ecosystem::Pypi
+                ),
+                ("tests/fixtures/deps/web/package.json", Ecosystem::Npm),
+            ]
+        );
+
+        let package_json = report
+            .manifests
+            .iter()
+            .find(|manifest| manifest.ecosystem == Ecosystem::Npm)
+            .unwrap();
+        assert_eq!(package_json.dependencies.len(), 2);
+        assert_eq!(package_json.dependencies[0].name, "react");
+        assert_eq!(
+            package_json.dependencies[0].version.as_deref(),
+            Some("^18.2.0")
+        );
+        assert_eq!(
+            package_json.dependencies[1].scope,
+            DependencyScope::Development
+        );
+    }
+
+    #[test]
+    fn test_analyze_resolves_lockfiles() {
+        let scanner = DependencyScanner::new("tests/fixtures/deps", None)
+            .expect("DependencyScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let lockfiles: Vec<&str> = report
+            .lockfiles
+            .iter()
+            .map(|lockfile| lockfile.path.as_str())
+            .collect();
+        assert_eq!(
+            lockfiles,
+            vec![
+                "tests/fixtures/deps/ml/poetry.lock",
+                "tests/fixtures/deps/web/package-lock.json",
+            ]
+        );
+
+        let resolved = |path: &str| -> Vec<(String, String, bool)> {
+            report
+                .lockfiles
+                .iter()
+                .find(|lockfile| lockfile.path.ends_with(path))
+                .unwrap()
+                .dependencies
+                .iter()
+                .map(|dependency| {
+                    (
+                        dependency.name.clone(),
+                        dependency.version.clone(),
+                        dependency.direct,
+                    )
+                })
+                .collect()
+        };
+        let entry = |name: &str, version: &str, direct: bool| {
+            (name.to_string(), version.to_string(), direct)
+        };
+
+        assert_eq!(
+            resolved("package-lock.json"),
+            vec![
+                entry("lodash", "3.10.1", false),
+                entry("loose-envify", "1.4.0", false),
+                entry("react", "18.3.1", true),
+                entry("vite", "5.2.11", true),
+            ]
+        );
+        // poetry.lock does not record direct requirements; pyproject.toml next to it does.
+        assert_eq!(
+            resolved("poetry.lock"),
+            vec![
+                entry("FastAPI", "0.110.3", true),
+                entry("numpy", "1.26.4", true),
+                entry("starlette", "0.37.2", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_analyze_detects_languages_with_config() {
+        let scanner = DependencyScanner::new("tests/fixtures/deps", None)
+            .expect("DependencyScanner creation should succeed");
+        let files = scanner.scan().expect("Scanning should succeed");
+        assert!(scanner
+            .analyze(files)
+            .languages
+            .contains(&"Elixir".to_string()));
+
+        let opts = DependencyScannerOptions {
+            languages: vec![LanguageDefinition {
+                name: "Mix".to_string(),
+                exts: vec!["exs".to_string()],
+                kind: Some(LanguageKind::Programming),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let scanner = DependencyScanner::new("tests/fixtures/deps", Some(opts))
+            .expect("DependencyScanner creation should succeed");
+        let files = scanner.scan().expect("Scanning should succeed");
+        let languages = scanner.analyze(files).languages;
+
+        assert!(languages.contains(&"Mix".to_string()));
+        assert!(!languages.contains(&"Elixir".to_string()));
+    }
+
+    #[test]
+    fn test_analyze_applies_exclude() {
+        let opts = DependencyScannerOptions {
+            exclude: vec!["web".to_string()],
+            ..Default::default()
+        };
+        let scanner = DependencyScanner::new("tests/fixtures/deps", Some(opts))
+            .expect("DependencyScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        assert!(report
+            .manifests
+            .iter()
+            .all(|manifest| manifest.ecosystem != Ecosystem::Npm));
+    }
+}
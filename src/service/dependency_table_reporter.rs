@@ -0,0 +1,220 @@
+use crate::config::REPORTER_FORMAT_TABLE;
+use crate::entity::{DependencyReport, Ecosystem};
+use crate::service::Reporter;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use tabled::builder::Builder;
+use tabled::settings::{object::Rows, Alignment, Modify, Style};
+
+pub struct DependencyTableReporter;
+
+impl Default for DependencyTableReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DependencyTableReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn to_table(&self, report: &DependencyReport) -> String {
+        let mut output = Vec::new();
+
+        let dependency_count: usize = report
+            .manifests
+            .iter()
+            .map(|manifest| manifest.dependencies.len())
+            .sum();
+        let resolved_count: usize = report
+            .lockfiles
+            .iter()
+            .map(|lockfile| lockfile.dependencies.len())
+            .sum();
+
+        let mut summary_builder = Builder::default();
+        summary_builder.push_record(vec!["Item", "Value"]);
+        summary_builder.push_record(vec!["Directory", &report.dir]);
+        summary_builder.push_record(vec!["Manifests", &report.manifests.len().to_string()]);
+        summary_builder.push_record(vec!["Dependencies", &dependency_count.to_string()]);
+        summary_builder.push_record(vec!["Lockfiles", &report.lockfiles.len().to_string()]);
+        summary_builder.push_record(vec!["Resolved Dependencies", &resolved_count.to_string()]);
+        summary_builder.push_record(vec![
+            "Languages",
+            &if report.languages.is_empty() {
+                "-".to_string()
+            } else {
+                report.languages.join(", ")
+            },
+        ]);
+
+        let summary_table = summary_builder
+            .build()
+            .with(Style::sharp())
+            .with(Modify::new(Rows::new(0..=0)).with(Alignment::center()))
+            .to_string();
+
+        output.push("=== Scan Summary ===".to_string());
+        output.push(summary_table);
+
+        if report.manifests.is_empty() && report.lockfiles.is_empty() {
+            return output.join("\n");
+        }
+
+        output.push(String::new());
+        output.push("=== Ecosystems ===".to_string());
+        output.push(Self::ecosystem_table(report));
+
+        let mut dependency_builder = Builder::default();
+        dependency_builder.push_record(vec![
+            "Ecosystem",
+            "Manifest",
+            "Dependency",
+            "Version",
+            "Scope",
+        ]);
+        for manifest in &report.manifests {
+            for dependency in &manifest.dependencies {
+                dependency_builder.push_record(vec![
+                    manifest.ecosystem.as_str(),
+                    &manifest.path,
+                    &dependency.name,
+                    dependency.version.as_deref().unwrap_or("-"),
+                    dependency.scope.as_str(),
+                ]);
+            }
+        }
+
+        if !report.manifests.is_empty() {
+            output.push(String::new());
+            output.push("=== Dependencies ===".to_string());
+            output.push(dependency_builder.build().with(Style::sharp()).to_string());
+        }
+
+        if !report.lockfiles.is_empty() {
+            output.push(String::new());
+            output.push("=== Resolved Dependencies ===".to_string());
+            output.push(Self::resolved_table(report));
+        }
+
+        output.join("\n")
+    }
+
+    fn resolved_table(report: &DependencyReport) -> String {
+        let mut builder = Builder::default();
+        builder.push_record(vec![
+            "Ecosystem",
+            "Lockfile",
+            "Dependency",
+            "Version",
+            "Direct",
+        ]);
+        for lockfile in &report.lockfiles {
+            for dependency in &lockfile.dependencies {
+                builder.push_record(vec![
+                    lockfile.ecosystem.as_str(),
+                    &lockfile.path,
+                    &dependency.name,
+                    &dependency.version,
+                    if dependency.direct { "yes" } else { "no" },
+                ]);
+            }
+        }
+
+        builder.build().with(Style::sharp()).to_string()
+    }
+
+    fn ecosystem_table(report: &DependencyReport) -> String {
+        let mut totals: BTreeMap<Ecosystem, (u64, u64, u64, u64)> = BTreeMap::new();
+        for manifest in &report.manifests {
+            let total = totals.entry(manifest.ecosystem).or_default();
+            total.0 += 1;
+            total.1 += manifest.dependencies.len() as u64;
+        }
+        for lockfile in &report.lockfiles {
+            let total = totals.entry(lockfile.ecosystem).or_default();
+            total.2 += 1;
+            total.3 += lockfile.dependencies.len() as u64;
+        }
+
+        let mut builder = Builder::default();
+        builder.push_record(vec![
+            "Ecosystem",
+            "Manifests",
+            "Dependencies",
+            "Lockfiles",
+            "Resolved",
+        ]);
+        for (ecosystem, (manifests, dependencies, lockfiles, resolved)) in totals {
+            builder.push_record(vec![
+                ecosystem.as_str(),
+                &manifests.to_string(),
+                &dependencies.to_string(),
+                &lockfiles.to_string(),
+                &resolved.to_string(),
+            ]);
+        }
+
+        builder.build().with(Style::sharp()).to_string()
+    }
+}
+
+impl Reporter<DependencyReport> for DependencyTableReporter {
+    fn name(&self) -> &str {
+        REPORTER_FORMAT_TABLE
+    }
+
+    fn file_extension(&self) -> &str {
+        "txt"
+    }
+
+    fn render(&self, report: &DependencyReport, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_table(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Dependency, DependencyScope, Lockfile, Manifest, ResolvedDependency};
+
+    fn create_test_report() -> DependencyReport {
+        DependencyReport {
+            dir: "/test/path".to_string(),
+            manifests: vec![Manifest {
+                path: "/test/path/package.json".to_string(),
+                ecosystem: Ecosystem::Npm,
+                dependencies: vec![
+                    Dependency::new("react", Some("^18.2.0"), DependencyScope::Runtime),
+                    Dependency::new("local-lib", None, DependencyScope::Development),
+                ],
+            }],
+            lockfiles: vec![Lockfile {
+                path: "/test/path/package-lock.json".to_string(),
+                ecosystem: Ecosystem::Npm,
+                dependencies: vec![
+                    ResolvedDependency::new("lodash", "3.10.1", false),
+                    ResolvedDependency::new("react", "18.3.1", true),
+                ],
+            }],
+            languages: vec!["JavaScript".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_to_table_lists_dependencies() {
+        let reporter = DependencyTableReporter::new();
+        let report = create_test_report();
+
+        let table_output = reporter.to_table(&report);
+
+        assert!(table_output.contains("=== Ecosystems ==="));
+        assert!(table_output.contains("=== Dependencies ==="));
+        assert!(table_output.contains("^18.2.0"));
+        assert!(table_output.contains("local-lib"));
+        assert!(table_output.contains("development"));
+        assert!(table_output.contains("=== Resolved Dependencies ==="));
+        assert!(table_output.contains("3.10.1"));
+    }
+}
@@ -0,0 +1,267 @@
+use crate::config::ClassificationConfig;
+use crate::entity::{FileAttributes, FileClassification, SourceRole};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use regex::RegexSet;
+use std::sync::OnceLock;
+
+/// Number of leading lines searched for generated-code markers.
+const MARKER_HEAD_LINES: usize = 20;
+
+struct CompiledClassifier {
+    vendored: GlobSet,
+    generated: GlobSet,
+    test: GlobSet,
+    fixture: GlobSet,
+    markers: RegexSet,
+}
+
+fn compiled_classifier() -> &'static CompiledClassifier {
+    static COMPILED: OnceLock<CompiledClassifier> = OnceLock::new();
+    COMPILED.get_or_init(|| CompiledClassifier {
+        vendored: build_glob_set(ClassificationConfig::vendored_patterns()),
+        generated: build_glob_set(ClassificationConfig::generated_patterns()),
+        test: build_glob_set(ClassificationConfig::test_patterns()),
+        fixture: build_glob_set(ClassificationConfig::fixture_patterns()),
+        markers: RegexSet::new(ClassificationConfig::generated_markers())
+            .expect("built-in generated markers must be valid regexes"),
+    })
+}
+
+fn build_glob_set(patterns: &[&str]) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern).expect("built-in classification globs must be valid"));
+    }
+    builder
+        .build()
+        .expect("built-in classification globs must be valid")
+}
+
+pub struct FileClassifier;
+
+impl FileClassifier {
+    /// Classifies a file by its path relative to the scanned directory. Attributes set
+    /// to false in `.gitattributes` turn the matching built-in rule off.
+    pub fn classify_path(
+        relative_path: &str,
+        attributes: &FileAttributes,
+    ) -> Option<FileClassification> {
+        let compiled = compiled_classifier();
+
+        if attributes.vendored != Some(false) && compiled.vendored.is_match(relative_path) {
+            Some(FileClassification::Vendored)
+        } else if attributes.generated != Some(false) && compiled.generated.is_match(relative_path)
+        {
+            Some(FileClassification::Generated)
+        } else {
+            None
+        }
+    }
+
+    /// Classifies a file as generated when its first lines carry a generator marker such
+    /// as `// Code generated ... DO NOT EDIT.` or `@generated`.
+    pub fn classify_content(
+        content: &str,
+        attributes: &FileAttributes,
+    ) -> Option<FileClassification> {
+        if attributes.generated == Some(false) {
+            return None;
+        }
+
+        let head_end = content
+            .match_indices('\n')
+            .nth(MARKER_HEAD_LINES - 1)
+            .map_or(content.len(), |(pos, _)| pos);
+
+        compiled_classifier()
+            .markers
+            .is_match(&content[..head_end])
+            .then_some(FileClassification::Generated)
+    }
+
+    /// Tells test sources and fixtures from production code by their path relative to the
+    /// scanned directory.
+    pub fn classify_role(relative_path: &str) -> SourceRole {
+        let compiled = compiled_classifier();
+
+        if compiled.fixture.is_match(relative_path) {
+            SourceRole::Fixture
+        } else if compiled.test.is_match(relative_path) {
+            SourceRole::Test
+        } else {
+            SourceRole::Production
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod classify_path {
+        use super::*;
+
+        #[test]
+        fn test_vendored_paths() {
+            let attributes = FileAttributes::default();
+
+            for path in [
+                "vendor/github.com/pkg/errors/errors.go",
+                "web/node_modules/react/index.js",
+                "third_party/zlib/zlib.c",
+                "static/jquery.min.js",
+            ] {
+                assert_eq!(
+                    FileClassifier::classify_path(path, &attributes),
+                    Some(FileClassification::Vendored),
+                    "{}",
+                    path
+                );
+            }
+        }
+
+        #[test]
+        fn test_generated_paths() {
+            let attributes = FileAttributes::default();
+
+            for path in ["api/service.pb.go", "src/schema_generated.rs", "Cargo.lock"] {
+                assert_eq!(
+                    FileClassifier::classify_path(path, &attributes),
+                    Some(FileClassification::Generated),
+                    "{}",
+                    path
+                );
+            }
+        }
+
+        #[test]
+        fn test_regular_paths() {
+            let attributes = FileAttributes::default();
+
+            assert_eq!(
+                FileClassifier::classify_path("src/main.rs", &attributes),
+                None
+            );
+            assert_eq!(
+                FileClassifier::classify_path("src/vendors.rs", &attributes),
+                None
+            );
+        }
+
+        #[test]
+        fn test_attributes_disable_rules() {
+            let attributes = FileAttributes {
+                vendored: Some(false),
+                ..Default::default()
+            };
+
+            assert_eq!(
+                FileClassifier::classify_path("vendor/lib.go", &attributes),
+                None
+            );
+        }
+    }
+
+    mod classify_content {
+        use super::*;
+
+        #[test]
+        fn test_generated_markers() {
+            let attributes = FileAttributes::default();
+
+            for content in [
+                "// Code generated by protoc-gen-go. DO NOT EDIT.\npackage api\n",
+                "/**\n * @generated SignedSource<<abc>>\n */\n",
+                "// <auto-generated>\n//   This code was generated by a tool.\n",
+            ] {
+                assert_eq!(
+                    FileClassifier::classify_content(content, &attributes),
+                    Some(FileClassification::Generated),
+                    "{}",
+                    content
+                );
+            }
+        }
+
+        #[test]
+        fn test_marker_outside_head() {
+            let attributes = FileAttributes::default();
+            let content = format!("{}// @generated\n", "x = 1\n".repeat(MARKER_HEAD_LINES));
+
+            assert_eq!(
+                FileClassifier::classify_content(&content, &attributes),
+                None
+            );
+        }
+
+        #[test]
+        fn test_attributes_disable_markers() {
+            let attributes = FileAttributes {
+                generated: Some(false),
+                ..Default::default()
+            };
+
+            assert_eq!(
+                FileClassifier::classify_content("// @generated\n", &attributes),
+                None
+            );
+        }
+    }
+
+    mod classify_role {
+        use super::*;
+
+        #[test]
+        fn test_test_paths() {
+            for path in [
+                "pkg/server/server_test.go",
+                "web/src/app.spec.ts",
+                "web/src/components/__tests__/Button.jsx",
+                "src/test/java/com/example/AppTest.java",
+                "app/tests/test_models.py",
+                "tests/integration.rs",
+                "spec/models/user_spec.rb",
+            ] {
+                assert_eq!(
+                    FileClassifier::classify_role(path),
+                    SourceRole::Test,
+                    "{}",
+                    path
+                );
+            }
+        }
+
+        #[test]
+        fn test_fixture_paths() {
+            for path in [
+                "tests/fixtures/web/index.js",
+                "pkg/parser/testdata/input.go",
+                "examples/basic.rs",
+            ] {
+                assert_eq!(
+                    FileClassifier::classify_role(path),
+                    SourceRole::Fixture,
+                    "{}",
+                    path
+                );
+            }
+        }
+
+        #[test]
+        fn test_production_paths() {
+            for path in [
+                "src/main.rs",
+                "pkg/server/server.go",
+                "web/src/testing-library.ts",
+                "app/contest.py",
+            ] {
+                assert_eq!(
+                    FileClassifier::classify_role(path),
+                    SourceRole::Production,
+                    "{}",
+                    path
+                );
+            }
+        }
+    }
+}
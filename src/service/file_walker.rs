@@ -0,0 +1,75 @@
+use crate::entity::{File, Result, TechScanError};
+use ignore::{overrides::OverrideBuilder, Walk, WalkBuilder};
+
+const GLOBAL_EXCLUDE_PATH: [&str; 2] = [".git", ".DS_Store"];
+
+/// Walks a directory the same way for every command: `.gitignore` files are respected,
+/// hidden files are included, and `exclude` patterns are applied on top.
+pub struct FileWalker;
+
+impl FileWalker {
+    pub fn walk(dir: &str, exclude: &[String]) -> Result<Vec<File>> {
+        let entries = Self::_walk_dir(dir, exclude)?;
+        let mut files = Vec::new();
+
+        for entry in entries {
+            match entry {
+                Ok(entry) => {
+                    if entry.file_type().is_none_or(|ft| !ft.is_file()) {
+                        continue;
+                    }
+                    match File::from_dir_entry(&entry) {
+                        Ok(file) => files.push(file),
+                        Err(e) => {
+                            log::warn!("Error processing file {}: {}", entry.path().display(), e,)
+                        }
+                    }
+                }
+                Err(e) => {
+                    log::warn!("Error reading entry: {}", e);
+                }
+            }
+        }
+
+        log::debug!("Found {} files in {}", files.len(), dir);
+        Ok(files)
+    }
+
+    fn _walk_dir(dir: &str, exclude: &[String]) -> Result<Walk> {
+        let mut override_builder = OverrideBuilder::new(dir);
+
+        for pattern in GLOBAL_EXCLUDE_PATH.iter() {
+            override_builder
+                .add(&format!("!{}", pattern))
+                .map_err(|e| {
+                    TechScanError::ValidationError(format!(
+                        "Failed to add global exclude pattern '{}': {}",
+                        pattern, e
+                    ))
+                })?;
+        }
+
+        for pattern in exclude {
+            override_builder
+                .add(&format!("!{}", pattern))
+                .map_err(|e| {
+                    TechScanError::ValidationError(format!(
+                        "Failed to add exclude pattern '{}': {}",
+                        pattern, e
+                    ))
+                })?;
+        }
+
+        let overrides = override_builder.build().map_err(|e| {
+            TechScanError::ValidationError(format!("Failed to build overrides: {}", e))
+        })?;
+
+        let entries = WalkBuilder::new(dir)
+            .git_ignore(true)
+            .hidden(false)
+            .overrides(overrides)
+            .build();
+
+        Ok(entries)
+    }
+}
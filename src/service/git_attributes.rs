@@ -0,0 +1,250 @@
+use crate::entity::FileAttributes;
+use globset::{GlobBuilder, GlobMatcher};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const GIT_ATTRIBUTES_FILENAME: &str = ".gitattributes";
+
+const ATTR_LANGUAGE: &str = "linguist-language";
+const ATTR_VENDORED: &str = "linguist-vendored";
+const ATTR_GENERATED: &str = "linguist-generated";
+const ATTR_DOCUMENTATION: &str = "linguist-documentation";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AttributeState {
+    Set,
+    Unset,
+    Unspecified,
+    Value(String),
+}
+
+#[derive(Debug)]
+struct AttributeRule {
+    base: PathBuf,
+    matcher: GlobMatcher,
+    match_basename: bool,
+    attributes: Vec<(String, AttributeState)>,
+}
+
+/// Linguist attributes collected from every `.gitattributes` file of a scanned tree.
+#[derive(Debug, Default)]
+pub struct GitAttributes {
+    rules: Vec<AttributeRule>,
+}
+
+impl GitAttributes {
+    /// Loads `.gitattributes` files. Files in deeper directories take precedence over
+    /// files closer to the root, as in git.
+    pub fn load(paths: &[&str]) -> Self {
+        let mut paths = paths.to_vec();
+        paths.sort_by_key(|path| Path::new(path).components().count());
+
+        let mut rules = Vec::new();
+        for path in paths {
+            match fs::read_to_string(path) {
+                Ok(content) => {
+                    let base = Path::new(path).parent().unwrap_or(Path::new(""));
+                    rules.extend(Self::parse(base, &content));
+                }
+                Err(e) => log::warn!("Error reading file {}: {}", path, e),
+            }
+        }
+
+        Self { rules }
+    }
+
+    /// Resolves the linguist attributes of `path`. Later rules override earlier ones.
+    pub fn attributes_for(&self, path: &str) -> FileAttributes {
+        let path = Path::new(path);
+        let mut attributes = FileAttributes::default();
+
+        for rule in &self.rules {
+            let Ok(relative) = path.strip_prefix(&rule.base) else {
+                continue;
+            };
+            let target = if rule.match_basename {
+                match relative.file_name() {
+                    Some(name) => Path::new(name),
+                    None => continue,
+                }
+            } else {
+                relative
+            };
+            if !rule.matcher.is_match(target) {
+                continue;
+            }
+
+            for (name, state) in &rule.attributes {
+                match name.as_str() {
+                    ATTR_LANGUAGE => {
+                        attributes.language = match state {
+                            AttributeState::Value(value) => Some(value.clone()),
+                            _ => None,
+                        }
+                    }
+                    ATTR_VENDORED => attributes.vendored = Self::to_bool(state),
+                    ATTR_GENERATED => attributes.generated = Self::to_bool(state),
+                    ATTR_DOCUMENTATION => attributes.documentation = Self::to_bool(state),
+                    _ => {}
+                }
+            }
+        }
+
+        attributes
+    }
+
+    fn parse(base: &Path, content: &str) -> Vec<AttributeRule> {
+        content
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                if line.is_empty() || line.starts_with('#') {
+                    return None;
+                }
+
+                let mut tokens = line.split_whitespace();
+                let pattern = tokens.next()?;
+                let attributes: Vec<(String, AttributeState)> = tokens
+                    .map(Self::parse_attribute)
+                    .filter(|(name, _)| name.starts_with("linguist-"))
+                    .collect();
+                if attributes.is_empty() {
+                    return None;
+                }
+
+                // Patterns without a slash match the file name at any depth, others are
+                // relative to the directory of the `.gitattributes` file.
+                let match_basename = !pattern.contains('/');
+                let glob = pattern.trim_start_matches('/');
+                let matcher = match GlobBuilder::new(glob).literal_separator(true).build() {
+                    Ok(glob) => glob.compile_matcher(),
+                    Err(e) => {
+                        log::warn!("Invalid pattern '{}' in .gitattributes: {}", pattern, e);
+                        return None;
+                    }
+                };
+
+                Some(AttributeRule {
+                    base: base.to_path_buf(),
+                    matcher,
+                    match_basename,
+                    attributes,
+                })
+            })
+            .collect()
+    }
+
+    fn parse_attribute(token: &str) -> (String, AttributeState) {
+        if let Some(name) = token.strip_prefix('-') {
+            (name.to_string(), AttributeState::Unset)
+        } else if let Some(name) = token.strip_prefix('!') {
+            (name.to_string(), AttributeState::Unspecified)
+        } else if let Some((name, value)) = token.split_once('=') {
+            (name.to_string(), AttributeState::Value(value.to_string()))
+        } else {
+            (token.to_string(), AttributeState::Set)
+        }
+    }
+
+    fn to_bool(state: &AttributeState) -> Option<bool> {
+        match state {
+            AttributeState::Set => Some(true),
+            AttributeState::Unset => Some(false),
+            AttributeState::Unspecified => None,
+            AttributeState::Value(value) => Some(value != "false"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_content(base: &str, content: &str) -> GitAttributes {
+        GitAttributes {
+            rules: GitAttributes::parse(Path::new(base), content),
+        }
+    }
+
+    mod attributes_for {
+        use super::*;
+
+        #[test]
+        fn test_language_override() {
+            let attributes = from_content("repo", "*.inc linguist-language=PHP\n");
+
+            let result = attributes.attributes_for("repo/lib/header.inc");
+            assert_eq!(result.language, Some("PHP".to_string()));
+
+            let result = attributes.attributes_for("other/header.inc");
+            assert_eq!(result.language, None);
+        }
+
+        #[test]
+        fn test_anchored_pattern() {
+            let attributes = from_content("repo", "/vendor/** linguist-vendored\n");
+
+            let result = attributes.attributes_for("repo/vendor/lib/a.js");
+            assert_eq!(result.vendored, Some(true));
+
+            let result = attributes.attributes_for("repo/src/vendor/a.js");
+            assert_eq!(result.vendored, None);
+        }
+
+        #[test]
+        fn test_boolean_states() {
+            let attributes = from_content(
+                "",
+                "*.pb.go linguist-generated=true\ndocs/* linguist-documentation\ndocs/keep.md -linguist-documentation\n",
+            );
+
+            assert_eq!(attributes.attributes_for("api.pb.go").generated, Some(true));
+            assert_eq!(
+                attributes.attributes_for("docs/guide.md").documentation,
+                Some(true)
+            );
+            assert_eq!(
+                attributes.attributes_for("docs/keep.md").documentation,
+                Some(false)
+            );
+            assert_eq!(
+                attributes.attributes_for("docs/sub/a.md").documentation,
+                None
+            );
+        }
+
+        #[test]
+        fn test_later_rules_override() {
+            let attributes =
+                from_content("", "*.js linguist-vendored\n*.js linguist-vendored=false\n");
+
+            assert_eq!(attributes.attributes_for("a.js").vendored, Some(false));
+        }
+
+        #[test]
+        fn test_ignores_comments_and_other_attributes() {
+            let attributes = from_content("", "# comment\n*.png binary\n*.sh text eol=lf\n");
+
+            assert!(attributes.rules.is_empty());
+        }
+    }
+
+    mod load {
+        use super::*;
+
+        #[test]
+        fn test_load_nested_files() {
+            let attributes = GitAttributes::load(&[
+                "tests/fixtures/gitattributes/lib/.gitattributes",
+                "tests/fixtures/gitattributes/.gitattributes",
+            ]);
+
+            let result = attributes.attributes_for("tests/fixtures/gitattributes/lib/legacy.js");
+            assert_eq!(result.vendored, Some(false));
+
+            let result =
+                attributes.attributes_for("tests/fixtures/gitattributes/third_party/lib.js");
+            assert_eq!(result.vendored, Some(true));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::config::{HeuristicConfig, LanguageConfig};
+use crate::entity::{Heuristic, Language};
+use regex::RegexSet;
+use std::sync::OnceLock;
+
+struct CompiledHeuristic {
+    heuristic: Heuristic,
+    rules: Vec<(&'static str, RegexSet)>,
+}
+
+fn compiled_heuristics() -> &'static [CompiledHeuristic] {
+    static COMPILED: OnceLock<Vec<CompiledHeuristic>> = OnceLock::new();
+    COMPILED.get_or_init(|| {
+        HeuristicConfig::heuristics()
+            .iter()
+            .map(|heuristic| CompiledHeuristic {
+                heuristic: *heuristic,
+                rules: heuristic
+                    .rules
+                    .iter()
+                    .map(|rule| {
+                        let patterns = RegexSet::new(rule.patterns)
+                            .expect("built-in heuristic patterns must be valid regexes");
+                        (rule.language, patterns)
+                    })
+                    .collect(),
+            })
+            .collect()
+    })
+}
+
+pub struct HeuristicEngine;
+
+impl HeuristicEngine {
+    /// Picks the language of a file with an ambiguous extension from its content.
+    /// Rules for languages missing from `config` are skipped. Returns `None` when `ext`
+    /// has no heuristic.
+    pub fn disambiguate<'a>(
+        ext: &str,
+        content: &str,
+        config: &'a LanguageConfig,
+    ) -> Option<&'a Language> {
+        let compiled = Self::find(ext)?;
+
+        compiled
+            .rules
+            .iter()
+            .filter_map(|(name, patterns)| {
+                config
+                    .get_language_by_name(name)
+                    .map(|language| (language, patterns))
+            })
+            .find(|(_, patterns)| patterns.is_match(content))
+            .map(|(language, _)| language)
+            .or_else(|| config.get_language_by_name(compiled.heuristic.fallback))
+    }
+
+    fn find(ext: &str) -> Option<&'static CompiledHeuristic> {
+        compiled_heuristics()
+            .iter()
+            .find(|compiled| compiled.heuristic.exts.contains(&ext))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod disambiguate {
+        use super::*;
+        use crate::entity::LanguageDefinition;
+
+        fn detect(ext: &str, content: &str) -> String {
+            let config = LanguageConfig::default();
+            HeuristicEngine::disambiguate(ext, content, &config)
+                .unwrap()
+                .name
+                .clone()
+        }
+
+        #[test]
+        fn test_disambiguate_header() {
+            assert_eq!(
+                detect("h", "@interface Foo : NSObject\n@end\n"),
+                "Objective-C"
+            );
+            assert_eq!(detect("h", "namespace app {\nclass Foo {};\n}\n"), "C++");
+            assert_eq!(detect("h", "int add(int a, int b);\n"), "C");
+        }
+
+        #[test]
+        fn test_disambiguate_m() {
+            assert_eq!(
+                detect("m", "#import <Foundation/Foundation.h>\n"),
+                "Objective-C"
+            );
+            assert_eq!(detect("m", ":- module hello.\n"), "Mercury");
+            assert_eq!(detect("m", "% compute\nfunction y = f(x)\n"), "MATLAB");
+            assert_eq!(detect("m", ""), "Objective-C");
+        }
+
+        #[test]
+        fn test_disambiguate_pl() {
+            assert_eq!(detect("pl", "use strict;\nmy $x = 1;\n"), "Perl");
+            assert_eq!(
+                detect("pl", "parent(tom, bob).\nchild(X) :- parent(_, X).\n"),
+                "Prolog"
+            );
+            assert_eq!(detect("pl", "print 1;\n"), "Perl");
+        }
+
+        #[test]
+        fn test_disambiguate_r() {
+            assert_eq!(detect("r", "REBOL [Title: \"Hello\"]\n"), "Rebol");
+            assert_eq!(detect("r", "x <- c(1, 2, 3)\n"), "R");
+        }
+
+        #[test]
+        fn test_disambiguate_ts() {
+            assert_eq!(
+                detect(
+                    "ts",
+                    "<?xml version=\"1.0\"?>\n<!DOCTYPE TS>\n<TS version=\"2.1\">\n"
+                ),
+                "Qt Translation"
+            );
+            assert_eq!(detect("ts", "const x: number = 1;\n"), "TypeScript");
+        }
+
+        #[test]
+        fn test_disambiguate_unambiguous_extension() {
+            let config = LanguageConfig::default();
+            assert!(HeuristicEngine::disambiguate("rs", "fn main() {}", &config).is_none());
+        }
+
+        #[test]
+        fn test_disambiguate_skips_disabled_language() {
+            let definitions = vec![LanguageDefinition {
+                name: "MATLAB".to_string(),
+                disabled: true,
+                ..Default::default()
+            }];
+            let config = LanguageConfig::new(&definitions).unwrap();
+
+            let result = HeuristicEngine::disambiguate("m", "% comment\n", &config);
+            assert_eq!(result.unwrap().name, "Objective-C");
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use crate::config::{REPORTER_FORMAT_CSV, REPORTER_FORMAT_TSV};
+use crate::entity::LanguageReport;
+use crate::service::Reporter;
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+/// Record separator required by RFC 4180.
+const LINE_ENDING: &str = "\r\n";
+
+const LANGUAGE_HEADER: &[&str] = &[
+    "language",
+    "kind",
+    "files",
+    "percentage",
+    "bytes",
+    "code_lines",
+    "comment_lines",
+    "blank_lines",
+    "production_files",
+    "test_files",
+    "fixture_files",
+    "production_code",
+    "test_code",
+    "fixture_code",
+];
+const FILE_HEADER: &[&str] = &["path", "language", "kind"];
+
+/// Renders a language report as comma- or tab-separated values, with one row per language
+/// or, with `with_file_rows`, one row per file. Fields are quoted as RFC 4180 describes, so
+/// values containing the delimiter, quotes or line breaks stay in their column.
+pub struct LanguageCsvReporter {
+    name: &'static str,
+    delimiter: char,
+    file_rows: bool,
+}
+
+impl LanguageCsvReporter {
+    pub fn csv() -> Self {
+        Self {
+            name: REPORTER_FORMAT_CSV,
+            delimiter: ',',
+            file_rows: false,
+        }
+    }
+
+    pub fn tsv() -> Self {
+        Self {
+            name: REPORTER_FORMAT_TSV,
+            delimiter: '\t',
+            file_rows: false,
+        }
+    }
+
+    pub fn with_file_rows(self) -> Self {
+        Self {
+            file_rows: true,
+            ..self
+        }
+    }
+
+    fn to_csv(&self, report: &LanguageReport) -> String {
+        let mut rows: Vec<Vec<String>> = Vec::new();
+
+        if self.file_rows {
+            rows.push(FILE_HEADER.iter().map(|field| field.to_string()).collect());
+            for item in &report.languages {
+                for path in &item.file_paths {
+                    rows.push(vec![
+                        path.clone(),
+                        item.language.name.clone(),
+                        item.language.kind.as_str().to_string(),
+                    ]);
+                }
+            }
+        } else {
+            rows.push(
+                LANGUAGE_HEADER
+                    .iter()
+                    .map(|field| field.to_string())
+                    .collect(),
+            );
+            for item in &report.languages {
+                rows.push(vec![
+                    item.language.name.clone(),
+                    item.language.kind.as_str().to_string(),
+                    item.file_count.to_string(),
+                    report
+                        .percentage(item)
+                        .map_or_else(String::new, |percentage| format!("{:.2}", percentage)),
+                    item.bytes.to_string(),
+                    item.code_lines.to_string(),
+                    item.comment_lines.to_string(),
+                    item.blank_lines.to_string(),
+                    item.production.file_count.to_string(),
+                    item.test.file_count.to_string(),
+                    item.fixture.file_count.to_string(),
+                    item.production.code_lines.to_string(),
+                    item.test.code_lines.to_string(),
+                    item.fixture.code_lines.to_string(),
+                ]);
+            }
+        }
+
+        rows.iter()
+            .map(|row| {
+                let fields: Vec<Cow<str>> = row.iter().map(|field| self.quote(field)).collect();
+                fields.join(&self.delimiter.to_string()) + LINE_ENDING
+            })
+            .collect()
+    }
+
+    /// Encloses a field in double quotes, doubling the quotes inside it, when it contains
+    /// the delimiter, a quote or a line break.
+    fn quote<'a>(&self, field: &'a str) -> Cow<'a, str> {
+        if field.contains([self.delimiter, '"', '\r', '\n']) {
+            Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+        } else {
+            Cow::Borrowed(field)
+        }
+    }
+}
+
+impl Reporter<LanguageReport> for LanguageCsvReporter {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn file_extension(&self) -> &str {
+        self.name
+    }
+
+    fn render(&self, report: &LanguageReport, writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_all(self.to_csv(report).as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Language, LanguageKind, LanguageReportItem, SourceStats, Weight};
+
+    fn create_item(
+        name: &str,
+        kind: LanguageKind,
+        file_paths: &[&str],
+        bytes: u64,
+    ) -> LanguageReportItem {
+        let mut item = LanguageReportItem::new(Language {
+            kind,
+            ..Language::new(name, &[])
+        });
+        item.file_count = file_paths.len() as u64;
+        item.file_paths = file_paths.iter().map(|path| path.to_string()).collect();
+        item.bytes = bytes;
+        item.code_lines = 10;
+        item
+    }
+
+    fn create_test_report() -> LanguageReport {
+        let mut rust = create_item(
+            "Rust",
+            LanguageKind::Programming,
+            &["src/main.rs", "src/a,b.rs"],
+            300,
+        );
+        rust.production = SourceStats {
+            file_count: 1,
+            code_lines: 7,
+        };
+        rust.test = SourceStats {
+            file_count: 1,
+            code_lines: 3,
+        };
+
+        LanguageReport {
+            dir: "/test/path".to_string(),
+            total_file_count: 4,
+            weight: Weight::Files,
+            kinds: vec![],
+            languages: vec![
+                rust,
+                create_item("C#", LanguageKind::Programming, &["src/say \"hi\".cs"], 100),
+                create_item("JSON", LanguageKind::Data, &["data\tset.json"], 50),
+            ],
+            classified_files: vec![],
+        }
+    }
+
+    mod to_csv {
+        use super::*;
+
+        #[test]
+        fn test_language_rows() {
+            let output = LanguageCsvReporter::csv().to_csv(&create_test_report());
+
+            assert_eq!(
+                output,
+                "language,kind,files,percentage,bytes,code_lines,comment_lines,blank_lines,\
+                 production_files,test_files,fixture_files,production_code,test_code,fixture_code\r\n\
+                 Rust,programming,2,66.67,300,10,0,0,1,1,0,7,3,0\r\n\
+                 C#,programming,1,33.33,100,10,0,0,0,0,0,0,0,0\r\n\
+                 JSON,data,1,,50,10,0,0,0,0,0,0,0,0\r\n"
+            );
+        }
+
+        #[test]
+        fn test_file_rows_are_quoted() {
+            let output = LanguageCsvReporter::csv()
+                .with_file_rows()
+                .to_csv(&create_test_report());
+
+            assert_eq!(
+                output,
+                "path,language,kind\r\n\
+                 src/main.rs,Rust,programming\r\n\
+                 \"src/a,b.rs\",Rust,programming\r\n\
+                 \"src/say \"\"hi\"\".cs\",C#,programming\r\n\
+                 data\tset.json,JSON,data\r\n"
+            );
+        }
+
+        #[test]
+        fn test_tsv_quotes_tabs() {
+            let output = LanguageCsvReporter::tsv()
+                .with_file_rows()
+                .to_csv(&create_test_report());
+
+            assert!(output.starts_with("path\tlanguage\tkind\r\n"));
+            assert!(output.contains("src/a,b.rs\tRust\tprogramming\r\n"));
+            assert!(output.contains("\"data\tset.json\"\tJSON\tdata\r\n"));
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::config::REPORTER_FORMAT_JSON;
+use crate::entity::LanguageReport;
+use crate::service::Reporter;
+use std::io::{self, Write};
+
+pub struct LanguageJsonReporter;
+
+impl Default for LanguageJsonReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguageJsonReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn to_json(&self, report: &LanguageReport) -> io::Result<String> {
+        serde_json::to_string_pretty(report)
+            .map_err(|e| io::Error::other(format!("JSON serialization error: {}", e)))
+    }
+}
+
+impl Reporter<LanguageReport> for LanguageJsonReporter {
+    fn name(&self) -> &str {
+        REPORTER_FORMAT_JSON
+    }
+
+    fn file_extension(&self) -> &str {
+        "json"
+    }
+
+    fn render(&self, report: &LanguageReport, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_json(report)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Language, LanguageReportItem, SourceStats, Weight};
+
+    fn create_test_report() -> LanguageReport {
+        let language = Language::new("Rust", &["rs"]);
+
+        let language_report = LanguageReportItem {
+            language,
+            file_count: 5,
+            file_paths: vec!["src/main.rs".to_string(), "src/lib.rs".to_string()],
+            code_lines: 120,
+            comment_lines: 30,
+            blank_lines: 15,
+            bytes: 4096,
+            content_detected_file_paths: vec![],
+            embedded_languages: vec![],
+            production: SourceStats {
+                file_count: 3,
+                code_lines: 80,
+            },
+            test: SourceStats {
+                file_count: 2,
+                code_lines: 40,
+            },
+            fixture: SourceStats::default(),
+        };
+
+        LanguageReport {
+            dir: "/test/path".to_string(),
+            total_file_count: 5,
+            weight: Weight::Files,
+            kinds: vec![],
+            languages: vec![language_report],
+            classified_files: vec![],
+        }
+    }
+
+    #[test]
+    fn test_to_json_success() {
+        let reporter = LanguageJsonReporter::new();
+        let report = create_test_report();
+
+        let result = reporter.to_json(&report);
+        assert!(result.is_ok());
+
+        let json_string = result.unwrap();
+
+        assert!(json_string.contains("\"dir\": \"/test/path\""));
+        assert!(json_string.contains("\"total_file_count\": 5"));
+        assert!(json_string.contains("\"name\": \"Rust\""));
+        assert!(json_string.contains("\"file_count\": 5"));
+        assert!(json_string.contains("\"code_lines\": 120"));
+        assert!(json_string.contains("\"comment_lines\": 30"));
+        assert!(json_string.contains("\"blank_lines\": 15"));
+        assert!(json_string.contains("\"bytes\": 4096"));
+        assert!(json_string.contains("\"weight\": \"files\""));
+
+        let parsed: serde_json::Value =
+            serde_json::from_str(&json_string).expect("Generated JSON is invalid");
+
+        assert_eq!(parsed["dir"], "/test/path");
+        assert_eq!(parsed["total_file_count"], 5);
+        assert_eq!(parsed["languages"][0]["test"]["file_count"], 2);
+        assert_eq!(parsed["languages"][0]["production"]["code_lines"], 80);
+    }
+
+    #[test]
+    fn test_render_ends_with_newline() {
+        let reporter = LanguageJsonReporter::new();
+        let mut output = Vec::new();
+
+        reporter
+            .render(&create_test_report(), &mut output)
+            .expect("Rendering should succeed");
+
+        assert!(String::from_utf8(output).unwrap().ends_with("}\n"));
+    }
+}
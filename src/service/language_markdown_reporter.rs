@@ -0,0 +1,225 @@
+use crate::config::REPORTER_FORMAT_MARKDOWN;
+use crate::entity::LanguageReport;
+use crate::service::Reporter;
+use std::io::{self, Write};
+
+/// Number of segments in the bar drawn for a language's percentage.
+const BAR_WIDTH: usize = 20;
+
+/// Renders a language report as GitHub-flavoured Markdown, suitable for pull request
+/// comments or for appending to `$GITHUB_STEP_SUMMARY`. With `with_file_lists`, the files
+/// of each language are listed in a collapsible `<details>` section.
+pub struct LanguageMarkdownReporter {
+    file_lists: bool,
+}
+
+impl Default for LanguageMarkdownReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguageMarkdownReporter {
+    pub fn new() -> Self {
+        Self { file_lists: false }
+    }
+
+    pub fn with_file_lists(self) -> Self {
+        Self { file_lists: true }
+    }
+
+    fn to_markdown(&self, report: &LanguageReport) -> String {
+        let mut output = vec![
+            "## Scan Summary".to_string(),
+            String::new(),
+            "| Item | Value |".to_string(),
+            "| --- | --- |".to_string(),
+        ];
+        for (label, value) in report.summary() {
+            output.push(format!("| {} | {} |", label, Self::escape_cell(&value)));
+        }
+
+        output.push(String::new());
+        output.push("## Language Statistics".to_string());
+        output.push(String::new());
+        output.push(
+            "| Language | Kind | Files | Code | Comment | Blank | Bytes | Percentage | Share |"
+                .to_string(),
+        );
+        output.push("| --- | --- | ---: | ---: | ---: | ---: | ---: | ---: | --- |".to_string());
+        for lang_report in &report.languages {
+            let percentage = report.percentage(lang_report);
+            output.push(format!(
+                "| {} | {} | {} | {} | {} | {} | {} | {} | {} |",
+                Self::escape_cell(&lang_report.language.name),
+                lang_report.language.kind.as_str(),
+                lang_report.file_count,
+                lang_report.code_lines,
+                lang_report.comment_lines,
+                lang_report.blank_lines,
+                lang_report.bytes,
+                percentage.map_or_else(|| "-".to_string(), |p| format!("{:.1}%", p)),
+                percentage.map_or_else(String::new, Self::bar),
+            ));
+        }
+
+        if self.file_lists {
+            output.push(String::new());
+            output.push("## Files".to_string());
+            for lang_report in &report.languages {
+                output.push(String::new());
+                output.push("<details>".to_string());
+                output.push(format!(
+                    "<summary>{} ({} {})</summary>",
+                    Self::escape_html(&lang_report.language.name),
+                    lang_report.file_count,
+                    if lang_report.file_count == 1 {
+                        "file"
+                    } else {
+                        "files"
+                    }
+                ));
+                output.push(String::new());
+                for path in &lang_report.file_paths {
+                    output.push(format!("- {}", Self::code_span(path)));
+                }
+                output.push(String::new());
+                output.push("</details>".to_string());
+            }
+        }
+
+        // A trailing blank line closes the last table or HTML block, so reports appended
+        // to the same file, such as `$GITHUB_STEP_SUMMARY`, start a new block.
+        output.push(String::new());
+        output.join("\n")
+    }
+
+    /// Draws `percentage` as a bar of `BAR_WIDTH` filled and empty block characters.
+    fn bar(percentage: f64) -> String {
+        let filled = ((percentage / 100.0) * BAR_WIDTH as f64).round() as usize;
+        let filled = filled.min(BAR_WIDTH);
+        format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled))
+    }
+
+    /// Escapes the characters that would end a table cell or row.
+    fn escape_cell(text: &str) -> String {
+        text.replace('|', "\\|").replace(['\r', '\n'], " ")
+    }
+
+    fn escape_html(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    }
+
+    /// Wraps `text` in a code span, using a fence longer than any run of backticks in it.
+    fn code_span(text: &str) -> String {
+        let longest_run = text
+            .split(|c| c != '`')
+            .map(|run| run.len())
+            .max()
+            .unwrap_or(0);
+        let fence = "`".repeat(longest_run + 1);
+        let padding = if text.starts_with('`') || text.ends_with('`') {
+            " "
+        } else {
+            ""
+        };
+        format!("{fence}{padding}{text}{padding}{fence}")
+    }
+}
+
+impl Reporter<LanguageReport> for LanguageMarkdownReporter {
+    fn name(&self) -> &str {
+        REPORTER_FORMAT_MARKDOWN
+    }
+
+    fn file_extension(&self) -> &str {
+        "md"
+    }
+
+    fn render(&self, report: &LanguageReport, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_markdown(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Language, LanguageKind, LanguageReportItem, Weight};
+
+    fn create_item(name: &str, kind: LanguageKind, file_paths: &[&str]) -> LanguageReportItem {
+        let mut item = LanguageReportItem::new(Language {
+            kind,
+            ..Language::new(name, &[])
+        });
+        item.file_count = file_paths.len() as u64;
+        item.file_paths = file_paths.iter().map(|path| path.to_string()).collect();
+        item.code_lines = 10;
+        item
+    }
+
+    fn create_test_report() -> LanguageReport {
+        LanguageReport {
+            dir: "/test/a|b".to_string(),
+            total_file_count: 5,
+            weight: Weight::Files,
+            kinds: vec![],
+            languages: vec![
+                create_item(
+                    "Rust",
+                    LanguageKind::Programming,
+                    &["src/main.rs", "src/lib.rs", "src/`tick`.rs"],
+                ),
+                create_item("C++", LanguageKind::Programming, &["src/a.cpp"]),
+                create_item("JSON", LanguageKind::Data, &["data.json"]),
+            ],
+            classified_files: vec![],
+        }
+    }
+
+    mod to_markdown {
+        use super::*;
+
+        #[test]
+        fn test_summary_and_statistics_tables() {
+            let output = LanguageMarkdownReporter::new().to_markdown(&create_test_report());
+
+            assert!(output.starts_with("## Scan Summary\n\n| Item | Value |\n| --- | --- |\n"));
+            assert!(output.contains("| Directory | /test/a\\|b |\n"));
+            assert!(output.contains("| Excluded Files | 0 |\n"));
+            assert!(output.contains(
+                "| Rust | programming | 3 | 10 | 0 | 0 | 0 | 75.0% | ███████████████░░░░░ |\n"
+            ));
+            assert!(output.contains("| JSON | data | 1 | 10 | 0 | 0 | 0 | - |  |"));
+            assert!(!output.contains("<details>"));
+        }
+
+        #[test]
+        fn test_file_lists() {
+            let output = LanguageMarkdownReporter::new()
+                .with_file_lists()
+                .to_markdown(&create_test_report());
+
+            assert!(output.contains(
+                "<details>\n<summary>Rust (3 files)</summary>\n\n- `src/main.rs`\n- `src/lib.rs`\n- ``src/`tick`.rs``\n\n</details>"
+            ));
+            assert!(output.contains("<summary>C++ (1 file)</summary>"));
+            assert!(output.ends_with("</details>\n"));
+        }
+    }
+
+    mod bar {
+        use super::*;
+
+        #[test]
+        fn test_bar_segments() {
+            assert_eq!(LanguageMarkdownReporter::bar(0.0), "░".repeat(20));
+            assert_eq!(LanguageMarkdownReporter::bar(100.0), "█".repeat(20));
+            assert_eq!(
+                LanguageMarkdownReporter::bar(50.0),
+                format!("{}{}", "█".repeat(10), "░".repeat(10))
+            );
+        }
+    }
+}
@@ -1,16 +1,26 @@
-use crate::config::LanguageConfig;
+use crate::config::{LanguageConfig, SfcConfig};
 use crate::entity::LanguageScannerOptions;
-use crate::entity::{File, LanguageReport, LanguageReportItem, Result, TechScanError};
-use ignore::{overrides::OverrideBuilder, Walk, WalkBuilder};
+use crate::entity::{
+    ClassifiedFile, CommentSyntax, File, FileClassification, Language, LanguageReport,
+    LanguageReportItem, Result, TechScanError,
+};
+use crate::service::git_attributes::GIT_ATTRIBUTES_FILENAME;
+use crate::service::{
+    ContentSniffer, FileClassifier, FileWalker, GitAttributes, HeuristicEngine, LineCounter,
+    SfcParser,
+};
 use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
 use std::path::Path;
 
-const GLOBAL_EXCLUDE_PATH: [&str; 2] = [".git", ".DS_Store"];
+const SNIFF_HEAD_BYTES: u64 = 1024;
 
 #[derive(Debug)]
 pub struct LanguageScanner {
     dir: String,
     opts: LanguageScannerOptions,
+    language_config: LanguageConfig,
 }
 
 impl LanguageScanner {
@@ -19,117 +29,220 @@ impl LanguageScanner {
             return Err(TechScanError::DirectoryNotFound(dir.to_string()));
         }
 
-        let opts = opts.map_or_else(LanguageScannerOptions::default, |o| o);
+        let opts = opts.unwrap_or_default();
+        let language_config = LanguageConfig::new(&opts.languages)?;
 
         Ok(Self {
             dir: dir.to_string(),
             opts,
+            language_config,
         })
     }
 
     pub fn scan(&self) -> Result<Vec<File>> {
-        let entries = self._walk_dir()?;
-        let mut files = Vec::new();
-
-        for entry in entries {
-            match entry {
-                Ok(entry) => {
-                    if entry.file_type().is_none_or(|ft| !ft.is_file()) {
-                        continue;
-                    }
-                    match File::from_dir_entry(&entry) {
-                        Ok(file) => files.push(file),
-                        Err(e) => {
-                            eprintln!("Error processing file {}: {}", entry.path().display(), e,)
-                        }
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Error reading entry: {}", e);
-                }
-            }
-        }
-
-        Ok(files)
+        FileWalker::walk(&self.dir, &self.opts.exclude)
     }
 
     pub fn analyze(&self, files: Vec<File>) -> LanguageReport {
-        let mut language_data: HashMap<String, Vec<String>> = HashMap::new();
+        let mut language_data: HashMap<&str, LanguageReportItem> = HashMap::new();
+        let mut classified_files = Vec::new();
+        let git_attributes = Self::load_git_attributes(&files);
 
         for file in &files {
-            if let Some(ext) = &file.ext {
-                let ext_lower = ext.to_lowercase();
-                if let Some(language) = LanguageConfig::detect_language(&ext_lower) {
-                    language_data
-                        .entry(language.name.to_string())
-                        .or_default()
-                        .push(file.path.clone());
+            let attributes = git_attributes.attributes_for(&file.path);
+            let attribute_language = attributes
+                .language
+                .as_deref()
+                .and_then(|name| self.language_config.get_language_by_name_ignore_case(name));
+
+            let detected = attribute_language
+                .or_else(|| self.language_config.detect_file_language(file))
+                .map(|language| (language, false))
+                .or_else(|| self.sniff_language(file).map(|language| (language, true)));
+
+            let mut classification = attributes
+                .classification()
+                .or_else(|| FileClassifier::classify_path(self.relative_path(file), &attributes));
+
+            let Some((language, content_detected)) = detected else {
+                if let Some(classification) = classification {
+                    classified_files.push(ClassifiedFile {
+                        path: file.path.clone(),
+                        language: None,
+                        classification,
+                    });
                 }
+                continue;
+            };
+
+            let raw_content = Self::read_content(file);
+            let content = String::from_utf8_lossy(&raw_content);
+            let language = if content_detected || attribute_language.is_some() {
+                language
+            } else {
+                self.disambiguate(file, &content).unwrap_or(language)
+            };
+            if !self.includes_kind(language) {
+                continue;
             }
-        }
 
-        let mut languages = Vec::new();
-        for (lang_name, file_paths) in language_data {
-            if let Some(language) = LanguageConfig::get_language_by_name(&lang_name) {
-                languages.push(LanguageReportItem {
-                    language,
-                    file_count: file_paths.len() as u64,
-                    file_paths,
+            if classification.is_none() {
+                classification = FileClassifier::classify_content(&content, &attributes);
+            }
+            if let Some(classification) = classification {
+                classified_files.push(ClassifiedFile {
+                    path: file.path.clone(),
+                    language: Some(language.name.clone()),
+                    classification,
                 });
+                if !self.counts_toward_languages(classification) {
+                    continue;
+                }
+            }
+
+            let line_stats = LineCounter::count(&content, &language.comment);
+            let bytes = raw_content.len() as u64;
+            let item = language_data
+                .entry(&language.name)
+                .or_insert_with(|| LanguageReportItem::new(language.clone()));
+            let role = FileClassifier::classify_role(self.relative_path(file));
+            item.add_file(&file.path, role, &line_stats, bytes);
+            if content_detected {
+                item.content_detected_file_paths.push(file.path.clone());
+            }
+            if self.opts.embedded {
+                self.count_embedded(item, &content);
             }
         }
 
-        languages.sort_by(|a, b| b.file_count.cmp(&a.file_count));
+        let mut languages: Vec<LanguageReportItem> = language_data.into_values().collect();
+        for item in &mut languages {
+            item.embedded_languages
+                .sort_by_key(|embedded| std::cmp::Reverse(embedded.code_lines));
+        }
+
+        let weight = self.opts.weight;
+        // Languages left out of the percentages are listed after the ones that count.
+        languages.sort_by_key(|item| {
+            (
+                !item.language.kind.counts_toward_percentage(),
+                std::cmp::Reverse(item.weighted_value(weight)),
+            )
+        });
 
         LanguageReport {
             dir: self.dir.clone(),
             total_file_count: files.len() as u64,
+            weight,
+            kinds: self.opts.kinds.clone(),
             languages,
+            classified_files,
         }
     }
 
-    fn _walk_dir(&self) -> Result<Walk> {
-        let mut override_builder = OverrideBuilder::new(&self.dir);
+    fn includes_kind(&self, language: &Language) -> bool {
+        self.opts.kinds.is_empty() || self.opts.kinds.contains(&language.kind)
+    }
 
-        for pattern in GLOBAL_EXCLUDE_PATH.iter() {
-            override_builder
-                .add(&format!("!{}", pattern))
-                .map_err(|e| {
-                    TechScanError::ValidationError(format!(
-                        "Failed to add global exclude pattern '{}': {}",
-                        pattern, e
-                    ))
-                })?;
+    /// Documentation is never counted; vendored and generated files only when
+    /// `include_vendored` is set.
+    fn counts_toward_languages(&self, classification: FileClassification) -> bool {
+        self.opts.include_vendored && classification != FileClassification::Documentation
+    }
+
+    fn relative_path<'a>(&self, file: &'a File) -> &'a str {
+        Path::new(&file.path)
+            .strip_prefix(&self.dir)
+            .ok()
+            .and_then(|path| path.to_str())
+            .unwrap_or(&file.path)
+    }
+
+    /// Counts the lines of each block of a single-file component under the language the
+    /// block is written in. Other languages are left alone.
+    fn count_embedded(&self, item: &mut LanguageReportItem, content: &str) {
+        let Some(syntax) = SfcConfig::find(&item.language.name) else {
+            return;
+        };
+
+        for block in SfcParser::parse(content, syntax) {
+            let language = self
+                .language_config
+                .detect_language(&block.lang)
+                .or_else(|| {
+                    self.language_config
+                        .get_language_by_name_ignore_case(&block.lang)
+                });
+            let line_stats = match language {
+                Some(language) => LineCounter::count(&block.content, &language.comment),
+                None => LineCounter::count(&block.content, &CommentSyntax::default()),
+            };
+            let name = language.map_or(block.lang.as_str(), |language| &language.name);
+            item.add_embedded(name, &line_stats);
         }
+    }
+
+    fn load_git_attributes(files: &[File]) -> GitAttributes {
+        let paths: Vec<&str> = files
+            .iter()
+            .filter(|file| file.name == GIT_ATTRIBUTES_FILENAME)
+            .map(|file| file.path.as_str())
+            .collect();
 
-        for pattern in &self.opts.exclude {
-            override_builder
-                .add(&format!("!{}", pattern))
-                .map_err(|e| {
-                    TechScanError::ValidationError(format!(
-                        "Failed to add exclude pattern '{}': {}",
-                        pattern, e
-                    ))
-                })?;
+        GitAttributes::load(&paths)
+    }
+
+    /// Detects the language of an extensionless file from its shebang or modeline.
+    /// Only runs when content sniffing is enabled.
+    fn sniff_language(&self, file: &File) -> Option<&Language> {
+        if !self.opts.sniff_content || file.ext.is_some() {
+            return None;
         }
 
-        let overrides = override_builder.build().map_err(|e| {
-            TechScanError::ValidationError(format!("Failed to build overrides: {}", e))
-        })?;
+        let mut head = Vec::new();
+        let read_result = fs::File::open(&file.path)
+            .and_then(|f| f.take(SNIFF_HEAD_BYTES).read_to_end(&mut head));
+        if let Err(e) = read_result {
+            log::warn!("Error reading file {}: {}", file.path, e);
+            return None;
+        }
 
-        let entries = WalkBuilder::new(&self.dir)
-            .git_ignore(true)
-            .hidden(false)
-            .overrides(overrides)
-            .build();
+        ContentSniffer::sniff(&String::from_utf8_lossy(&head), &self.language_config)
+    }
+
+    /// Resolves files whose extension is shared by several languages from their content.
+    /// Files matched by name, and extensions assigned in the config file, keep their
+    /// detected language.
+    fn disambiguate(&self, file: &File, content: &str) -> Option<&Language> {
+        if self
+            .language_config
+            .detect_language_by_filename(&file.name)
+            .is_some()
+        {
+            return None;
+        }
 
-        Ok(entries)
+        let ext = file.ext.as_ref()?.to_lowercase();
+        if self.language_config.is_overridden_ext(&ext) {
+            return None;
+        }
+        HeuristicEngine::disambiguate(&ext, content, &self.language_config)
+    }
+
+    /// Reads the raw bytes of a file. Sizes are taken from these bytes; line counting and
+    /// heuristics work on a lossy UTF-8 conversion of them.
+    fn read_content(file: &File) -> Vec<u8> {
+        fs::read(&file.path).unwrap_or_else(|e| {
+            log::warn!("Error reading file {}: {}", file.path, e);
+            Vec::new()
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::entity::{FileClassification, LanguageDefinition, LanguageKind, Weight};
     use std::collections::HashSet;
 
     #[test]
@@ -159,6 +272,544 @@ mod tests {
         assert!(files.len() == 29, "Expected 29 files, got {}", files.len());
     }
 
+    #[test]
+    fn test_analyze_counts_lines_per_language() {
+        let scanner = LanguageScanner::new("tests/fixtures/lines", None)
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let rust = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Rust")
+            .expect("Rust should be detected");
+        assert_eq!(rust.code_lines, 3);
+        assert_eq!(rust.comment_lines, 4);
+        assert_eq!(rust.blank_lines, 1);
+
+        let python = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Python")
+            .expect("Python should be detected");
+        assert_eq!(python.code_lines, 2);
+        assert_eq!(python.comment_lines, 1);
+        assert_eq!(python.blank_lines, 1);
+    }
+
+    #[test]
+    fn test_analyze_records_bytes_per_language() {
+        let scanner = LanguageScanner::new("tests/fixtures/lines", None)
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let rust = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Rust")
+            .expect("Rust should be detected");
+        let expected = fs::metadata("tests/fixtures/lines/main.rs").unwrap().len();
+        assert_eq!(rust.bytes, expected);
+    }
+
+    #[test]
+    fn test_analyze_records_raw_bytes_of_non_utf8_files() {
+        let scanner = LanguageScanner::new("tests/fixtures/encoding", None)
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let c = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "C")
+            .expect("C should be detected");
+        let expected = fs::metadata("tests/fixtures/encoding/latin1.c")
+            .unwrap()
+            .len();
+        assert_eq!(c.bytes, expected);
+        assert_eq!(c.comment_lines, 1);
+        assert_eq!(c.code_lines, 1);
+    }
+
+    #[test]
+    fn test_analyze_sorts_by_weight() {
+        let opts = LanguageScannerOptions {
+            weight: Weight::Bytes,
+            kinds: vec![],
+            ..Default::default()
+        };
+        let scanner = LanguageScanner::new("tests/fixtures/lines", Some(opts))
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        assert_eq!(report.weight, Weight::Bytes);
+        assert_eq!(report.languages[0].language.name, "Rust");
+        assert!(report.languages[0].bytes >= report.languages[1].bytes);
+    }
+
+    #[test]
+    fn test_analyze_detects_files_by_name() {
+        let scanner = LanguageScanner::new("tests/fixtures/filenames", None)
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let detected: HashSet<&str> = report
+            .languages
+            .iter()
+            .map(|item| item.language.name.as_str())
+            .collect();
+
+        for name in [
+            "Dockerfile",
+            "Makefile",
+            "CMake",
+            "Starlark",
+            "Groovy",
+            "Ruby",
+        ] {
+            assert!(detected.contains(name), "{} should be detected", name);
+        }
+
+        let dockerfile = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Dockerfile")
+            .unwrap();
+        assert_eq!(dockerfile.file_count, 2);
+    }
+
+    #[test]
+    fn test_analyze_sniffs_content_when_enabled() {
+        let opts = LanguageScannerOptions {
+            sniff_content: true,
+            ..Default::default()
+        };
+        let scanner = LanguageScanner::new("tests/fixtures/shebang", Some(opts))
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let python = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Python")
+            .expect("Python should be detected from shebang");
+        assert_eq!(python.file_count, 1);
+        assert_eq!(python.content_detected_file_paths.len(), 1);
+
+        let shell = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Shell")
+            .expect("Shell should be detected");
+        assert_eq!(shell.file_count, 2);
+        assert_eq!(shell.content_detected_file_paths.len(), 1);
+
+        let ruby = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Ruby")
+            .expect("Ruby should be detected from modeline");
+        assert_eq!(ruby.content_detected_file_paths.len(), 1);
+    }
+
+    #[test]
+    fn test_analyze_skips_content_sniffing_by_default() {
+        let scanner = LanguageScanner::new("tests/fixtures/shebang", None)
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        assert_eq!(report.languages.len(), 1);
+        assert_eq!(report.languages[0].language.name, "Shell");
+        assert!(report.languages[0].content_detected_file_paths.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_disambiguates_shared_extensions() {
+        let scanner = LanguageScanner::new("tests/fixtures/heuristics", None)
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let names: HashMap<&str, u64> = report
+            .languages
+            .iter()
+            .map(|item| (item.language.name.as_str(), item.file_count))
+            .collect();
+
+        assert_eq!(names.get("Objective-C"), Some(&2));
+        assert_eq!(names.get("C++"), Some(&1));
+        assert_eq!(names.get("C"), Some(&1));
+        assert_eq!(names.get("MATLAB"), Some(&1));
+        assert_eq!(names.get("Prolog"), Some(&1));
+        assert_eq!(names.get("Perl"), Some(&1));
+        assert_eq!(names.get("Qt Translation"), Some(&1));
+        assert_eq!(names.get("TypeScript"), Some(&1));
+    }
+
+    #[test]
+    fn test_analyze_with_language_definitions() {
+        let opts = LanguageScannerOptions {
+            languages: vec![
+                LanguageDefinition {
+                    name: "C++".to_string(),
+                    exts: vec!["h".to_string()],
+                    ..Default::default()
+                },
+                LanguageDefinition {
+                    name: "MATLAB".to_string(),
+                    disabled: true,
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        };
+        let scanner = LanguageScanner::new("tests/fixtures/heuristics", Some(opts))
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let names: HashMap<&str, u64> = report
+            .languages
+            .iter()
+            .map(|item| (item.language.name.as_str(), item.file_count))
+            .collect();
+
+        assert_eq!(names.get("C++"), Some(&3));
+        assert_eq!(names.get("C"), None);
+        assert_eq!(names.get("MATLAB"), None);
+        assert_eq!(names.get("Objective-C"), Some(&2));
+    }
+
+    #[test]
+    fn test_scanner_invalid_language_definition() {
+        let opts = LanguageScannerOptions {
+            languages: vec![LanguageDefinition::default()],
+            ..Default::default()
+        };
+        let result = LanguageScanner::new("tests/fixtures/heuristics", Some(opts));
+
+        assert!(matches!(result, Err(TechScanError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_analyze_applies_gitattributes() {
+        let scanner = LanguageScanner::new("tests/fixtures/gitattributes", None)
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let names: HashMap<&str, u64> = report
+            .languages
+            .iter()
+            .map(|item| (item.language.name.as_str(), item.file_count))
+            .collect();
+
+        assert_eq!(names.get("Go"), Some(&1));
+        assert_eq!(names.get("PHP"), Some(&1));
+        assert_eq!(names.get("JavaScript"), Some(&1));
+        assert_eq!(names.get("Python"), None);
+
+        let classified: HashMap<&str, (Option<&str>, FileClassification)> = report
+            .classified_files
+            .iter()
+            .map(|file| {
+                (
+                    file.path.as_str(),
+                    (file.language.as_deref(), file.classification),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            classified.get("tests/fixtures/gitattributes/api.pb.go"),
+            Some(&(Some("Go"), FileClassification::Generated))
+        );
+        assert_eq!(
+            classified.get("tests/fixtures/gitattributes/lib/util.js"),
+            Some(&(Some("JavaScript"), FileClassification::Vendored))
+        );
+        assert_eq!(
+            classified.get("tests/fixtures/gitattributes/third_party/lib.js"),
+            Some(&(Some("JavaScript"), FileClassification::Vendored))
+        );
+        assert_eq!(
+            classified.get("tests/fixtures/gitattributes/docs/guide.md"),
+            Some(&(Some("Markdown"), FileClassification::Documentation))
+        );
+        assert_eq!(
+            classified.get("tests/fixtures/gitattributes/docs/example.py"),
+            Some(&(Some("Python"), FileClassification::Documentation))
+        );
+        assert_eq!(
+            classified.get("tests/fixtures/gitattributes/lib/.gitattributes"),
+            Some(&(None, FileClassification::Vendored))
+        );
+        assert_eq!(report.classified_files.len(), 6);
+    }
+
+    #[test]
+    fn test_analyze_classifies_vendored_and_generated_files() {
+        let scanner = LanguageScanner::new("tests/fixtures/vendored", None)
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        assert_eq!(report.languages.len(), 1);
+        assert_eq!(report.languages[0].language.name, "Go");
+        assert_eq!(
+            report.languages[0].file_paths,
+            vec!["tests/fixtures/vendored/src/main.go"]
+        );
+
+        let classified: HashMap<&str, FileClassification> = report
+            .classified_files
+            .iter()
+            .map(|file| (file.path.as_str(), file.classification))
+            .collect();
+
+        assert_eq!(classified.len(), 4);
+        assert_eq!(
+            classified.get("tests/fixtures/vendored/vendor/github.com/pkg/errors/errors.go"),
+            Some(&FileClassification::Vendored)
+        );
+        assert_eq!(
+            classified.get("tests/fixtures/vendored/static/app.min.js"),
+            Some(&FileClassification::Vendored)
+        );
+        assert_eq!(
+            classified.get("tests/fixtures/vendored/api/service.pb.go"),
+            Some(&FileClassification::Generated)
+        );
+        assert_eq!(
+            classified.get("tests/fixtures/vendored/gen/schema.go"),
+            Some(&FileClassification::Generated)
+        );
+    }
+
+    #[test]
+    fn test_analyze_includes_vendored_files() {
+        let opts = LanguageScannerOptions {
+            include_vendored: true,
+            ..Default::default()
+        };
+        let scanner = LanguageScanner::new("tests/fixtures/vendored", Some(opts))
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let go = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Go")
+            .expect("Go should be detected");
+        assert_eq!(go.file_count, 4);
+
+        let js = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "JavaScript")
+            .expect("JavaScript should be detected");
+        assert_eq!(js.file_count, 1);
+
+        assert_eq!(report.classified_files.len(), 4);
+    }
+
+    #[test]
+    fn test_analyze_embedded_languages() {
+        let embedded_of = |report: &LanguageReport, name: &str| -> Vec<(String, u64, u64, u64)> {
+            report
+                .languages
+                .iter()
+                .find(|item| item.language.name == name)
+                .expect("framework language should be detected")
+                .embedded_languages
+                .iter()
+                .map(|item| {
+                    (
+                        item.language.clone(),
+                        item.block_count,
+                        item.code_lines,
+                        item.comment_lines,
+                    )
+                })
+                .collect()
+        };
+
+        let scanner = LanguageScanner::new("tests/fixtures/embedded", None)
+            .expect("LanguageScanner creation should succeed");
+        let report = scanner.analyze(scanner.scan().expect("Scanning should succeed"));
+        assert!(embedded_of(&report, "Vue").is_empty());
+
+        let opts = LanguageScannerOptions {
+            embedded: true,
+            ..Default::default()
+        };
+        let scanner = LanguageScanner::new("tests/fixtures/embedded", Some(opts))
+            .expect("LanguageScanner creation should succeed");
+        let report = scanner.analyze(scanner.scan().expect("Scanning should succeed"));
+
+        let vue = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Vue")
+            .unwrap();
+        assert_eq!(vue.file_count, 2);
+
+        let mut embedded = embedded_of(&report, "Vue");
+        embedded.sort();
+        assert_eq!(
+            embedded,
+            vec![
+                ("CSS".to_string(), 1, 1, 0),
+                ("HTML".to_string(), 1, 3, 1),
+                ("JavaScript".to_string(), 1, 3, 0),
+                ("SCSS".to_string(), 1, 3, 0),
+                ("TypeScript".to_string(), 1, 2, 1),
+                ("pug".to_string(), 1, 1, 0),
+            ]
+        );
+
+        assert_eq!(
+            embedded_of(&report, "Svelte"),
+            vec![
+                ("HTML".to_string(), 1, 3, 0),
+                ("TypeScript".to_string(), 1, 1, 0),
+                ("CSS".to_string(), 1, 1, 0),
+            ]
+        );
+        assert_eq!(
+            embedded_of(&report, "Astro"),
+            vec![
+                ("HTML".to_string(), 1, 3, 0),
+                ("TypeScript".to_string(), 1, 2, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_analyze_assigns_language_kinds() {
+        let scanner = LanguageScanner::new("tests/fixtures/kinds", None)
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let kinds: HashMap<&str, LanguageKind> = report
+            .languages
+            .iter()
+            .map(|item| (item.language.name.as_str(), item.language.kind))
+            .collect();
+
+        assert_eq!(kinds.get("Python"), Some(&LanguageKind::Programming));
+        assert_eq!(kinds.get("HTML"), Some(&LanguageKind::Markup));
+        assert_eq!(kinds.get("CSS"), Some(&LanguageKind::Style));
+        assert_eq!(kinds.get("JSON"), Some(&LanguageKind::Data));
+        assert_eq!(kinds.get("TOML"), Some(&LanguageKind::Config));
+        assert_eq!(kinds.get("YAML"), Some(&LanguageKind::Config));
+        assert_eq!(kinds.get("Markdown"), Some(&LanguageKind::Prose));
+
+        let first_excluded = report
+            .languages
+            .iter()
+            .position(|item| !item.language.kind.counts_toward_percentage())
+            .unwrap();
+        assert_eq!(first_excluded, 3);
+    }
+
+    #[test]
+    fn test_analyze_filters_by_kind() {
+        let opts = LanguageScannerOptions {
+            kinds: vec![LanguageKind::Programming, LanguageKind::Markup],
+            ..Default::default()
+        };
+        let scanner = LanguageScanner::new("tests/fixtures/kinds", Some(opts))
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let mut names: Vec<&str> = report
+            .languages
+            .iter()
+            .map(|item| item.language.name.as_str())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec!["HTML", "Python"]);
+    }
+
+    #[test]
+    fn test_analyze_with_only_data_kinds_has_percentages() {
+        let opts = LanguageScannerOptions {
+            kinds: vec![LanguageKind::Data],
+            ..Default::default()
+        };
+        let scanner = LanguageScanner::new("tests/fixtures/kinds", Some(opts))
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        assert!(!report.languages.is_empty());
+        let total: f64 = report
+            .languages
+            .iter()
+            .map(|item| {
+                report
+                    .percentage(item)
+                    .expect("Data languages should have a percentage")
+            })
+            .sum();
+        assert!((total - 100.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_analyze_splits_source_roles() {
+        let scanner = LanguageScanner::new("tests/fixtures/roles", None)
+            .expect("LanguageScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let go = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Go")
+            .expect("Go should be detected");
+        assert_eq!(go.production.file_count, 1);
+        assert_eq!(go.test.file_count, 1);
+        assert_eq!(go.production.code_lines, 4);
+        assert_eq!(go.test.code_lines, 7);
+
+        let python = report
+            .languages
+            .iter()
+            .find(|item| item.language.name == "Python")
+            .expect("Python should be detected");
+        assert_eq!(python.production.file_count, 1);
+        assert_eq!(python.test.file_count, 1);
+        assert_eq!(python.fixture.file_count, 1);
+        assert_eq!(python.test_ratio(), Some(1.0));
+    }
+
     #[test]
     fn test_scanner_nonexistent_directory() {
         let result = LanguageScanner::new("nonexistent/directory", None);
@@ -177,6 +828,7 @@ mod tests {
     fn test_scan_with_exclude_pattern() {
         let opts = LanguageScannerOptions {
             exclude: vec!["*.rs".to_string()],
+            ..Default::default()
         };
 
         let scanner = LanguageScanner::new("tests/fixtures/cli", Some(opts))
@@ -199,6 +851,7 @@ mod tests {
     fn test_scan_with_multiple_exclude_patterns() {
         let opts = LanguageScannerOptions {
             exclude: vec!["*.rs".to_string(), "*.js".to_string(), "*.rb".to_string()],
+            ..Default::default()
         };
 
         let scanner = LanguageScanner::new("tests/fixtures/cli", Some(opts))
@@ -0,0 +1,476 @@
+use crate::config::REPORTER_FORMAT_TABLE;
+use crate::entity::{FileClassification, LanguageKind, LanguageReport, LanguageReportItem};
+use crate::service::Reporter;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use tabled::builder::Builder;
+use tabled::settings::{object::Rows, Alignment, Modify, Style};
+
+pub struct LanguageTableReporter;
+
+impl Default for LanguageTableReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LanguageTableReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn to_table(&self, report: &LanguageReport) -> io::Result<String> {
+        let mut output = Vec::new();
+
+        let mut summary_builder = Builder::default();
+        summary_builder.push_record(vec!["Item", "Value"]);
+        for (label, value) in report.summary() {
+            summary_builder.push_record(vec![label, &value]);
+        }
+
+        let summary_table = summary_builder
+            .build()
+            .with(Style::sharp())
+            .with(Modify::new(Rows::new(0..=0)).with(Alignment::center()))
+            .to_string();
+
+        let mut lang_builder = Builder::default();
+        lang_builder.push_record(vec![
+            "Language",
+            "Kind",
+            "Files",
+            "Code",
+            "Comment",
+            "Blank",
+            "Bytes",
+            "Percentage",
+        ]);
+
+        for lang_report in &report.languages {
+            let percentage = report.percentage(lang_report).map_or_else(
+                || "-".to_string(),
+                |percentage| format!("{:.1}%", percentage),
+            );
+            lang_builder.push_record(vec![
+                &lang_report.language.name,
+                lang_report.language.kind.as_str(),
+                &lang_report.file_count.to_string(),
+                &lang_report.code_lines.to_string(),
+                &lang_report.comment_lines.to_string(),
+                &lang_report.blank_lines.to_string(),
+                &lang_report.bytes.to_string(),
+                &percentage,
+            ]);
+        }
+
+        let last_row = report.languages.len() + 1; // +1 for header
+        let lang_table = lang_builder
+            .build()
+            .with(Style::sharp())
+            .with(Modify::new(Rows::new(last_row..=last_row)).with(Alignment::center()))
+            .to_string();
+
+        output.push("=== Scan Summary ===".to_string());
+        output.push(summary_table);
+        output.push(String::new());
+        output.push("=== Language Statistics ===".to_string());
+        output.push(lang_table);
+
+        output.push(String::new());
+        output.push("=== Language Kinds ===".to_string());
+        output.push(Self::kind_table(report));
+
+        if report.languages.iter().any(Self::has_test_sources) {
+            output.push(String::new());
+            output.push("=== Test vs Production ===".to_string());
+            output.push(Self::source_role_table(report));
+        }
+
+        if report
+            .languages
+            .iter()
+            .any(|lang_report| !lang_report.embedded_languages.is_empty())
+        {
+            output.push(String::new());
+            output.push("=== Embedded Languages ===".to_string());
+            output.push(Self::embedded_table(report));
+        }
+
+        if !report.classified_files.is_empty() {
+            output.push(String::new());
+            output.push("=== Vendored, Generated and Documentation Files ===".to_string());
+            output.push(Self::classified_table(report));
+        }
+
+        Ok(output.join("\n"))
+    }
+
+    fn kind_table(report: &LanguageReport) -> String {
+        let mut totals: BTreeMap<LanguageKind, (u64, u64, u64, u64)> = BTreeMap::new();
+        for lang_report in &report.languages {
+            let total = totals.entry(lang_report.language.kind).or_default();
+            total.0 += 1;
+            total.1 += lang_report.file_count;
+            total.2 += lang_report.code_lines;
+            total.3 += lang_report.bytes;
+        }
+
+        let mut builder = Builder::default();
+        builder.push_record(vec!["Kind", "Languages", "Files", "Code", "Bytes"]);
+        for (kind, (languages, files, code, bytes)) in totals {
+            builder.push_record(vec![
+                kind.as_str(),
+                &languages.to_string(),
+                &files.to_string(),
+                &code.to_string(),
+                &bytes.to_string(),
+            ]);
+        }
+
+        builder.build().with(Style::sharp()).to_string()
+    }
+
+    /// Whether any file of the language is a test or fixture source.
+    fn has_test_sources(lang_report: &LanguageReportItem) -> bool {
+        lang_report.test.file_count > 0 || lang_report.fixture.file_count > 0
+    }
+
+    /// Splits the files and code lines of each programming language, and of any other
+    /// language with test or fixture files, into production, test and fixture sources. The
+    /// ratio is test code lines per production code line.
+    fn source_role_table(report: &LanguageReport) -> String {
+        let mut builder = Builder::default();
+        builder.push_record(vec![
+            "Language",
+            "Production Files",
+            "Test Files",
+            "Fixture Files",
+            "Production Code",
+            "Test Code",
+            "Fixture Code",
+            "Test/Code",
+        ]);
+        for lang_report in &report.languages {
+            if lang_report.language.kind != LanguageKind::Programming
+                && !Self::has_test_sources(lang_report)
+            {
+                continue;
+            }
+            let ratio = lang_report
+                .test_ratio()
+                .map_or_else(|| "-".to_string(), |ratio| format!("{:.2}", ratio));
+            builder.push_record(vec![
+                &lang_report.language.name,
+                &lang_report.production.file_count.to_string(),
+                &lang_report.test.file_count.to_string(),
+                &lang_report.fixture.file_count.to_string(),
+                &lang_report.production.code_lines.to_string(),
+                &lang_report.test.code_lines.to_string(),
+                &lang_report.fixture.code_lines.to_string(),
+                &ratio,
+            ]);
+        }
+
+        builder.build().with(Style::sharp()).to_string()
+    }
+
+    fn embedded_table(report: &LanguageReport) -> String {
+        let mut builder = Builder::default();
+        builder.push_record(vec![
+            "Language", "Embedded", "Blocks", "Code", "Comment", "Blank",
+        ]);
+        for lang_report in &report.languages {
+            for embedded in &lang_report.embedded_languages {
+                builder.push_record(vec![
+                    &lang_report.language.name,
+                    &embedded.language,
+                    &embedded.block_count.to_string(),
+                    &embedded.code_lines.to_string(),
+                    &embedded.comment_lines.to_string(),
+                    &embedded.blank_lines.to_string(),
+                ]);
+            }
+        }
+
+        builder.build().with(Style::sharp()).to_string()
+    }
+
+    fn classified_table(report: &LanguageReport) -> String {
+        let mut counts: BTreeMap<(FileClassification, &str), u64> = BTreeMap::new();
+        for file in &report.classified_files {
+            let language = file.language.as_deref().unwrap_or("-");
+            *counts.entry((file.classification, language)).or_default() += 1;
+        }
+
+        let mut builder = Builder::default();
+        builder.push_record(vec!["Classification", "Language", "Files"]);
+        for ((classification, language), count) in counts {
+            builder.push_record(vec![classification.as_str(), language, &count.to_string()]);
+        }
+
+        builder.build().with(Style::sharp()).to_string()
+    }
+}
+
+impl Reporter<LanguageReport> for LanguageTableReporter {
+    fn name(&self) -> &str {
+        REPORTER_FORMAT_TABLE
+    }
+
+    fn file_extension(&self) -> &str {
+        "txt"
+    }
+
+    fn render(&self, report: &LanguageReport, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_table(report)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{ClassifiedFile, Language, SourceStats, Weight};
+
+    fn create_test_report() -> LanguageReport {
+        let language = Language::new("Rust", &["rs"]);
+
+        let language_report = LanguageReportItem {
+            language,
+            file_count: 5,
+            file_paths: vec!["src/main.rs".to_string(), "src/lib.rs".to_string()],
+            code_lines: 120,
+            comment_lines: 30,
+            blank_lines: 15,
+            bytes: 4096,
+            content_detected_file_paths: vec![],
+            embedded_languages: vec![],
+            production: SourceStats {
+                file_count: 3,
+                code_lines: 80,
+            },
+            test: SourceStats {
+                file_count: 2,
+                code_lines: 40,
+            },
+            fixture: SourceStats::default(),
+        };
+
+        LanguageReport {
+            dir: "/test/path".to_string(),
+            total_file_count: 5,
+            weight: Weight::Files,
+            kinds: vec![],
+            languages: vec![language_report],
+            classified_files: vec![],
+        }
+    }
+
+    #[test]
+    fn test_percentage_excludes_unknown_files() {
+        let reporter = LanguageTableReporter::new();
+
+        let rust_lang = Language::new("Rust", &["rs"]);
+        let js_lang = Language::new("JavaScript", &["js"]);
+
+        let report = LanguageReport {
+            dir: "/test/path".to_string(),
+            total_file_count: 100,
+            weight: Weight::Files,
+            kinds: vec![],
+            languages: vec![
+                LanguageReportItem {
+                    language: rust_lang,
+                    file_count: 30,
+                    file_paths: vec![],
+                    code_lines: 0,
+                    comment_lines: 0,
+                    blank_lines: 0,
+                    bytes: 0,
+                    content_detected_file_paths: vec![],
+                    embedded_languages: vec![],
+                    production: SourceStats::default(),
+                    test: SourceStats::default(),
+                    fixture: SourceStats::default(),
+                },
+                LanguageReportItem {
+                    language: js_lang,
+                    file_count: 20,
+                    file_paths: vec![],
+                    code_lines: 0,
+                    comment_lines: 0,
+                    blank_lines: 0,
+                    bytes: 0,
+                    content_detected_file_paths: vec![],
+                    embedded_languages: vec![],
+                    production: SourceStats::default(),
+                    test: SourceStats::default(),
+                    fixture: SourceStats::default(),
+                },
+            ],
+            classified_files: vec![],
+        };
+
+        let result = reporter.to_table(&report);
+        assert!(result.is_ok());
+
+        let table_output = result.unwrap();
+
+        assert!(table_output.contains("60.0%"));
+        assert!(table_output.contains("40.0%"));
+    }
+
+    #[test]
+    fn test_percentage_excludes_data_languages() {
+        let reporter = LanguageTableReporter::new();
+        let mut report = create_test_report();
+
+        let mut json_lang = Language::new("JSON", &["json"]);
+        json_lang.kind = LanguageKind::Data;
+        let mut json_report = LanguageReportItem::new(json_lang);
+        json_report.file_count = 15;
+        report.languages.push(json_report);
+
+        let table_output = reporter.to_table(&report).unwrap();
+
+        assert!(table_output.contains("100.0%"));
+        assert!(table_output.contains("=== Language Kinds ==="));
+        assert!(table_output.contains("data"));
+    }
+
+    #[test]
+    fn test_to_table_shows_line_counts() {
+        let reporter = LanguageTableReporter::new();
+        let report = create_test_report();
+
+        let table_output = reporter.to_table(&report).unwrap();
+
+        assert!(table_output.contains("Code"));
+        assert!(table_output.contains("Comment"));
+        assert!(table_output.contains("Blank"));
+        assert!(table_output.contains("120"));
+        assert!(table_output.contains("30"));
+        assert!(table_output.contains("15"));
+    }
+
+    #[test]
+    fn test_to_table_shows_source_roles() {
+        let reporter = LanguageTableReporter::new();
+        let report = create_test_report();
+
+        let table_output = reporter.to_table(&report).unwrap();
+
+        assert!(table_output.contains("=== Test vs Production ==="));
+        assert!(table_output.contains("Test/Code"));
+        assert!(table_output.contains("0.50"));
+    }
+
+    #[test]
+    fn test_to_table_shows_source_roles_of_other_kinds() {
+        let reporter = LanguageTableReporter::new();
+
+        let mut json_lang = Language::new("JSON", &["json"]);
+        json_lang.kind = LanguageKind::Data;
+        let mut json_report = LanguageReportItem::new(json_lang);
+        json_report.file_count = 2;
+        json_report.fixture = SourceStats {
+            file_count: 2,
+            code_lines: 12,
+        };
+        let report = LanguageReport {
+            languages: vec![json_report],
+            ..create_test_report()
+        };
+
+        let table_output = reporter.to_table(&report).unwrap();
+
+        assert!(table_output.contains("=== Test vs Production ==="));
+        assert!(table_output.contains("JSON"));
+    }
+
+    #[test]
+    fn test_to_table_hides_source_roles_without_tests() {
+        let reporter = LanguageTableReporter::new();
+        let mut report = create_test_report();
+        report.languages[0].test = SourceStats::default();
+
+        let table_output = reporter.to_table(&report).unwrap();
+
+        assert!(!table_output.contains("=== Test vs Production ==="));
+    }
+
+    #[test]
+    fn test_to_table_shows_classified_files() {
+        let reporter = LanguageTableReporter::new();
+        let mut report = create_test_report();
+
+        let table_output = reporter.to_table(&report).unwrap();
+        assert!(!table_output.contains("Vendored"));
+
+        report.classified_files = vec![
+            ClassifiedFile {
+                path: "vendor/a.go".to_string(),
+                language: Some("Go".to_string()),
+                classification: FileClassification::Vendored,
+            },
+            ClassifiedFile {
+                path: "vendor/b.go".to_string(),
+                language: Some("Go".to_string()),
+                classification: FileClassification::Vendored,
+            },
+        ];
+
+        let table_output = reporter.to_table(&report).unwrap();
+        assert!(table_output.contains("=== Vendored, Generated and Documentation Files ==="));
+        assert!(table_output.contains("Vendored Files"));
+        assert!(table_output.contains("vendored"));
+    }
+
+    #[test]
+    fn test_percentage_follows_weight() {
+        let reporter = LanguageTableReporter::new();
+
+        let report = LanguageReport {
+            dir: "/test/path".to_string(),
+            total_file_count: 4,
+            weight: Weight::Bytes,
+            kinds: vec![],
+            languages: vec![
+                LanguageReportItem {
+                    language: Language::new("Go", &["go"]),
+                    file_count: 1,
+                    file_paths: vec![],
+                    code_lines: 0,
+                    comment_lines: 0,
+                    blank_lines: 0,
+                    bytes: 750,
+                    content_detected_file_paths: vec![],
+                    embedded_languages: vec![],
+                    production: SourceStats::default(),
+                    test: SourceStats::default(),
+                    fixture: SourceStats::default(),
+                },
+                LanguageReportItem {
+                    language: Language::new("TypeScript", &["ts"]),
+                    file_count: 3,
+                    file_paths: vec![],
+                    code_lines: 0,
+                    comment_lines: 0,
+                    blank_lines: 0,
+                    bytes: 250,
+                    content_detected_file_paths: vec![],
+                    embedded_languages: vec![],
+                    production: SourceStats::default(),
+                    test: SourceStats::default(),
+                    fixture: SourceStats::default(),
+                },
+            ],
+            classified_files: vec![],
+        };
+
+        let table_output = reporter.to_table(&report).unwrap();
+
+        assert!(table_output.contains("75.0%"));
+        assert!(table_output.contains("25.0%"));
+        assert!(table_output.contains("bytes"));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::config::REPORTER_FORMAT_JSON;
+use crate::entity::LicenseReport;
+use crate::service::Reporter;
+use std::io::{self, Write};
+
+pub struct LicenseJsonReporter;
+
+impl Default for LicenseJsonReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LicenseJsonReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn to_json(&self, report: &LicenseReport) -> io::Result<String> {
+        serde_json::to_string_pretty(report)
+            .map_err(|e| io::Error::other(format!("JSON serialization error: {}", e)))
+    }
+}
+
+impl Reporter<LicenseReport> for LicenseJsonReporter {
+    fn name(&self) -> &str {
+        REPORTER_FORMAT_JSON
+    }
+
+    fn file_extension(&self) -> &str {
+        "json"
+    }
+
+    fn render(&self, report: &LicenseReport, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_json(report)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{DetectedLicense, LicenseSource};
+
+    fn create_test_report() -> LicenseReport {
+        LicenseReport {
+            dir: "/test/path".to_string(),
+            project_license: Some("MIT".to_string()),
+            licenses: vec![
+                DetectedLicense {
+                    path: "/test/path/LICENSE".to_string(),
+                    source: LicenseSource::File,
+                    license: Some("MIT".to_string()),
+                    similarity: Some(0.987),
+                    vendored: false,
+                    conflict: false,
+                },
+                DetectedLicense {
+                    path: "/test/path/src/parser.c".to_string(),
+                    source: LicenseSource::Header,
+                    license: Some("GPL-2.0-only".to_string()),
+                    similarity: None,
+                    vendored: false,
+                    conflict: true,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_to_json_success() {
+        let reporter = LicenseJsonReporter::new();
+        let report = create_test_report();
+
+        let json_string = reporter.to_json(&report).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&json_string).expect("Generated JSON is invalid");
+
+        assert_eq!(parsed["project_license"], "MIT");
+        assert_eq!(parsed["licenses"][0]["source"], "file");
+        assert_eq!(parsed["licenses"][1]["source"], "header");
+        assert_eq!(parsed["licenses"][1]["conflict"], true);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::config::LicenseConfig;
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+/// Similarity above which a text is taken to be a bundled license. Reformatted or lightly
+/// edited license files stay above it. Closely related licenses, such as BSD-2-Clause and
+/// BSD-3-Clause, can both pass, so the most similar one is picked.
+const MIN_SIMILARITY: f64 = 0.9;
+
+type Shingles = HashSet<String>;
+
+fn license_shingles() -> &'static [(&'static str, Shingles)] {
+    static SHINGLES: OnceLock<Vec<(&'static str, Shingles)>> = OnceLock::new();
+    SHINGLES.get_or_init(|| {
+        LicenseConfig::texts()
+            .iter()
+            .map(|(id, text)| (*id, LicenseMatcher::shingles(text)))
+            .collect()
+    })
+}
+
+pub struct LicenseMatcher;
+
+impl LicenseMatcher {
+    /// Returns the SPDX identifier of the bundled license most similar to `text`, with its
+    /// similarity, when it is similar enough.
+    pub fn identify(text: &str) -> Option<(&'static str, f64)> {
+        let shingles = Self::shingles(text);
+
+        license_shingles()
+            .iter()
+            .map(|(id, license)| (*id, Self::similarity(&shingles, license)))
+            .filter(|(_, similarity)| *similarity >= MIN_SIMILARITY)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+
+    /// Dice coefficient of two sets of word pairs.
+    fn similarity(a: &Shingles, b: &Shingles) -> f64 {
+        if a.is_empty() || b.is_empty() {
+            return 0.0;
+        }
+        let shared = a.intersection(b).count();
+        2.0 * shared as f64 / (a.len() + b.len()) as f64
+    }
+
+    /// Splits a normalized text into overlapping word pairs. Case, punctuation, layout and
+    /// copyright lines, which differ from project to project, are ignored.
+    fn shingles(text: &str) -> Shingles {
+        let words: Vec<String> = text
+            .lines()
+            .filter(|line| {
+                let line = line.trim_start().to_lowercase();
+                !line.starts_with("copyright") && !line.starts_with("(c)")
+            })
+            .flat_map(|line| {
+                line.split(|c: char| !c.is_alphanumeric())
+                    .filter(|word| !word.is_empty())
+                    .map(|word| word.to_lowercase().replace("licence", "license"))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        words
+            .windows(2)
+            .map(|pair| format!("{} {}", pair[0], pair[1]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod identify {
+        use super::*;
+
+        #[test]
+        fn test_bundled_licenses() {
+            for (id, text) in LicenseConfig::texts() {
+                assert_eq!(
+                    LicenseMatcher::identify(text).map(|(matched, _)| matched),
+                    Some(*id),
+                    "{}",
+                    id
+                );
+            }
+        }
+
+        #[test]
+        fn test_reformatted_license() {
+            let text = "# The MIT Licence\n\nCopyright 2024 Example Corp.\n\nPermission is hereby granted, free of charge, to any person obtaining a copy of this software and associated documentation files (the \"Software\"), to deal in the Software without restriction, including without limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of the Software, and to permit persons to whom the Software is furnished to do so, subject to the following conditions:\n\nThe above copyright notice and this permission notice shall be included in all copies or substantial portions of the Software.\n\nTHE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.\n";
+
+            let (id, similarity) =
+                LicenseMatcher::identify(text).expect("MIT should be identified");
+
+            assert_eq!(id, "MIT");
+            assert!(similarity > 0.95, "{}", similarity);
+        }
+
+        #[test]
+        fn test_unknown_text() {
+            assert_eq!(
+                LicenseMatcher::identify("All rights reserved. Do not redistribute."),
+                None
+            );
+        }
+    }
+}
@@ -0,0 +1,505 @@
+use crate::config::LicenseConfig;
+use crate::entity::{
+    DetectedLicense, File, FileAttributes, FileClassification, LicenseReport,
+    LicenseScannerOptions, LicenseSource, Result, TechScanError,
+};
+use crate::service::{FileClassifier, FileWalker, LicenseMatcher};
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::Read;
+use std::iter::Peekable;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Leading bytes of a source file searched for an SPDX header.
+const HEADER_HEAD_BYTES: u64 = 4096;
+
+/// `LICENSE`, `LICENCE-MIT`, `COPYING.LESSER`, `UNLICENSE` and the like, optionally with a
+/// text extension. Matched against the upper-cased file name.
+fn license_file_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(
+            r"^(?:(?:UN)?LICEN[CS]E(?:[-_][A-Z0-9-]+)?|COPYING(?:[-_.][A-Z0-9-]+)?)(?:\.(?:MD|TXT|RST|MARKDOWN))?$",
+        )
+        .expect("license file regex must be valid")
+    })
+}
+
+fn spdx_header_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"SPDX-License-Identifier:[ \t]*([A-Za-z0-9(][A-Za-z0-9.+:() \t-]*)")
+            .expect("SPDX header regex must be valid")
+    })
+}
+
+#[derive(Debug)]
+pub struct LicenseScanner {
+    dir: String,
+    opts: LicenseScannerOptions,
+}
+
+impl LicenseScanner {
+    pub fn new(dir: &str, opts: Option<LicenseScannerOptions>) -> Result<Self> {
+        if !Path::new(dir).exists() {
+            return Err(TechScanError::DirectoryNotFound(dir.to_string()));
+        }
+
+        Ok(Self {
+            dir: dir.to_string(),
+            opts: opts.unwrap_or_default(),
+        })
+    }
+
+    pub fn scan(&self) -> Result<Vec<File>> {
+        FileWalker::walk(&self.dir, &self.opts.exclude)
+    }
+
+    pub fn analyze(&self, files: Vec<File>) -> LicenseReport {
+        let mut licenses = Vec::new();
+        let mut project_licenses = BTreeSet::new();
+
+        for file in &files {
+            let relative_path = self.relative_path(file);
+            let vendored = FileClassifier::classify_path(relative_path, &FileAttributes::default())
+                == Some(FileClassification::Vendored);
+
+            if Self::is_license_file(&file.name) {
+                let Some(content) = Self::read(file) else {
+                    continue;
+                };
+                let matched = LicenseMatcher::identify(&content);
+                let is_project_file = !relative_path.contains(['/', '\\']);
+                if let (true, Some((id, _))) = (is_project_file, matched) {
+                    project_licenses.insert(id);
+                }
+                licenses.push(DetectedLicense {
+                    path: file.path.clone(),
+                    source: LicenseSource::File,
+                    license: matched.map(|(id, _)| id.to_string()),
+                    similarity: matched.map(|(_, similarity)| similarity),
+                    vendored,
+                    conflict: false,
+                });
+            } else if let Some(expression) = Self::read_spdx_header(file) {
+                licenses.push(DetectedLicense {
+                    path: file.path.clone(),
+                    source: LicenseSource::Header,
+                    license: Some(expression),
+                    similarity: None,
+                    vendored,
+                    conflict: false,
+                });
+            }
+        }
+
+        let project_ids: Vec<String> = project_licenses
+            .iter()
+            .map(|id| SpdxExpression::base_id(id).0)
+            .collect();
+        for license in &mut licenses {
+            license.conflict = match &license.license {
+                Some(expression) if !project_ids.is_empty() => SpdxExpression::parse(expression)
+                    .is_some_and(|expression| !expression.is_compatible(&project_ids)),
+                _ => false,
+            };
+        }
+        licenses.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let project_license = (!project_licenses.is_empty()).then(|| {
+            project_licenses
+                .into_iter()
+                .collect::<Vec<_>>()
+                .join(" OR ")
+        });
+
+        LicenseReport {
+            dir: self.dir.clone(),
+            project_license,
+            licenses,
+        }
+    }
+
+    fn is_license_file(file_name: &str) -> bool {
+        license_file_regex().is_match(&file_name.to_uppercase())
+    }
+
+    fn read_spdx_header(file: &File) -> Option<String> {
+        let mut head = Vec::new();
+        fs::File::open(&file.path)
+            .and_then(|f| f.take(HEADER_HEAD_BYTES).read_to_end(&mut head))
+            .ok()?;
+
+        Self::parse_spdx_header(&String::from_utf8_lossy(&head))
+    }
+
+    /// Returns the expression of the first `SPDX-License-Identifier:` line, without the
+    /// comment delimiters around it.
+    fn parse_spdx_header(head: &str) -> Option<String> {
+        let expression = spdx_header_regex()
+            .captures(head)?
+            .get(1)?
+            .as_str()
+            .trim_end()
+            .trim_end_matches('-')
+            .trim_end();
+        (!expression.is_empty()).then(|| expression.to_string())
+    }
+
+    fn relative_path<'a>(&self, file: &'a File) -> &'a str {
+        Path::new(&file.path)
+            .strip_prefix(&self.dir)
+            .ok()
+            .and_then(|path| path.to_str())
+            .unwrap_or(&file.path)
+    }
+
+    fn read(file: &File) -> Option<String> {
+        match fs::read(&file.path) {
+            Ok(bytes) => Some(String::from_utf8_lossy(&bytes).into_owned()),
+            Err(e) => {
+                log::warn!("Error reading file {}: {}", file.path, e);
+                None
+            }
+        }
+    }
+}
+
+/// A parsed SPDX license expression. `WITH` exceptions only add permissions, so they are
+/// dropped.
+#[derive(Debug, PartialEq)]
+enum SpdxExpression {
+    /// A license id, lower-cased and without its `-only`/`-or-later`/`+` suffix, and whether
+    /// later versions of the license are allowed too.
+    License {
+        id: String,
+        or_later: bool,
+    },
+    And(Vec<SpdxExpression>),
+    Or(Vec<SpdxExpression>),
+}
+
+impl SpdxExpression {
+    fn parse(expression: &str) -> Option<Self> {
+        let spaced = expression.replace('(', " ( ").replace(')', " ) ");
+        let mut tokens = spaced.split_whitespace().peekable();
+        let parsed = Self::parse_or(&mut tokens)?;
+        tokens.next().is_none().then_some(parsed)
+    }
+
+    fn parse_or<'a>(tokens: &mut Peekable<impl Iterator<Item = &'a str>>) -> Option<Self> {
+        let mut terms = vec![Self::parse_and(tokens)?];
+        while tokens
+            .next_if(|token| token.eq_ignore_ascii_case("OR"))
+            .is_some()
+        {
+            terms.push(Self::parse_and(tokens)?);
+        }
+        Some(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Self::Or(terms)
+        })
+    }
+
+    fn parse_and<'a>(tokens: &mut Peekable<impl Iterator<Item = &'a str>>) -> Option<Self> {
+        let mut terms = vec![Self::parse_term(tokens)?];
+        while tokens
+            .next_if(|token| token.eq_ignore_ascii_case("AND"))
+            .is_some()
+        {
+            terms.push(Self::parse_term(tokens)?);
+        }
+        Some(if terms.len() == 1 {
+            terms.remove(0)
+        } else {
+            Self::And(terms)
+        })
+    }
+
+    fn parse_term<'a>(tokens: &mut Peekable<impl Iterator<Item = &'a str>>) -> Option<Self> {
+        let term = match tokens.next()? {
+            "(" => {
+                let inner = Self::parse_or(tokens)?;
+                tokens.next_if_eq(&")")?;
+                inner
+            }
+            ")" => return None,
+            token if ["AND", "OR", "WITH"].contains(&token.to_uppercase().as_str()) => return None,
+            token => {
+                let (id, or_later) = Self::base_id(token);
+                Self::License { id, or_later }
+            }
+        };
+        if tokens
+            .next_if(|token| token.eq_ignore_ascii_case("WITH"))
+            .is_some()
+        {
+            tokens.next()?;
+        }
+        Some(term)
+    }
+
+    /// Splits a license id into its lower-cased base id and whether it allows later
+    /// versions.
+    fn base_id(id: &str) -> (String, bool) {
+        let id = id.to_lowercase();
+        if let Some(base) = id
+            .strip_suffix('+')
+            .or_else(|| id.strip_suffix("-or-later"))
+        {
+            return (base.to_string(), true);
+        }
+        (id.trim_end_matches("-only").to_string(), false)
+    }
+
+    /// Whether a project under any of `project_ids` can include code under this expression:
+    /// one alternative of an `OR` is enough, but every part of an `AND` has to fit.
+    fn is_compatible(&self, project_ids: &[String]) -> bool {
+        match self {
+            Self::License { id, or_later } => {
+                LicenseConfig::is_non_reciprocal(id)
+                    || project_ids
+                        .iter()
+                        .any(|project| project == id || (*or_later && Self::is_later(project, id)))
+            }
+            Self::And(terms) => terms.iter().all(|term| term.is_compatible(project_ids)),
+            Self::Or(terms) => terms.iter().any(|term| term.is_compatible(project_ids)),
+        }
+    }
+
+    /// Whether `project` is a later version of the same license as `id`, such as `gpl-3.0`
+    /// for `gpl-2.0`.
+    fn is_later(project: &str, id: &str) -> bool {
+        match (project.rsplit_once('-'), id.rsplit_once('-')) {
+            (Some((project_name, project_version)), Some((name, version)))
+                if project_name == name =>
+            {
+                match (project_version.parse::<f64>(), version.parse::<f64>()) {
+                    (Ok(project_version), Ok(version)) => project_version > version,
+                    _ => false,
+                }
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn licenses(report: &LicenseReport) -> Vec<(&str, Option<&str>, bool, bool)> {
+        report
+            .licenses
+            .iter()
+            .map(|license| {
+                (
+                    license.path.as_str(),
+                    license.license.as_deref(),
+                    license.vendored,
+                    license.conflict,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_scanner_nonexistent_directory() {
+        let result = LicenseScanner::new("nonexistent_directory_12345", None);
+
+        assert!(matches!(result, Err(TechScanError::DirectoryNotFound(_))));
+    }
+
+    #[test]
+    fn test_analyze_license_fixture() {
+        let scanner = LicenseScanner::new("tests/fixtures/license", None)
+            .expect("LicenseScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        assert_eq!(report.project_license.as_deref(), Some("Apache-2.0 OR MIT"));
+        assert_eq!(
+            licenses(&report),
+            vec![
+                (
+                    "tests/fixtures/license/LICENSE-APACHE",
+                    Some("Apache-2.0"),
+                    false,
+                    false
+                ),
+                (
+                    "tests/fixtures/license/LICENSE-MIT",
+                    Some("MIT"),
+                    false,
+                    false
+                ),
+                (
+                    "tests/fixtures/license/src/lib.rs",
+                    Some("MIT OR Apache-2.0"),
+                    false,
+                    false
+                ),
+                (
+                    "tests/fixtures/license/src/parser.c",
+                    Some("GPL-2.0-only"),
+                    false,
+                    true
+                ),
+                (
+                    "tests/fixtures/license/vendor/fmt/LICENSE",
+                    Some("BSD-3-Clause"),
+                    true,
+                    false
+                ),
+                (
+                    "tests/fixtures/license/vendor/legacy/LICENSE.txt",
+                    None,
+                    true,
+                    false
+                ),
+            ]
+        );
+    }
+
+    mod is_license_file {
+        use super::*;
+
+        #[test]
+        fn test_license_file_names() {
+            for name in [
+                "LICENSE",
+                "LICENSE.md",
+                "license.txt",
+                "LICENCE",
+                "LICENSE-MIT",
+                "LICENSE-APACHE",
+                "COPYING",
+                "COPYING.LESSER",
+                "UNLICENSE",
+            ] {
+                assert!(LicenseScanner::is_license_file(name), "{}", name);
+            }
+        }
+
+        #[test]
+        fn test_other_file_names() {
+            for name in [
+                "license.rs",
+                "license_config.rs",
+                "LICENSES.json",
+                "README.md",
+            ] {
+                assert!(!LicenseScanner::is_license_file(name), "{}", name);
+            }
+        }
+    }
+
+    mod parse_spdx_header {
+        use super::*;
+
+        #[test]
+        fn test_comment_styles() {
+            for (head, expected) in [
+                (
+                    "/* SPDX-License-Identifier: GPL-2.0-only */\n",
+                    "GPL-2.0-only",
+                ),
+                ("// SPDX-License-Identifier: MIT\n", "MIT"),
+                (
+                    "<!-- SPDX-License-Identifier: Apache-2.0 -->\n",
+                    "Apache-2.0",
+                ),
+                (
+                    "#!/bin/sh\n# SPDX-License-Identifier: (MIT OR Apache-2.0)\n",
+                    "(MIT OR Apache-2.0)",
+                ),
+            ] {
+                assert_eq!(
+                    LicenseScanner::parse_spdx_header(head).as_deref(),
+                    Some(expected),
+                    "{}",
+                    head
+                );
+            }
+        }
+
+        #[test]
+        fn test_no_expression() {
+            assert_eq!(LicenseScanner::parse_spdx_header("fn main() {}\n"), None);
+            assert_eq!(
+                LicenseScanner::parse_spdx_header("let pattern = \"SPDX-License-Identifier:\";\n"),
+                None
+            );
+        }
+    }
+
+    mod spdx_expression {
+        use super::*;
+
+        fn is_compatible(expression: &str, project_ids: &[&str]) -> bool {
+            let project_ids: Vec<String> = project_ids.iter().map(|id| id.to_string()).collect();
+            SpdxExpression::parse(expression)
+                .expect("expression should parse")
+                .is_compatible(&project_ids)
+        }
+
+        #[test]
+        fn test_parse() {
+            let license = |id: &str, or_later| SpdxExpression::License {
+                id: id.to_string(),
+                or_later,
+            };
+            assert_eq!(SpdxExpression::parse("MIT"), Some(license("mit", false)));
+            assert_eq!(
+                SpdxExpression::parse("(MIT OR Apache-2.0) AND BSD-3-Clause"),
+                Some(SpdxExpression::And(vec![
+                    SpdxExpression::Or(vec![license("mit", false), license("apache-2.0", false)]),
+                    license("bsd-3-clause", false),
+                ]))
+            );
+            assert_eq!(
+                SpdxExpression::parse("MIT OR GPL-2.0+ AND LGPL-2.1-only"),
+                Some(SpdxExpression::Or(vec![
+                    license("mit", false),
+                    SpdxExpression::And(vec![license("gpl-2.0", true), license("lgpl-2.1", false)]),
+                ]))
+            );
+            assert_eq!(
+                SpdxExpression::parse("GPL-2.0-or-later WITH Classpath-exception-2.0"),
+                Some(license("gpl-2.0", true))
+            );
+        }
+
+        #[test]
+        fn test_parse_invalid() {
+            for expression in ["", "MIT OR", "(MIT", "MIT)", "AND MIT", "MIT WITH"] {
+                assert_eq!(SpdxExpression::parse(expression), None, "{}", expression);
+            }
+        }
+
+        #[test]
+        fn test_and_requires_every_part() {
+            assert!(!is_compatible("MIT AND GPL-3.0", &["mit"]));
+            assert!(is_compatible("MIT AND Apache-2.0", &["mit"]));
+        }
+
+        #[test]
+        fn test_or_requires_one_alternative() {
+            assert!(is_compatible("GPL-3.0 OR MIT", &["mit"]));
+            assert!(!is_compatible("GPL-3.0 OR AGPL-3.0", &["mit"]));
+        }
+
+        #[test]
+        fn test_or_later_versions() {
+            assert!(is_compatible("GPL-2.0-or-later", &["gpl-3.0"]));
+            assert!(is_compatible("GPL-2.0+", &["gpl-3.0"]));
+            assert!(!is_compatible("GPL-2.0-only", &["gpl-3.0"]));
+            assert!(!is_compatible("GPL-3.0-or-later", &["gpl-2.0"]));
+            assert!(!is_compatible("AGPL-3.0-or-later", &["gpl-3.0"]));
+        }
+    }
+}
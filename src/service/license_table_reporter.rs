@@ -0,0 +1,201 @@
+use crate::config::REPORTER_FORMAT_TABLE;
+use crate::entity::{DetectedLicense, LicenseReport, LicenseSource};
+use crate::service::Reporter;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use tabled::builder::Builder;
+use tabled::settings::{object::Rows, Alignment, Modify, Style};
+
+pub struct LicenseTableReporter;
+
+impl Default for LicenseTableReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LicenseTableReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn to_table(&self, report: &LicenseReport) -> String {
+        let mut output = Vec::new();
+
+        let license_files: Vec<&DetectedLicense> = report
+            .licenses
+            .iter()
+            .filter(|license| license.source == LicenseSource::File)
+            .collect();
+        let headers: Vec<&DetectedLicense> = report
+            .licenses
+            .iter()
+            .filter(|license| license.source == LicenseSource::Header)
+            .collect();
+        let conflicts: Vec<&DetectedLicense> = report
+            .licenses
+            .iter()
+            .filter(|license| license.conflict)
+            .collect();
+
+        let mut summary_builder = Builder::default();
+        summary_builder.push_record(vec!["Item", "Value"]);
+        summary_builder.push_record(vec!["Directory", &report.dir]);
+        summary_builder.push_record(vec![
+            "Project License",
+            report.project_license.as_deref().unwrap_or("-"),
+        ]);
+        summary_builder.push_record(vec!["License Files", &license_files.len().to_string()]);
+        summary_builder.push_record(vec!["SPDX Headers", &headers.len().to_string()]);
+        summary_builder.push_record(vec!["Conflicts", &conflicts.len().to_string()]);
+
+        let summary_table = summary_builder
+            .build()
+            .with(Style::sharp())
+            .with(Modify::new(Rows::new(0..=0)).with(Alignment::center()))
+            .to_string();
+
+        output.push("=== Scan Summary ===".to_string());
+        output.push(summary_table);
+
+        if !license_files.is_empty() {
+            let mut builder = Builder::default();
+            builder.push_record(vec![
+                "Path",
+                "License",
+                "Similarity",
+                "Vendored",
+                "Conflict",
+            ]);
+            for license in &license_files {
+                let similarity = license.similarity.map_or_else(
+                    || "-".to_string(),
+                    |similarity| format!("{:.1}%", similarity * 100.0),
+                );
+                builder.push_record(vec![
+                    &license.path,
+                    license.license.as_deref().unwrap_or("unknown"),
+                    &similarity,
+                    Self::yes_no(license.vendored),
+                    Self::yes_no(license.conflict),
+                ]);
+            }
+
+            output.push(String::new());
+            output.push("=== License Files ===".to_string());
+            output.push(builder.build().with(Style::sharp()).to_string());
+        }
+
+        if !headers.is_empty() {
+            output.push(String::new());
+            output.push("=== SPDX Headers ===".to_string());
+            output.push(Self::header_table(&headers));
+        }
+
+        if !conflicts.is_empty() {
+            let mut builder = Builder::default();
+            builder.push_record(vec!["Path", "Source", "License"]);
+            for license in &conflicts {
+                builder.push_record(vec![
+                    &license.path,
+                    license.source.as_str(),
+                    license.license.as_deref().unwrap_or("unknown"),
+                ]);
+            }
+
+            output.push(String::new());
+            output.push("=== Conflicts ===".to_string());
+            output.push(builder.build().with(Style::sharp()).to_string());
+        }
+
+        output.join("\n")
+    }
+
+    /// Counts the source files per SPDX expression. Individual files are listed in the
+    /// JSON report, and under conflicts when they conflict.
+    fn header_table(headers: &[&DetectedLicense]) -> String {
+        let mut counts: BTreeMap<&str, (u64, bool)> = BTreeMap::new();
+        for header in headers {
+            let count = counts
+                .entry(header.license.as_deref().unwrap_or("unknown"))
+                .or_default();
+            count.0 += 1;
+            count.1 |= header.conflict;
+        }
+
+        let mut builder = Builder::default();
+        builder.push_record(vec!["License", "Files", "Conflict"]);
+        for (license, (files, conflict)) in counts {
+            builder.push_record(vec![license, &files.to_string(), Self::yes_no(conflict)]);
+        }
+
+        builder.build().with(Style::sharp()).to_string()
+    }
+
+    fn yes_no(value: bool) -> &'static str {
+        if value {
+            "yes"
+        } else {
+            "no"
+        }
+    }
+}
+
+impl Reporter<LicenseReport> for LicenseTableReporter {
+    fn name(&self) -> &str {
+        REPORTER_FORMAT_TABLE
+    }
+
+    fn file_extension(&self) -> &str {
+        "txt"
+    }
+
+    fn render(&self, report: &LicenseReport, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_table(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_report() -> LicenseReport {
+        LicenseReport {
+            dir: "/test/path".to_string(),
+            project_license: Some("MIT".to_string()),
+            licenses: vec![
+                DetectedLicense {
+                    path: "/test/path/LICENSE".to_string(),
+                    source: LicenseSource::File,
+                    license: Some("MIT".to_string()),
+                    similarity: Some(0.987),
+                    vendored: false,
+                    conflict: false,
+                },
+                DetectedLicense {
+                    path: "/test/path/src/parser.c".to_string(),
+                    source: LicenseSource::Header,
+                    license: Some("GPL-2.0-only".to_string()),
+                    similarity: None,
+                    vendored: false,
+                    conflict: true,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_to_table_lists_conflicts() {
+        let reporter = LicenseTableReporter::new();
+        let report = create_test_report();
+
+        let table_output = reporter.to_table(&report);
+
+        assert!(table_output.contains("Project License"));
+        assert!(table_output.contains("=== License Files ==="));
+        assert!(table_output.contains("98.7%"));
+        assert!(table_output.contains("=== SPDX Headers ==="));
+        assert!(table_output.contains("=== Conflicts ==="));
+        assert!(table_output.contains("/test/path/src/parser.c"));
+    }
+}
@@ -0,0 +1,275 @@
+use crate::entity::{CommentSyntax, LineStats};
+
+pub struct LineCounter;
+
+impl LineCounter {
+    /// Counts code, comment and blank lines of `content`.
+    ///
+    /// A line is counted as code when it has any non-whitespace text outside comments,
+    /// otherwise as comment. String literals are not tokenized, so comment delimiters
+    /// inside strings are treated as real comments.
+    pub fn count(content: &str, syntax: &CommentSyntax) -> LineStats {
+        let mut stats = LineStats::default();
+        let mut depth = 0usize;
+        let mut current_block: Option<(&str, &str)> = None;
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                stats.blank += 1;
+                continue;
+            }
+
+            if syntax.block_at_line_start {
+                if let Some((_, end)) = current_block {
+                    if Self::starts_with_marker(line, end) {
+                        current_block = None;
+                    }
+                    stats.comment += 1;
+                    continue;
+                }
+                if let Some((start, end)) = syntax
+                    .block
+                    .iter()
+                    .find(|(start, _)| Self::starts_with_marker(line, start))
+                {
+                    current_block = Some((start, end));
+                    stats.comment += 1;
+                    continue;
+                }
+            }
+
+            let mut has_code = false;
+            let mut rest = trimmed;
+
+            while !rest.is_empty() {
+                if let Some((start, end)) = current_block {
+                    let end_pos = rest.find(end);
+                    let start_pos = if syntax.nested {
+                        rest.find(start)
+                    } else {
+                        None
+                    };
+
+                    match (start_pos, end_pos) {
+                        (Some(s), Some(e)) if s < e => {
+                            depth += 1;
+                            rest = &rest[s + start.len()..];
+                        }
+                        (Some(s), None) => {
+                            depth += 1;
+                            rest = &rest[s + start.len()..];
+                        }
+                        (_, Some(e)) => {
+                            depth -= 1;
+                            if depth == 0 {
+                                current_block = None;
+                            }
+                            rest = &rest[e + end.len()..];
+                        }
+                        (None, None) => rest = "",
+                    }
+                    continue;
+                }
+
+                match Self::find_comment_start(rest, syntax) {
+                    Some((pos, CommentStart::Line)) => {
+                        has_code |= !rest[..pos].trim().is_empty();
+                        rest = "";
+                    }
+                    Some((pos, CommentStart::Block(start, end))) => {
+                        has_code |= !rest[..pos].trim().is_empty();
+                        depth = 1;
+                        current_block = Some((start, end));
+                        rest = &rest[pos + start.len()..];
+                    }
+                    None => {
+                        has_code = true;
+                        rest = "";
+                    }
+                }
+            }
+
+            if has_code {
+                stats.code += 1;
+            } else {
+                stats.comment += 1;
+            }
+        }
+
+        stats
+    }
+
+    fn find_comment_start<'a>(
+        text: &str,
+        syntax: &'a CommentSyntax,
+    ) -> Option<(usize, CommentStart<'a>)> {
+        let line_starts = syntax.line.iter().filter_map(|marker| {
+            text.find(marker.as_str())
+                .map(|pos| (pos, marker.len(), CommentStart::Line))
+        });
+
+        // Blocks that open at the start of a line are handled by `count`.
+        let block_starts = syntax
+            .block
+            .iter()
+            .filter(|_| !syntax.block_at_line_start)
+            .filter_map(|(start, end)| {
+                text.find(start.as_str())
+                    .map(|pos| (pos, start.len(), CommentStart::Block(start, end)))
+            });
+
+        // The earliest marker wins; on a tie the longest one does, so that Lua's `--[[`
+        // is not mistaken for a `--` line comment.
+        line_starts
+            .chain(block_starts)
+            .min_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)))
+            .map(|(pos, _, start)| (pos, start))
+    }
+
+    /// Whether `line` starts with `marker` in its first column, followed by whitespace or
+    /// the end of the line, as POD commands and Ruby's `=begin`/`=end` must. A trailing `*`
+    /// in `marker` stands for any identifier, so `=*` matches `=pod`, `=head1` and `=over`.
+    fn starts_with_marker(line: &str, marker: &str) -> bool {
+        let rest = match marker.strip_suffix('*') {
+            Some(prefix) => line
+                .strip_prefix(prefix)
+                .filter(|rest| rest.starts_with(|c: char| c.is_ascii_alphabetic()))
+                .map(|rest| {
+                    rest.trim_start_matches(|c: char| c.is_ascii_alphanumeric() || c == '_')
+                }),
+            None => line.strip_prefix(marker),
+        };
+        rest.is_some_and(|rest| rest.chars().next().is_none_or(char::is_whitespace))
+    }
+}
+
+enum CommentStart<'a> {
+    Line,
+    Block(&'a str, &'a str),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LanguageConfig;
+
+    fn syntax_of(name: &str) -> CommentSyntax {
+        LanguageConfig::default()
+            .get_language_by_name(name)
+            .unwrap()
+            .comment
+            .clone()
+    }
+
+    mod count {
+        use super::*;
+
+        #[test]
+        fn test_count_code_comment_and_blank_lines() {
+            let content = "// header\nfn main() {\n\n    println!(\"hi\"); // trailing\n}\n";
+            let stats = LineCounter::count(content, &syntax_of("Rust"));
+
+            assert_eq!(
+                stats,
+                LineStats {
+                    code: 3,
+                    comment: 1,
+                    blank: 1,
+                }
+            );
+        }
+
+        #[test]
+        fn test_count_block_comment_spanning_lines() {
+            let content = "/*\n * doc\n */\nint main() { return 0; }\n";
+            let stats = LineCounter::count(content, &syntax_of("C"));
+
+            assert_eq!(stats.comment, 3);
+            assert_eq!(stats.code, 1);
+        }
+
+        #[test]
+        fn test_count_code_after_block_comment_end() {
+            let content = "/* start\nend */ int x = 1;\n";
+            let stats = LineCounter::count(content, &syntax_of("C"));
+
+            assert_eq!(stats.comment, 1);
+            assert_eq!(stats.code, 1);
+        }
+
+        #[test]
+        fn test_count_nested_block_comment() {
+            let content = "/* outer\n/* inner */\nstill comment */\nfn main() {}\n";
+            let stats = LineCounter::count(content, &syntax_of("Rust"));
+
+            assert_eq!(stats.comment, 3);
+            assert_eq!(stats.code, 1);
+        }
+
+        #[test]
+        fn test_count_non_nested_block_comment() {
+            let content = "/* outer\n/* inner */\nint x = 1;\n";
+            let stats = LineCounter::count(content, &syntax_of("C"));
+
+            assert_eq!(stats.comment, 2);
+            assert_eq!(stats.code, 1);
+        }
+
+        #[test]
+        fn test_count_prefers_longest_marker() {
+            let content = "--[[\nblock\n]]\nprint(1) -- note\n";
+            let stats = LineCounter::count(content, &syntax_of("Lua"));
+
+            assert_eq!(stats.comment, 3);
+            assert_eq!(stats.code, 1);
+        }
+
+        #[test]
+        fn test_count_pod_block_at_line_start() {
+            let content =
+                "my $x = 1;\n=pod\n\nDocs.\n\n=cut\nprint $x;\n=head1 NAME\n\nx - demo\n=cut\n";
+            let stats = LineCounter::count(content, &syntax_of("Perl"));
+
+            assert_eq!(stats.code, 2);
+            assert_eq!(stats.comment, 6);
+            assert_eq!(stats.blank, 3);
+        }
+
+        #[test]
+        fn test_count_pod_marker_inside_line() {
+            let content = "my $x = $a =pod;\nprint \"=cut\";\n  =pod\n=1;\nprint $x;\n";
+            let stats = LineCounter::count(content, &syntax_of("Perl"));
+
+            assert_eq!(stats.code, 5);
+            assert_eq!(stats.comment, 0);
+        }
+
+        #[test]
+        fn test_count_ruby_block_at_line_start() {
+            let content = "=begin\nnotes\n=end\nputs 1\n";
+            let stats = LineCounter::count(content, &syntax_of("Ruby"));
+
+            assert_eq!(stats.comment, 3);
+            assert_eq!(stats.code, 1);
+        }
+
+        #[test]
+        fn test_count_ruby_marker_inside_line() {
+            let content = "text = \"=begin\"\n=beginning = 1\nputs text # note\n";
+            let stats = LineCounter::count(content, &syntax_of("Ruby"));
+
+            assert_eq!(stats.code, 3);
+            assert_eq!(stats.comment, 0);
+        }
+
+        #[test]
+        fn test_count_without_comment_syntax() {
+            let content = "a\n\n# b\n";
+            let stats = LineCounter::count(content, &CommentSyntax::default());
+
+            assert_eq!(stats.code, 2);
+            assert_eq!(stats.blank, 1);
+        }
+    }
+}
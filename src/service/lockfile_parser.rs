@@ -0,0 +1,625 @@
+use crate::entity::{LockfileFormat, ResolvedDependency, Result, TechScanError};
+use serde_json::Value as JsonValue;
+use std::collections::HashSet;
+use toml::{Table as TomlTable, Value as TomlValue};
+use yaml_rust2::{Yaml, YamlLoader};
+
+/// Sections of `package.json` and pnpm importers that list the project's own requirements.
+const NPM_DEPENDENCY_SECTIONS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "optionalDependencies",
+    "peerDependencies",
+];
+
+pub struct LockfileParser;
+
+impl LockfileParser {
+    /// Parses the packages pinned by a lockfile, sorted by name and version. Packages are
+    /// marked direct when the lockfile itself records the project's requirements, as
+    /// `package-lock.json` v2+, `pnpm-lock.yaml`, `Cargo.lock` and `Gemfile.lock` do.
+    pub fn parse(format: LockfileFormat, content: &str) -> Result<Vec<ResolvedDependency>> {
+        let mut dependencies = match format {
+            LockfileFormat::PackageLock => Self::parse_package_lock(content)?,
+            LockfileFormat::YarnLock => Self::parse_yarn_lock(content),
+            LockfileFormat::PnpmLock => Self::parse_pnpm_lock(content)?,
+            LockfileFormat::CargoLock => Self::parse_cargo_lock(content)?,
+            LockfileFormat::GoSum => Self::parse_go_sum(content),
+            LockfileFormat::PoetryLock => Self::parse_poetry_lock(content)?,
+            LockfileFormat::GemfileLock => Self::parse_gemfile_lock(content),
+            LockfileFormat::ComposerLock => Self::parse_composer_lock(content)?,
+        };
+
+        // The same package can be installed at several places of a tree, e.g. nested
+        // `node_modules`; it is listed once and stays direct if any occurrence is.
+        dependencies.sort();
+        dependencies.dedup_by(|later, earlier| {
+            let duplicate = later.name == earlier.name && later.version == earlier.version;
+            if duplicate {
+                earlier.direct |= later.direct;
+            }
+            duplicate
+        });
+
+        Ok(dependencies)
+    }
+
+    fn parse_package_lock(content: &str) -> Result<Vec<ResolvedDependency>> {
+        let value: JsonValue = serde_json::from_str(content)
+            .map_err(|e| Self::invalid_lockfile("package-lock.json", e))?;
+
+        // Lockfile v2 and v3 key packages by their install path. The root project and
+        // workspace members are keyed by their directory and list the direct requirements.
+        if let Some(packages) = value.get("packages").and_then(JsonValue::as_object) {
+            let direct: HashSet<&str> = packages
+                .iter()
+                .filter(|(path, _)| !path.contains("node_modules/"))
+                .flat_map(|(_, package)| Self::json_keys(package, NPM_DEPENDENCY_SECTIONS))
+                .collect();
+
+            return Ok(packages
+                .iter()
+                .filter_map(|(path, package)| {
+                    let (parent, name) = path.rsplit_once("node_modules/")?;
+                    if package.get("link").and_then(JsonValue::as_bool) == Some(true) {
+                        return None;
+                    }
+                    let version = package.get("version")?.as_str()?;
+                    let top_level = !parent.contains("node_modules/");
+                    Some(ResolvedDependency::new(
+                        name,
+                        version,
+                        top_level && direct.contains(name),
+                    ))
+                })
+                .collect());
+        }
+
+        // Lockfile v1 nests packages by dependency tree and does not tell direct ones apart.
+        let mut dependencies = Vec::new();
+        if let Some(entries) = value.get("dependencies").and_then(JsonValue::as_object) {
+            Self::package_lock_v1(entries, &mut dependencies);
+        }
+        Ok(dependencies)
+    }
+
+    fn package_lock_v1(
+        entries: &serde_json::Map<String, JsonValue>,
+        dependencies: &mut Vec<ResolvedDependency>,
+    ) {
+        for (name, entry) in entries {
+            if let Some(version) = entry.get("version").and_then(JsonValue::as_str) {
+                dependencies.push(ResolvedDependency::new(name, version, false));
+            }
+            if let Some(nested) = entry.get("dependencies").and_then(JsonValue::as_object) {
+                Self::package_lock_v1(nested, dependencies);
+            }
+        }
+    }
+
+    fn json_keys<'a>(value: &'a JsonValue, sections: &[&str]) -> Vec<&'a str> {
+        sections
+            .iter()
+            .filter_map(|section| value.get(section).and_then(JsonValue::as_object))
+            .flat_map(|entries| entries.keys().map(String::as_str))
+            .collect()
+    }
+
+    /// Parses both the classic v1 format (`version "1.2.3"`) and the YAML-based format of
+    /// Yarn 2+ (`version: 1.2.3`). Workspace packages are the project itself and skipped.
+    fn parse_yarn_lock(content: &str) -> Vec<ResolvedDependency> {
+        let mut dependencies = Vec::new();
+        let mut name: Option<&str> = None;
+
+        for line in content.lines() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+            if !line.starts_with(' ') {
+                name = line.strip_suffix(':').and_then(Self::yarn_package_name);
+                continue;
+            }
+
+            let Some(current) = name else {
+                continue;
+            };
+            // Fields of an entry are indented by two spaces, its requirements by four.
+            let Some(field) = line.strip_prefix("  ").filter(|f| !f.starts_with(' ')) else {
+                continue;
+            };
+            let Some(version) = field.strip_prefix("version") else {
+                continue;
+            };
+            let version = version.trim_start_matches(':').trim().trim_matches('"');
+            dependencies.push(ResolvedDependency::new(current, version, false));
+            name = None;
+        }
+
+        dependencies
+    }
+
+    /// Extracts the package name from an entry header such as
+    /// `"@babel/core@^7.0.0", "@babel/core@^7.1.0"` or `lodash@npm:^4.17.21`.
+    fn yarn_package_name(header: &str) -> Option<&str> {
+        let descriptor = header.split(',').next()?.trim().trim_matches('"');
+        if descriptor == "__metadata" || descriptor.contains("@workspace:") {
+            return None;
+        }
+        let at = descriptor.get(1..)?.find('@')? + 1;
+        Some(&descriptor[..at])
+    }
+
+    fn parse_pnpm_lock(content: &str) -> Result<Vec<ResolvedDependency>> {
+        let documents = YamlLoader::load_from_str(content)
+            .map_err(|e| Self::invalid_lockfile("pnpm-lock.yaml", e))?;
+        let Some(document) = documents.first() else {
+            return Ok(Vec::new());
+        };
+
+        // Workspaces list each project under `importers`; single projects before lockfile
+        // v6 list their requirements at the top level.
+        let importers: Vec<&Yaml> = match document["importers"].as_hash() {
+            Some(importers) => importers.values().collect(),
+            None => vec![document],
+        };
+        let mut direct: HashSet<(&str, &str)> = HashSet::new();
+        for importer in importers {
+            for section in NPM_DEPENDENCY_SECTIONS {
+                let Some(entries) = importer[*section].as_hash() else {
+                    continue;
+                };
+                for (name, spec) in entries {
+                    // v6+ write `{ specifier, version }`, older versions the version only.
+                    let version = match spec {
+                        Yaml::Hash(_) => spec["version"].as_str(),
+                        _ => spec.as_str(),
+                    };
+                    if let (Some(name), Some(version)) = (name.as_str(), version) {
+                        direct.insert((name, Self::strip_pnpm_peers(version)));
+                    }
+                }
+            }
+        }
+
+        let Some(packages) = document["packages"].as_hash() else {
+            return Ok(Vec::new());
+        };
+        Ok(packages
+            .keys()
+            .filter_map(|key| Self::pnpm_package(key.as_str()?))
+            .map(|(name, version)| {
+                ResolvedDependency::new(name, version, direct.contains(&(name, version)))
+            })
+            .collect())
+    }
+
+    /// Splits a package key: `/@babel/core/7.2.0_react@18.2.0` before lockfile v6,
+    /// `/@babel/core@7.2.0(react@18.2.0)` in v6 and `@babel/core@7.2.0` from v9.
+    fn pnpm_package(key: &str) -> Option<(&str, &str)> {
+        let key = key.trim_start_matches('/');
+        let scope_len = if key.starts_with('@') {
+            key.find('/')? + 1
+        } else {
+            0
+        };
+        let name_len = scope_len + key[scope_len..].find(['@', '/'])?;
+        Some((
+            &key[..name_len],
+            Self::strip_pnpm_peers(&key[name_len + 1..]),
+        ))
+    }
+
+    fn strip_pnpm_peers(version: &str) -> &str {
+        version.split(['(', '_']).next().unwrap_or(version)
+    }
+
+    fn parse_cargo_lock(content: &str) -> Result<Vec<ResolvedDependency>> {
+        let table: TomlTable = content
+            .parse()
+            .map_err(|e| Self::invalid_lockfile("Cargo.lock", e))?;
+        let packages: Vec<&TomlTable> = table
+            .get("package")
+            .and_then(TomlValue::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(TomlValue::as_table)
+            .collect();
+
+        // Workspace members have no `source`. Their `dependencies` name the direct
+        // requirements, with a version only when several versions are locked.
+        let (members, locked): (Vec<&TomlTable>, Vec<&TomlTable>) = packages
+            .into_iter()
+            .partition(|package| !package.contains_key("source"));
+        let direct: HashSet<&str> = members
+            .iter()
+            .filter_map(|member| member.get("dependencies").and_then(TomlValue::as_array))
+            .flatten()
+            .filter_map(TomlValue::as_str)
+            .collect();
+
+        Ok(locked
+            .into_iter()
+            .filter_map(|package| {
+                let name = package.get("name")?.as_str()?;
+                let version = package.get("version")?.as_str()?;
+                let is_direct = direct.contains(name)
+                    || direct.contains(format!("{} {}", name, version).as_str());
+                Some(ResolvedDependency::new(name, version, is_direct))
+            })
+            .collect())
+    }
+
+    /// Lists the modules whose content is checksummed. `/go.mod` checksums are also kept
+    /// for modules that were only consulted while resolving versions, so they are skipped.
+    fn parse_go_sum(content: &str) -> Vec<ResolvedDependency> {
+        content
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let module = parts.next()?;
+                let version = parts.next()?;
+                if version.ends_with("/go.mod") {
+                    return None;
+                }
+                Some(ResolvedDependency::new(module, version, false))
+            })
+            .collect()
+    }
+
+    fn parse_poetry_lock(content: &str) -> Result<Vec<ResolvedDependency>> {
+        let table: TomlTable = content
+            .parse()
+            .map_err(|e| Self::invalid_lockfile("poetry.lock", e))?;
+
+        Ok(table
+            .get("package")
+            .and_then(TomlValue::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|package| {
+                let name = package.get("name")?.as_str()?;
+                let version = package.get("version")?.as_str()?;
+                Some(ResolvedDependency::new(name, version, false))
+            })
+            .collect())
+    }
+
+    fn parse_gemfile_lock(content: &str) -> Vec<ResolvedDependency> {
+        let mut dependencies = Vec::new();
+        let mut direct: HashSet<&str> = HashSet::new();
+        let mut section = "";
+
+        for line in content.lines() {
+            if !line.starts_with(' ') {
+                section = line.trim();
+                continue;
+            }
+            match section {
+                // Specs are indented by four spaces, their own requirements by six.
+                "GEM" | "GIT" | "PATH" => {
+                    let Some(spec) = line.strip_prefix("    ").filter(|s| !s.starts_with(' '))
+                    else {
+                        continue;
+                    };
+                    if let Some((name, version)) = spec.split_once(" (") {
+                        dependencies.push(ResolvedDependency::new(
+                            name,
+                            version.trim_end_matches(')'),
+                            false,
+                        ));
+                    }
+                }
+                "DEPENDENCIES" => {
+                    if let Some(name) = line.split([' ', '!']).find(|part| !part.is_empty()) {
+                        direct.insert(name);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for dependency in &mut dependencies {
+            dependency.direct = direct.contains(dependency.name.as_str());
+        }
+        dependencies
+    }
+
+    fn parse_composer_lock(content: &str) -> Result<Vec<ResolvedDependency>> {
+        let value: JsonValue = serde_json::from_str(content)
+            .map_err(|e| Self::invalid_lockfile("composer.lock", e))?;
+
+        Ok(["packages", "packages-dev"]
+            .iter()
+            .filter_map(|section| value.get(section).and_then(JsonValue::as_array))
+            .flatten()
+            .filter_map(|package| {
+                let name = package.get("name")?.as_str()?;
+                let version = package.get("version")?.as_str()?;
+                Some(ResolvedDependency::new(name, version, false))
+            })
+            .collect())
+    }
+
+    fn invalid_lockfile(name: &str, error: impl std::fmt::Display) -> TechScanError {
+        TechScanError::ValidationError(format!("Invalid {}: {}", name, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(dependencies: &[ResolvedDependency]) -> Vec<(&str, &str, bool)> {
+        dependencies
+            .iter()
+            .map(|dependency| {
+                (
+                    dependency.name.as_str(),
+                    dependency.version.as_str(),
+                    dependency.direct,
+                )
+            })
+            .collect()
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_package_lock_v3() {
+            let content = r#"{
+                "lockfileVersion": 3,
+                "packages": {
+                    "": {"dependencies": {"express": "^4.18.0"}},
+                    "node_modules/express": {"version": "4.18.2"},
+                    "node_modules/lodash": {"version": "4.17.21"},
+                    "node_modules/express/node_modules/lodash": {"version": "3.10.1"},
+                    "node_modules/shared": {"resolved": "packages/shared", "link": true}
+                }
+            }"#;
+            let result = LockfileParser::parse(LockfileFormat::PackageLock, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("express", "4.18.2", true),
+                    ("lodash", "3.10.1", false),
+                    ("lodash", "4.17.21", false),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_package_lock_v1() {
+            let content = r#"{
+                "lockfileVersion": 1,
+                "dependencies": {
+                    "express": {
+                        "version": "4.18.2",
+                        "dependencies": {"lodash": {"version": "3.10.1"}}
+                    }
+                }
+            }"#;
+            let result = LockfileParser::parse(LockfileFormat::PackageLock, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![("express", "4.18.2", false), ("lodash", "3.10.1", false)]
+            );
+        }
+
+        #[test]
+        fn test_yarn_lock_v1() {
+            let content = r#"# THIS IS AN AUTOGENERATED FILE. DO NOT EDIT THIS FILE DIRECTLY.
+# yarn lockfile v1
+
+
+"@babel/core@^7.0.0", "@babel/core@^7.1.0":
+  version "7.24.5"
+  resolved "https://registry.yarnpkg.com/@babel/core/-/core-7.24.5.tgz"
+  dependencies:
+    version "^1.0.0"
+
+lodash@^4.17.21:
+  version "4.17.21"
+"#;
+            let result = LockfileParser::parse(LockfileFormat::YarnLock, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("@babel/core", "7.24.5", false),
+                    ("lodash", "4.17.21", false)
+                ]
+            );
+        }
+
+        #[test]
+        fn test_yarn_lock_berry() {
+            let content = r#"__metadata:
+  version: 8
+  cacheKey: 10c0
+
+"app@workspace:.":
+  version: 0.0.0-use.local
+  resolution: "app@workspace:."
+
+"lodash@npm:^4.17.0, lodash@npm:^4.17.21":
+  version: 4.17.21
+  resolution: "lodash@npm:4.17.21"
+"#;
+            let result = LockfileParser::parse(LockfileFormat::YarnLock, content).unwrap();
+
+            assert_eq!(summary(&result), vec![("lodash", "4.17.21", false)]);
+        }
+
+        #[test]
+        fn test_pnpm_lock_v9() {
+            let content = r#"lockfileVersion: '9.0'
+importers:
+  .:
+    dependencies:
+      react-dom:
+        specifier: ^18.2.0
+        version: 18.2.0(react@18.2.0)
+packages:
+  react-dom@18.2.0:
+    resolution: {integrity: sha512-x}
+  react@18.2.0:
+    resolution: {integrity: sha512-y}
+  '@types/node@20.12.7':
+    resolution: {integrity: sha512-z}
+"#;
+            let result = LockfileParser::parse(LockfileFormat::PnpmLock, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("@types/node", "20.12.7", false),
+                    ("react", "18.2.0", false),
+                    ("react-dom", "18.2.0", true),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_pnpm_lock_v5() {
+            let content = r#"lockfileVersion: 5.4
+dependencies:
+  lodash: 3.10.1
+packages:
+  /lodash/3.10.1:
+    resolution: {integrity: sha512-x}
+  /@babel/core/7.2.0_react@18.2.0:
+    resolution: {integrity: sha512-y}
+"#;
+            let result = LockfileParser::parse(LockfileFormat::PnpmLock, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![("@babel/core", "7.2.0", false), ("lodash", "3.10.1", true)]
+            );
+        }
+
+        #[test]
+        fn test_cargo_lock() {
+            let content = r#"version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "rand 0.8.5",
+ "serde",
+]
+
+[[package]]
+name = "rand"
+version = "0.7.3"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "rand"
+version = "0.8.5"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+
+[[package]]
+name = "serde"
+version = "1.0.200"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#;
+            let result = LockfileParser::parse(LockfileFormat::CargoLock, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("rand", "0.7.3", false),
+                    ("rand", "0.8.5", true),
+                    ("serde", "1.0.200", true),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_go_sum() {
+            let content = "github.com/gin-gonic/gin v1.9.1 h1:abc=\ngithub.com/gin-gonic/gin v1.9.1/go.mod h1:def=\ngithub.com/old/dep v0.1.0/go.mod h1:ghi=\n";
+            let result = LockfileParser::parse(LockfileFormat::GoSum, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![("github.com/gin-gonic/gin", "v1.9.1", false)]
+            );
+        }
+
+        #[test]
+        fn test_poetry_lock() {
+            let content = r#"[[package]]
+name = "requests"
+version = "2.31.0"
+optional = false
+
+[metadata]
+lock-version = "2.0"
+"#;
+            let result = LockfileParser::parse(LockfileFormat::PoetryLock, content).unwrap();
+
+            assert_eq!(summary(&result), vec![("requests", "2.31.0", false)]);
+        }
+
+        #[test]
+        fn test_gemfile_lock() {
+            let content = r#"GEM
+  remote: https://rubygems.org/
+  specs:
+    actionpack (7.1.3)
+      rack (>= 2.2.4)
+    nokogiri (1.16.4-x86_64-linux)
+    rack (3.0.10)
+
+PLATFORMS
+  x86_64-linux
+
+DEPENDENCIES
+  actionpack (~> 7.1)
+  nokogiri!
+
+BUNDLED WITH
+   2.5.9
+"#;
+            let result = LockfileParser::parse(LockfileFormat::GemfileLock, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("actionpack", "7.1.3", true),
+                    ("nokogiri", "1.16.4-x86_64-linux", true),
+                    ("rack", "3.0.10", false),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_composer_lock() {
+            let content = r#"{
+                "packages": [{"name": "monolog/monolog", "version": "3.6.0"}],
+                "packages-dev": [{"name": "phpunit/phpunit", "version": "10.5.20"}]
+            }"#;
+            let result = LockfileParser::parse(LockfileFormat::ComposerLock, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("monolog/monolog", "3.6.0", false),
+                    ("phpunit/phpunit", "10.5.20", false),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_invalid_lockfile() {
+            let result = LockfileParser::parse(LockfileFormat::CargoLock, "[[package");
+
+            assert!(matches!(result, Err(TechScanError::ValidationError(_))));
+        }
+    }
+}
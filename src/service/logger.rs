@@ -0,0 +1,60 @@
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Prefix of the log targets of this crate. Records of dependencies are dropped.
+const TARGET_PREFIX: &str = "techscan";
+
+static LOGGER: Logger = Logger;
+
+/// Writes progress and diagnostics to stderr, so that stdout only carries the report.
+pub struct Logger;
+
+impl Logger {
+    /// Installs the logger. Only the first call has an effect.
+    pub fn init(level: LevelFilter) {
+        if log::set_logger(&LOGGER).is_ok() {
+            log::set_max_level(level);
+        }
+    }
+
+    /// Errors only with `--quiet`, debug output with `--verbose`, and progress and
+    /// warnings otherwise.
+    pub fn level(quiet: bool, verbose: bool) -> LevelFilter {
+        if quiet {
+            LevelFilter::Error
+        } else if verbose {
+            LevelFilter::Debug
+        } else {
+            LevelFilter::Info
+        }
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level() && metadata.target().starts_with(TARGET_PREFIX)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("{}", record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod level {
+        use super::*;
+
+        #[test]
+        fn test_levels() {
+            assert_eq!(Logger::level(false, false), LevelFilter::Info);
+            assert_eq!(Logger::level(true, false), LevelFilter::Error);
+            assert_eq!(Logger::level(false, true), LevelFilter::Debug);
+        }
+    }
+}
@@ -0,0 +1,800 @@
+use crate::entity::{Dependency, DependencyScope, ManifestFormat, Result, TechScanError};
+use regex::Regex;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use toml::{Table as TomlTable, Value as TomlValue};
+use yaml_rust2::{Yaml, YamlLoader};
+
+const GRADLE_CONFIGURATIONS: &str = "implementation|api|compileOnly|runtimeOnly|testImplementation|testRuntimeOnly|testCompileOnly|androidTestImplementation|debugImplementation|annotationProcessor|kapt|ksp|classpath|developmentOnly|compile|testCompile";
+
+fn pep508_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"^([A-Za-z0-9][A-Za-z0-9._-]*)\s*(?:\[[^\]]*\])?\s*(.*)$")
+            .expect("PEP 508 regex must be valid")
+    })
+}
+
+fn gem_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r#"^\s*gem\s*\(?\s*["']([^"']+)["']((?:\s*,\s*["'][^"']*["'])*)(.*)$"#)
+            .expect("gem regex must be valid")
+    })
+}
+
+fn quoted_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| Regex::new(r#"["']([^"']*)["']"#).expect("quoted regex must be valid"))
+}
+
+fn gradle_regexes() -> &'static [Regex; 2] {
+    static REGEXES: OnceLock<[Regex; 2]> = OnceLock::new();
+    REGEXES.get_or_init(|| {
+        [
+            // implementation("group:name:version"), including platform(...) wrappers.
+            Regex::new(&format!(
+                r#"(?m)^\s*({})\s*\(?\s*(?:(?:enforcedPlatform|platform)\s*\(\s*)?["']([^"':\s]+):([^"':\s]+)(?::([^"'\s]+))?["']"#,
+                GRADLE_CONFIGURATIONS
+            ))
+            .expect("gradle regex must be valid"),
+            // implementation group: 'group', name: 'name', version: 'version'
+            Regex::new(&format!(
+                r#"(?m)^\s*({})\s*\(?\s*group\s*[:=]\s*["']([^"']+)["']\s*,\s*name\s*[:=]\s*["']([^"']+)["'](?:\s*,\s*version\s*[:=]\s*["']([^"']+)["'])?"#,
+                GRADLE_CONFIGURATIONS
+            ))
+            .expect("gradle regex must be valid"),
+        ]
+    })
+}
+
+fn mix_dep_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r#"\{\s*:(\w+)\s*(?:,\s*"([^"]*)")?\s*((?:,[^{}]*)?)\}"#)
+            .expect("mix dependency regex must be valid")
+    })
+}
+
+pub struct ManifestParser;
+
+impl ManifestParser {
+    /// Parses the direct dependencies declared in a manifest. Version constraints are kept
+    /// as written; nothing is resolved against a registry.
+    pub fn parse(format: ManifestFormat, content: &str) -> Result<Vec<Dependency>> {
+        match format {
+            ManifestFormat::PackageJson => Self::parse_package_json(content),
+            ManifestFormat::CargoToml => Self::parse_cargo_toml(content),
+            ManifestFormat::GoMod => Ok(Self::parse_go_mod(content)),
+            ManifestFormat::Requirements => Ok(Self::parse_requirements(content)),
+            ManifestFormat::Pyproject => Self::parse_pyproject(content),
+            ManifestFormat::Gemfile => Ok(Self::parse_gemfile(content)),
+            ManifestFormat::ComposerJson => Self::parse_composer_json(content),
+            ManifestFormat::PomXml => Self::parse_pom_xml(content),
+            ManifestFormat::Gradle => Ok(Self::parse_gradle(content)),
+            ManifestFormat::Pubspec => Self::parse_pubspec(content),
+            ManifestFormat::MixExs => Ok(Self::parse_mix_exs(content)),
+        }
+    }
+
+    fn parse_package_json(content: &str) -> Result<Vec<Dependency>> {
+        let value: JsonValue =
+            serde_json::from_str(content).map_err(|e| Self::invalid_manifest("package.json", e))?;
+
+        Ok(Self::json_dependencies(
+            &value,
+            &[
+                ("dependencies", DependencyScope::Runtime),
+                ("devDependencies", DependencyScope::Development),
+                ("peerDependencies", DependencyScope::Peer),
+                ("optionalDependencies", DependencyScope::Optional),
+            ],
+        ))
+    }
+
+    fn parse_composer_json(content: &str) -> Result<Vec<Dependency>> {
+        let value: JsonValue = serde_json::from_str(content)
+            .map_err(|e| Self::invalid_manifest("composer.json", e))?;
+
+        // Platform requirements such as `php` and `ext-json` have no vendor prefix.
+        Ok(Self::json_dependencies(
+            &value,
+            &[
+                ("require", DependencyScope::Runtime),
+                ("require-dev", DependencyScope::Development),
+            ],
+        )
+        .into_iter()
+        .filter(|dependency| dependency.name.contains('/'))
+        .collect())
+    }
+
+    fn json_dependencies(
+        value: &JsonValue,
+        sections: &[(&str, DependencyScope)],
+    ) -> Vec<Dependency> {
+        let mut dependencies = Vec::new();
+        for (section, scope) in sections {
+            let Some(entries) = value.get(section).and_then(JsonValue::as_object) else {
+                continue;
+            };
+            for (name, version) in entries {
+                dependencies.push(Dependency::new(name, version.as_str(), *scope));
+            }
+        }
+        dependencies
+    }
+
+    fn parse_cargo_toml(content: &str) -> Result<Vec<Dependency>> {
+        let table: TomlTable = content
+            .parse()
+            .map_err(|e| Self::invalid_manifest("Cargo.toml", e))?;
+
+        let mut dependencies = Self::cargo_dependencies(&table);
+        if let Some(targets) = table.get("target").and_then(TomlValue::as_table) {
+            for target in targets.values().filter_map(TomlValue::as_table) {
+                dependencies.extend(Self::cargo_dependencies(target));
+            }
+        }
+        if let Some(workspace) = table.get("workspace").and_then(TomlValue::as_table) {
+            dependencies.extend(Self::toml_dependencies(
+                workspace,
+                "dependencies",
+                DependencyScope::Runtime,
+            ));
+        }
+
+        Ok(dependencies)
+    }
+
+    fn cargo_dependencies(table: &TomlTable) -> Vec<Dependency> {
+        [
+            ("dependencies", DependencyScope::Runtime),
+            ("dev-dependencies", DependencyScope::Development),
+            ("build-dependencies", DependencyScope::Build),
+        ]
+        .into_iter()
+        .flat_map(|(section, scope)| Self::toml_dependencies(table, section, scope))
+        .collect()
+    }
+
+    /// Reads a `name = "version"` or `name = { version = "..." }` table, as used by Cargo
+    /// and Poetry.
+    fn toml_dependencies(
+        table: &TomlTable,
+        section: &str,
+        scope: DependencyScope,
+    ) -> Vec<Dependency> {
+        let Some(entries) = table.get(section).and_then(TomlValue::as_table) else {
+            return Vec::new();
+        };
+
+        entries
+            .iter()
+            .map(|(name, spec)| {
+                let version = match spec {
+                    TomlValue::String(version) => Some(version.as_str()),
+                    TomlValue::Table(spec) => spec.get("version").and_then(TomlValue::as_str),
+                    _ => None,
+                };
+                Dependency::new(name, version, scope)
+            })
+            .collect()
+    }
+
+    fn parse_go_mod(content: &str) -> Vec<Dependency> {
+        let mut dependencies = Vec::new();
+        let mut in_block = false;
+
+        for line in content.lines() {
+            let line = line.trim();
+            if in_block {
+                if line.starts_with(')') {
+                    in_block = false;
+                } else {
+                    dependencies.extend(Self::go_requirement(line));
+                }
+                continue;
+            }
+
+            let Some(rest) = line.strip_prefix("require") else {
+                continue;
+            };
+            if rest.trim_start().starts_with('(') {
+                in_block = true;
+            } else if rest.starts_with(char::is_whitespace) {
+                dependencies.extend(Self::go_requirement(rest.trim()));
+            }
+        }
+
+        dependencies
+    }
+
+    /// Parses a `module version` requirement. Requirements marked `// indirect` are
+    /// pulled in by other modules and are skipped.
+    fn go_requirement(line: &str) -> Option<Dependency> {
+        let (spec, comment) = line.split_once("//").unwrap_or((line, ""));
+        if comment.trim() == "indirect" {
+            return None;
+        }
+
+        let mut parts = spec.split_whitespace();
+        let name = parts.next()?;
+        Some(Dependency::new(
+            name,
+            parts.next(),
+            DependencyScope::Runtime,
+        ))
+    }
+
+    fn parse_requirements(content: &str) -> Vec<Dependency> {
+        content
+            .lines()
+            .filter_map(|line| {
+                let line = line.split_once('#').map_or(line, |(line, _)| line).trim();
+                // Options such as `-r other.txt` and `--index-url` are not packages.
+                if line.is_empty() || line.starts_with('-') {
+                    return None;
+                }
+                Self::pep508_dependency(line, DependencyScope::Runtime)
+            })
+            .collect()
+    }
+
+    /// Parses a PEP 508 requirement such as `requests[socks]>=2.31; python_version>"3.8"`.
+    fn pep508_dependency(requirement: &str, scope: DependencyScope) -> Option<Dependency> {
+        let captures = pep508_regex().captures(requirement.trim())?;
+        let spec = captures[2].split(';').next().unwrap_or("").trim();
+        // Direct references (`name @ https://...`) have no version constraint.
+        let version = if spec.starts_with('@') {
+            None
+        } else {
+            Some(spec.trim_start_matches('(').trim_end_matches(')'))
+        };
+
+        Some(Dependency::new(&captures[1], version, scope))
+    }
+
+    fn parse_pyproject(content: &str) -> Result<Vec<Dependency>> {
+        let table: TomlTable = content
+            .parse()
+            .map_err(|e| Self::invalid_manifest("pyproject.toml", e))?;
+        let mut dependencies = Vec::new();
+
+        let pep508_array = |value: Option<&TomlValue>, scope: DependencyScope| {
+            value
+                .and_then(TomlValue::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(TomlValue::as_str)
+                .filter_map(move |requirement| Self::pep508_dependency(requirement, scope))
+                .collect::<Vec<_>>()
+        };
+
+        if let Some(project) = table.get("project").and_then(TomlValue::as_table) {
+            dependencies.extend(pep508_array(
+                project.get("dependencies"),
+                DependencyScope::Runtime,
+            ));
+            if let Some(extras) = project
+                .get("optional-dependencies")
+                .and_then(TomlValue::as_table)
+            {
+                for extra in extras.values() {
+                    dependencies.extend(pep508_array(Some(extra), DependencyScope::Optional));
+                }
+            }
+        }
+
+        if let Some(groups) = table.get("dependency-groups").and_then(TomlValue::as_table) {
+            for group in groups.values() {
+                dependencies.extend(pep508_array(Some(group), DependencyScope::Development));
+            }
+        }
+
+        let poetry = table
+            .get("tool")
+            .and_then(|tool| tool.get("poetry"))
+            .and_then(TomlValue::as_table);
+        if let Some(poetry) = poetry {
+            // `python` declares the supported interpreter, not a package.
+            dependencies.extend(
+                Self::toml_dependencies(poetry, "dependencies", DependencyScope::Runtime)
+                    .into_iter()
+                    .filter(|dependency| dependency.name != "python"),
+            );
+            dependencies.extend(Self::toml_dependencies(
+                poetry,
+                "dev-dependencies",
+                DependencyScope::Development,
+            ));
+            if let Some(groups) = poetry.get("group").and_then(TomlValue::as_table) {
+                for group in groups.values().filter_map(TomlValue::as_table) {
+                    dependencies.extend(Self::toml_dependencies(
+                        group,
+                        "dependencies",
+                        DependencyScope::Development,
+                    ));
+                }
+            }
+        }
+
+        Ok(dependencies)
+    }
+
+    fn parse_gemfile(content: &str) -> Vec<Dependency> {
+        let mut dependencies = Vec::new();
+        // One entry per open `do`/`if` block, true for `group :development`/`:test` blocks.
+        let mut blocks: Vec<bool> = Vec::new();
+
+        for line in content.lines() {
+            let code = line.split_once('#').map_or(line, |(code, _)| code).trim();
+
+            if let Some(captures) = gem_regex().captures(code) {
+                let version = quoted_regex()
+                    .captures_iter(&captures[2])
+                    .map(|quoted| quoted[1].to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let development = blocks.iter().any(|&dev| dev)
+                    || (captures[3].contains("group") && Self::is_dev_group(&captures[3]));
+                let scope = if development {
+                    DependencyScope::Development
+                } else {
+                    DependencyScope::Runtime
+                };
+                dependencies.push(Dependency::new(&captures[1], Some(&version), scope));
+            } else if code.ends_with(" do") || code.contains(" do |") {
+                blocks.push(code.starts_with("group") && Self::is_dev_group(code));
+            } else if code.starts_with("if ") || code.starts_with("unless ") {
+                blocks.push(false);
+            } else if code == "end" {
+                blocks.pop();
+            }
+        }
+
+        dependencies
+    }
+
+    fn is_dev_group(text: &str) -> bool {
+        text.contains(":development") || text.contains(":test")
+    }
+
+    fn parse_pom_xml(content: &str) -> Result<Vec<Dependency>> {
+        let document = roxmltree::Document::parse(content)
+            .map_err(|e| Self::invalid_manifest("pom.xml", e))?;
+        let project = document.root_element();
+
+        let mut properties: HashMap<String, String> = HashMap::new();
+        if let Some(version) = Self::xml_child_text(project, "version") {
+            properties.insert("project.version".to_string(), version);
+        }
+        if let Some(node) = Self::xml_child(project, "properties") {
+            for property in node.children().filter(|node| node.is_element()) {
+                if let Some(text) = property.text() {
+                    properties.insert(
+                        property.tag_name().name().to_string(),
+                        text.trim().to_string(),
+                    );
+                }
+            }
+        }
+
+        let Some(dependencies) = Self::xml_child(project, "dependencies") else {
+            return Ok(Vec::new());
+        };
+
+        Ok(dependencies
+            .children()
+            .filter(|node| node.is_element() && node.tag_name().name() == "dependency")
+            .filter_map(|node| {
+                let group = Self::xml_child_text(node, "groupId")?;
+                let artifact = Self::xml_child_text(node, "artifactId")?;
+                let version = Self::xml_child_text(node, "version").map(|version| {
+                    version
+                        .strip_prefix("${")
+                        .and_then(|key| key.strip_suffix('}'))
+                        .and_then(|key| properties.get(key).cloned())
+                        .unwrap_or(version)
+                });
+                let scope = match Self::xml_child_text(node, "scope").as_deref() {
+                    Some("test") => DependencyScope::Development,
+                    Some("provided") | Some("system") => DependencyScope::Build,
+                    _ => DependencyScope::Runtime,
+                };
+                Some(Dependency::new(
+                    &format!("{}:{}", group, artifact),
+                    version.as_deref(),
+                    scope,
+                ))
+            })
+            .collect())
+    }
+
+    fn xml_child<'a, 'input>(
+        node: roxmltree::Node<'a, 'input>,
+        name: &str,
+    ) -> Option<roxmltree::Node<'a, 'input>> {
+        node.children()
+            .find(|child| child.is_element() && child.tag_name().name() == name)
+    }
+
+    fn xml_child_text(node: roxmltree::Node<'_, '_>, name: &str) -> Option<String> {
+        Self::xml_child(node, name)
+            .and_then(|child| child.text())
+            .map(|text| text.trim().to_string())
+    }
+
+    fn parse_gradle(content: &str) -> Vec<Dependency> {
+        let mut matches: Vec<(usize, Dependency)> = gradle_regexes()
+            .iter()
+            .flat_map(|regex| regex.captures_iter(content))
+            .map(|captures| {
+                let configuration = &captures[1];
+                let scope = if configuration.starts_with("test")
+                    || configuration.starts_with("androidTest")
+                    || configuration.starts_with("debug")
+                {
+                    DependencyScope::Development
+                } else if matches!(
+                    configuration,
+                    "annotationProcessor" | "kapt" | "ksp" | "classpath" | "compileOnly"
+                ) {
+                    DependencyScope::Build
+                } else {
+                    DependencyScope::Runtime
+                };
+                let name = format!("{}:{}", &captures[2], &captures[3]);
+                let version = captures.get(4).map(|version| version.as_str());
+                (
+                    captures.get(0).map_or(0, |m| m.start()),
+                    Dependency::new(&name, version, scope),
+                )
+            })
+            .collect();
+
+        matches.sort_by_key(|(position, _)| *position);
+        matches
+            .into_iter()
+            .map(|(_, dependency)| dependency)
+            .collect()
+    }
+
+    fn parse_pubspec(content: &str) -> Result<Vec<Dependency>> {
+        let documents = YamlLoader::load_from_str(content)
+            .map_err(|e| Self::invalid_manifest("pubspec.yaml", e))?;
+        let Some(document) = documents.first() else {
+            return Ok(Vec::new());
+        };
+
+        let mut dependencies = Vec::new();
+        for (section, scope) in [
+            ("dependencies", DependencyScope::Runtime),
+            ("dev_dependencies", DependencyScope::Development),
+        ] {
+            let Some(entries) = document[section].as_hash() else {
+                continue;
+            };
+            for (name, spec) in entries {
+                let Some(name) = name.as_str() else {
+                    continue;
+                };
+                let version = match spec {
+                    Yaml::String(version) => Some(version.as_str()),
+                    Yaml::Hash(_) => spec["version"].as_str(),
+                    _ => None,
+                };
+                dependencies.push(Dependency::new(name, version, scope));
+            }
+        }
+
+        Ok(dependencies)
+    }
+
+    fn parse_mix_exs(content: &str) -> Vec<Dependency> {
+        let Some(start) = content
+            .find("defp deps")
+            .or_else(|| content.find("def deps"))
+        else {
+            return Vec::new();
+        };
+        let section = &content[start..];
+        let end = section[1..]
+            .find("\n  def")
+            .map_or(section.len(), |end| end + 1);
+
+        mix_dep_regex()
+            .captures_iter(&section[..end])
+            .map(|captures| {
+                let options = &captures[3];
+                let scope = if options.contains("only:") && !options.contains(":prod") {
+                    DependencyScope::Development
+                } else if options.contains("runtime: false") {
+                    DependencyScope::Build
+                } else {
+                    DependencyScope::Runtime
+                };
+                Dependency::new(
+                    &captures[1],
+                    captures.get(2).map(|version| version.as_str()),
+                    scope,
+                )
+            })
+            .collect()
+    }
+
+    fn invalid_manifest(name: &str, error: impl std::fmt::Display) -> TechScanError {
+        TechScanError::ValidationError(format!("Invalid {}: {}", name, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(dependencies: &[Dependency]) -> Vec<(&str, Option<&str>, DependencyScope)> {
+        dependencies
+            .iter()
+            .map(|dependency| {
+                (
+                    dependency.name.as_str(),
+                    dependency.version.as_deref(),
+                    dependency.scope,
+                )
+            })
+            .collect()
+    }
+
+    mod parse {
+        use super::*;
+        use DependencyScope::*;
+
+        #[test]
+        fn test_package_json() {
+            let content = r#"{
+                "name": "app",
+                "dependencies": {"react": "^18.2.0"},
+                "devDependencies": {"typescript": "~5.4.0"},
+                "peerDependencies": {"react-dom": ">=18"}
+            }"#;
+            let result = ManifestParser::parse(ManifestFormat::PackageJson, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("react", Some("^18.2.0"), Runtime),
+                    ("typescript", Some("~5.4.0"), Development),
+                    ("react-dom", Some(">=18"), Peer),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_invalid_package_json() {
+            let result = ManifestParser::parse(ManifestFormat::PackageJson, "{");
+
+            assert!(matches!(result, Err(TechScanError::ValidationError(_))));
+        }
+
+        #[test]
+        fn test_cargo_toml() {
+            let content = r#"
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+regex = "1.11"
+local = { path = "../local" }
+
+[dev-dependencies]
+tempfile = "3"
+
+[build-dependencies]
+cc = "1.0"
+
+[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+"#;
+            let result = ManifestParser::parse(ManifestFormat::CargoToml, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("local", None, Runtime),
+                    ("regex", Some("1.11"), Runtime),
+                    ("serde", Some("1.0"), Runtime),
+                    ("tempfile", Some("3"), Development),
+                    ("cc", Some("1.0"), Build),
+                    ("libc", Some("0.2"), Runtime),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_go_mod() {
+            let content = "module example.com/app\n\ngo 1.22\n\nrequire github.com/spf13/cobra v1.8.0\n\nrequire (\n\tgithub.com/stretchr/testify v1.9.0\n\tgolang.org/x/sys v0.20.0 // indirect\n)\n";
+            let result = ManifestParser::parse(ManifestFormat::GoMod, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("github.com/spf13/cobra", Some("v1.8.0"), Runtime),
+                    ("github.com/stretchr/testify", Some("v1.9.0"), Runtime),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_requirements() {
+            let content = "# comment\n-r base.txt\nrequests[socks]>=2.31,<3 ; python_version > \"3.8\"\nflask==3.0.0  # web\nnumpy\nmypkg @ https://example.com/mypkg.whl\n";
+            let result = ManifestParser::parse(ManifestFormat::Requirements, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("requests", Some(">=2.31,<3"), Runtime),
+                    ("flask", Some("==3.0.0"), Runtime),
+                    ("numpy", None, Runtime),
+                    ("mypkg", None, Runtime),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_pyproject() {
+            let content = r#"
+[project]
+dependencies = ["httpx>=0.27", "pydantic"]
+
+[project.optional-dependencies]
+cli = ["rich>=13"]
+
+[dependency-groups]
+dev = ["pytest>=8"]
+
+[tool.poetry.dependencies]
+python = "^3.11"
+django = "^5.0"
+
+[tool.poetry.group.dev.dependencies]
+black = { version = "^24.0" }
+"#;
+            let result = ManifestParser::parse(ManifestFormat::Pyproject, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("httpx", Some(">=0.27"), Runtime),
+                    ("pydantic", None, Runtime),
+                    ("rich", Some(">=13"), Optional),
+                    ("pytest", Some(">=8"), Development),
+                    ("django", Some("^5.0"), Runtime),
+                    ("black", Some("^24.0"), Development),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_gemfile() {
+            let content = "source 'https://rubygems.org'\n\ngem 'rails', '~> 7.1', '>= 7.1.2'\ngem \"puma\"\ngem 'rspec-rails', group: :test\n\ngroup :development, :test do\n  gem 'pry'\nend\n\ngroup :production do\n  gem 'pg', '~> 1.5'\nend\n";
+            let result = ManifestParser::parse(ManifestFormat::Gemfile, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("rails", Some("~> 7.1, >= 7.1.2"), Runtime),
+                    ("puma", None, Runtime),
+                    ("rspec-rails", None, Development),
+                    ("pry", None, Development),
+                    ("pg", Some("~> 1.5"), Runtime),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_composer_json() {
+            let content = r#"{
+                "require": {"php": ">=8.1", "ext-json": "*", "laravel/framework": "^11.0"},
+                "require-dev": {"phpunit/phpunit": "^10.5"}
+            }"#;
+            let result = ManifestParser::parse(ManifestFormat::ComposerJson, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("laravel/framework", Some("^11.0"), Runtime),
+                    ("phpunit/phpunit", Some("^10.5"), Development),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_pom_xml() {
+            let content = r#"<?xml version="1.0"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <version>1.0.0</version>
+  <properties>
+    <spring.version>6.1.5</spring.version>
+  </properties>
+  <dependencyManagement>
+    <dependencies>
+      <dependency><groupId>managed</groupId><artifactId>bom</artifactId></dependency>
+    </dependencies>
+  </dependencyManagement>
+  <dependencies>
+    <dependency>
+      <groupId>org.springframework</groupId>
+      <artifactId>spring-core</artifactId>
+      <version>${spring.version}</version>
+    </dependency>
+    <dependency>
+      <groupId>junit</groupId>
+      <artifactId>junit</artifactId>
+      <version>4.13.2</version>
+      <scope>test</scope>
+    </dependency>
+  </dependencies>
+</project>"#;
+            let result = ManifestParser::parse(ManifestFormat::PomXml, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("org.springframework:spring-core", Some("6.1.5"), Runtime),
+                    ("junit:junit", Some("4.13.2"), Development),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_gradle() {
+            let content = "dependencies {\n    implementation(platform(\"org.springframework.boot:spring-boot-dependencies:3.2.0\"))\n    implementation 'com.google.guava:guava:33.0.0-jre'\n    testImplementation(\"org.junit.jupiter:junit-jupiter\")\n    annotationProcessor group: 'org.projectlombok', name: 'lombok', version: '1.18.30'\n}\n";
+            let result = ManifestParser::parse(ManifestFormat::Gradle, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    (
+                        "org.springframework.boot:spring-boot-dependencies",
+                        Some("3.2.0"),
+                        Runtime
+                    ),
+                    ("com.google.guava:guava", Some("33.0.0-jre"), Runtime),
+                    ("org.junit.jupiter:junit-jupiter", None, Development),
+                    ("org.projectlombok:lombok", Some("1.18.30"), Build),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_pubspec() {
+            let content = "name: app\ndependencies:\n  flutter:\n    sdk: flutter\n  http: ^1.2.0\n  provider:\n    version: ^6.1.0\ndev_dependencies:\n  flutter_lints: ^3.0.0\n";
+            let result = ManifestParser::parse(ManifestFormat::Pubspec, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("flutter", None, Runtime),
+                    ("http", Some("^1.2.0"), Runtime),
+                    ("provider", Some("^6.1.0"), Runtime),
+                    ("flutter_lints", Some("^3.0.0"), Development),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_mix_exs() {
+            let content = "defmodule App.MixProject do\n  def project do\n    [app: :app, deps: deps()]\n  end\n\n  defp deps do\n    [\n      {:phoenix, \"~> 1.7.10\"},\n      {:credo, \"~> 1.7\", only: [:dev, :test], runtime: false},\n      {:esbuild, \"~> 0.8\", runtime: Mix.env() == :dev},\n      {:local, path: \"../local\"}\n    ]\n  end\nend\n";
+            let result = ManifestParser::parse(ManifestFormat::MixExs, content).unwrap();
+
+            assert_eq!(
+                summary(&result),
+                vec![
+                    ("phoenix", Some("~> 1.7.10"), Runtime),
+                    ("credo", Some("~> 1.7"), Development),
+                    ("esbuild", Some("~> 0.8"), Runtime),
+                    ("local", None, Runtime),
+                ]
+            );
+        }
+    }
+}
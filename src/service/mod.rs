@@ -1,7 +1,65 @@
 pub mod config_builder;
-pub mod language_reporter;
+pub mod content_sniffer;
+pub mod dependency_json_reporter;
+pub mod dependency_sbom_reporter;
+pub mod dependency_scanner;
+pub mod dependency_table_reporter;
+pub mod file_classifier;
+pub mod file_walker;
+pub mod git_attributes;
+pub mod heuristic_engine;
+pub mod language_csv_reporter;
+pub mod language_json_reporter;
+pub mod language_markdown_reporter;
 pub mod language_scanner;
+pub mod language_table_reporter;
+pub mod license_json_reporter;
+pub mod license_matcher;
+pub mod license_scanner;
+pub mod license_table_reporter;
+pub mod line_counter;
+pub mod lockfile_parser;
+pub mod logger;
+pub mod manifest_parser;
+pub mod reporter;
+pub mod reporter_registry;
+pub mod runtime_detector;
+pub mod sbom_builder;
+pub mod sfc_parser;
+pub mod stack_detector;
+pub mod stack_json_reporter;
+pub mod stack_scanner;
+pub mod stack_table_reporter;
 
 pub use config_builder::ConfigBuilder;
-pub use language_reporter::LanguageReporter;
+pub use content_sniffer::ContentSniffer;
+pub use dependency_json_reporter::DependencyJsonReporter;
+pub use dependency_sbom_reporter::DependencySbomReporter;
+pub use dependency_scanner::DependencyScanner;
+pub use dependency_table_reporter::DependencyTableReporter;
+pub use file_classifier::FileClassifier;
+pub use file_walker::FileWalker;
+pub use git_attributes::GitAttributes;
+pub use heuristic_engine::HeuristicEngine;
+pub use language_csv_reporter::LanguageCsvReporter;
+pub use language_json_reporter::LanguageJsonReporter;
+pub use language_markdown_reporter::LanguageMarkdownReporter;
 pub use language_scanner::LanguageScanner;
+pub use language_table_reporter::LanguageTableReporter;
+pub use license_json_reporter::LicenseJsonReporter;
+pub use license_matcher::LicenseMatcher;
+pub use license_scanner::LicenseScanner;
+pub use license_table_reporter::LicenseTableReporter;
+pub use line_counter::LineCounter;
+pub use lockfile_parser::LockfileParser;
+pub use logger::Logger;
+pub use manifest_parser::ManifestParser;
+pub use reporter::{BuiltinReporters, Reporter};
+pub use reporter_registry::ReporterRegistry;
+pub use runtime_detector::RuntimeDetector;
+pub use sbom_builder::SbomBuilder;
+pub use sfc_parser::SfcParser;
+pub use stack_detector::StackDetector;
+pub use stack_json_reporter::StackJsonReporter;
+pub use stack_scanner::StackScanner;
+pub use stack_table_reporter::StackTableReporter;
@@ -0,0 +1,20 @@
+use crate::entity::LanguageReport;
+use std::io::{self, Write};
+
+/// A format a report, such as a `LanguageReport`, can be rendered in. Register an
+/// implementation with a `ReporterRegistry` for the same report to make it selectable by
+/// `name`.
+pub trait Reporter<R = LanguageReport> {
+    /// Name of the format, as given to `--reporter`.
+    fn name(&self) -> &str;
+
+    /// Extension, without the dot, of files written in this format.
+    fn file_extension(&self) -> &str;
+
+    fn render(&self, report: &R, writer: &mut dyn Write) -> io::Result<()>;
+}
+
+/// A report with built-in formats, which a new `ReporterRegistry` for it starts with.
+pub trait BuiltinReporters: Sized {
+    fn builtin_reporters() -> Vec<Box<dyn Reporter<Self>>>;
+}
@@ -0,0 +1,208 @@
+use crate::entity::{DependencyReport, LanguageReport, LicenseReport, StackReport};
+use crate::service::{
+    BuiltinReporters, DependencyJsonReporter, DependencySbomReporter, DependencyTableReporter,
+    LanguageCsvReporter, LanguageJsonReporter, LanguageMarkdownReporter, LanguageTableReporter,
+    LicenseJsonReporter, LicenseTableReporter, Reporter, StackJsonReporter, StackTableReporter,
+};
+use std::io::{self, Write};
+
+/// The formats a report can be rendered in, looked up by name.
+pub struct ReporterRegistry<R = LanguageReport> {
+    reporters: Vec<Box<dyn Reporter<R>>>,
+}
+
+impl<R: BuiltinReporters> Default for ReporterRegistry<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: BuiltinReporters> ReporterRegistry<R> {
+    /// Creates a registry with the built-in formats of the report.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for reporter in R::builtin_reporters() {
+            registry.register(reporter);
+        }
+        registry
+    }
+}
+
+impl<R> ReporterRegistry<R> {
+    /// Creates a registry without any format.
+    pub fn empty() -> Self {
+        Self {
+            reporters: Vec::new(),
+        }
+    }
+
+    /// Adds a format. A format registered under the same name is replaced.
+    pub fn register(&mut self, reporter: Box<dyn Reporter<R>>) {
+        match self
+            .reporters
+            .iter_mut()
+            .find(|registered| registered.name() == reporter.name())
+        {
+            Some(registered) => *registered = reporter,
+            None => self.reporters.push(reporter),
+        }
+    }
+
+    pub fn get(&self, format: &str) -> Option<&dyn Reporter<R>> {
+        self.reporters
+            .iter()
+            .find(|reporter| reporter.name() == format)
+            .map(|reporter| reporter.as_ref())
+    }
+
+    /// Names of the registered formats, in registration order.
+    pub fn names(&self) -> Vec<&str> {
+        self.reporters
+            .iter()
+            .map(|reporter| reporter.name())
+            .collect()
+    }
+
+    pub fn validate_format(&self, format: &str) -> io::Result<&dyn Reporter<R>> {
+        self.get(format).ok_or_else(|| {
+            io::Error::other(format!(
+                "Unsupported reporter format: '{}'. Supported formats: {}.",
+                format,
+                self.names().join(", ")
+            ))
+        })
+    }
+
+    pub fn output(&self, report: &R, format: &str, writer: &mut dyn Write) -> io::Result<()> {
+        self.validate_format(format)?.render(report, writer)
+    }
+}
+
+impl BuiltinReporters for LanguageReport {
+    fn builtin_reporters() -> Vec<Box<dyn Reporter<Self>>> {
+        vec![
+            Box::new(LanguageTableReporter::new()),
+            Box::new(LanguageJsonReporter::new()),
+            Box::new(LanguageCsvReporter::csv()),
+            Box::new(LanguageCsvReporter::tsv()),
+            Box::new(LanguageMarkdownReporter::new()),
+        ]
+    }
+}
+
+impl BuiltinReporters for DependencyReport {
+    fn builtin_reporters() -> Vec<Box<dyn Reporter<Self>>> {
+        vec![
+            Box::new(DependencyTableReporter::new()),
+            Box::new(DependencyJsonReporter::new()),
+            Box::new(DependencySbomReporter::cyclonedx()),
+            Box::new(DependencySbomReporter::spdx()),
+        ]
+    }
+}
+
+impl BuiltinReporters for StackReport {
+    fn builtin_reporters() -> Vec<Box<dyn Reporter<Self>>> {
+        vec![
+            Box::new(StackTableReporter::new()),
+            Box::new(StackJsonReporter::new()),
+        ]
+    }
+}
+
+impl BuiltinReporters for LicenseReport {
+    fn builtin_reporters() -> Vec<Box<dyn Reporter<Self>>> {
+        vec![
+            Box::new(LicenseTableReporter::new()),
+            Box::new(LicenseJsonReporter::new()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::Weight;
+
+    struct CountReporter;
+
+    impl Reporter<LanguageReport> for CountReporter {
+        fn name(&self) -> &str {
+            "count"
+        }
+
+        fn file_extension(&self) -> &str {
+            "txt"
+        }
+
+        fn render(&self, report: &LanguageReport, writer: &mut dyn Write) -> io::Result<()> {
+            writeln!(writer, "{}", report.languages.len())
+        }
+    }
+
+    fn create_test_report() -> LanguageReport {
+        LanguageReport {
+            dir: "/test/path".to_string(),
+            total_file_count: 0,
+            weight: Weight::Files,
+            kinds: vec![],
+            languages: vec![],
+            classified_files: vec![],
+        }
+    }
+
+    #[test]
+    fn test_builtin_formats() {
+        let registry = ReporterRegistry::<LanguageReport>::new();
+
+        assert_eq!(
+            registry.names(),
+            vec!["table", "json", "csv", "tsv", "markdown"]
+        );
+        assert_eq!(registry.get("json").unwrap().file_extension(), "json");
+    }
+
+    #[test]
+    fn test_builtin_formats_of_other_reports() {
+        assert_eq!(
+            ReporterRegistry::<DependencyReport>::new().names(),
+            vec!["table", "json", "cyclonedx", "spdx"]
+        );
+        assert_eq!(
+            ReporterRegistry::<StackReport>::new().names(),
+            vec!["table", "json"]
+        );
+        assert_eq!(
+            ReporterRegistry::<LicenseReport>::new().names(),
+            vec!["table", "json"]
+        );
+    }
+
+    #[test]
+    fn test_validate_format_invalid() {
+        let registry = ReporterRegistry::<LanguageReport>::new();
+
+        let error_msg = registry.validate_format("xml").err().unwrap().to_string();
+        assert_eq!(
+            error_msg,
+            "Unsupported reporter format: 'xml'. Supported formats: table, json, csv, tsv, markdown."
+        );
+    }
+
+    #[test]
+    fn test_register_custom_format() {
+        let mut registry = ReporterRegistry::<LanguageReport>::new();
+        registry.register(Box::new(CountReporter));
+
+        let mut output = Vec::new();
+        registry
+            .output(&create_test_report(), "count", &mut output)
+            .expect("Custom format should render");
+
+        assert_eq!(
+            registry.names(),
+            vec!["table", "json", "csv", "tsv", "markdown", "count"]
+        );
+        assert_eq!(String::from_utf8(output).unwrap(), "0\n");
+    }
+}
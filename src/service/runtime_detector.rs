@@ -0,0 +1,346 @@
+use crate::config::RuntimeConfig;
+use crate::entity::{File, FileAttributes, ProjectRuntimes, RuntimeVersion};
+use crate::service::FileClassifier;
+use regex::Regex;
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+use toml::{Table as TomlTable, Value as TomlValue};
+
+/// Files that pin a runtime or toolchain version, besides Dockerfiles.
+const VERSION_FILES: &[&str] = &[
+    ".nvmrc",
+    ".node-version",
+    ".python-version",
+    ".ruby-version",
+    ".tool-versions",
+    "rust-toolchain",
+    "rust-toolchain.toml",
+    "go.mod",
+    "package.json",
+    "Cargo.toml",
+    "global.json",
+];
+
+fn from_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"(?im)^\s*FROM\s+(?:--\S+\s+)*(\S+)").expect("FROM regex must be valid")
+    })
+}
+
+fn go_directive_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"(?m)^go\s+(\S+)\s*$").expect("go directive regex must be valid")
+    })
+}
+
+pub struct RuntimeDetector;
+
+impl RuntimeDetector {
+    /// Collects the runtime versions pinned by the version files among `files`, grouped by
+    /// project root and sorted by root. Vendored and generated files are ignored.
+    pub fn detect(dir: &str, files: &[File]) -> Vec<ProjectRuntimes> {
+        let mut projects: BTreeMap<String, Vec<RuntimeVersion>> = BTreeMap::new();
+
+        for file in files {
+            if !Self::is_version_file(&file.name) {
+                continue;
+            }
+            let relative_path = Path::new(&file.path)
+                .strip_prefix(dir)
+                .ok()
+                .and_then(|path| path.to_str())
+                .unwrap_or(&file.path);
+            if FileClassifier::classify_path(relative_path, &FileAttributes::default()).is_some() {
+                continue;
+            }
+
+            let content = match fs::read_to_string(&file.path) {
+                Ok(content) => content,
+                Err(e) => {
+                    log::warn!("Error reading file {}: {}", file.path, e);
+                    continue;
+                }
+            };
+            let versions = Self::parse(&file.name, &content);
+            if versions.is_empty() {
+                continue;
+            }
+
+            let root = Path::new(&file.path)
+                .parent()
+                .map(|parent| parent.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            projects
+                .entry(root)
+                .or_default()
+                .extend(
+                    versions
+                        .into_iter()
+                        .map(|(runtime, version)| RuntimeVersion {
+                            runtime,
+                            version,
+                            source: file.path.clone(),
+                        }),
+                );
+        }
+
+        projects
+            .into_iter()
+            .map(|(root, mut runtimes)| {
+                runtimes.sort_by(|a, b| {
+                    a.runtime
+                        .cmp(&b.runtime)
+                        .then_with(|| a.source.cmp(&b.source))
+                });
+                ProjectRuntimes { root, runtimes }
+            })
+            .collect()
+    }
+
+    /// Parses the versions pinned by a version file as `(runtime, version)` pairs. Files
+    /// that cannot be parsed pin nothing.
+    pub fn parse(file_name: &str, content: &str) -> Vec<(String, String)> {
+        let pinned = |runtime: &str, version: Option<&str>| {
+            version
+                .map(|version| vec![(runtime.to_string(), version.to_string())])
+                .unwrap_or_default()
+        };
+
+        match file_name {
+            ".nvmrc" | ".node-version" => pinned(
+                "node",
+                Self::first_line(content).map(|version| version.trim_start_matches('v')),
+            ),
+            ".python-version" => pinned("python", Self::first_line(content)),
+            ".ruby-version" => pinned(
+                "ruby",
+                Self::first_line(content).map(|version| version.trim_start_matches("ruby-")),
+            ),
+            ".tool-versions" => Self::parse_tool_versions(content),
+            // rust-toolchain may hold a bare channel or the TOML format.
+            "rust-toolchain" | "rust-toolchain.toml" => match content.parse::<TomlTable>() {
+                Ok(table) => pinned(
+                    "rust",
+                    table
+                        .get("toolchain")
+                        .and_then(|toolchain| toolchain.get("channel"))
+                        .and_then(TomlValue::as_str),
+                ),
+                Err(_) => pinned("rust", Self::first_line(content)),
+            },
+            "go.mod" => pinned(
+                "go",
+                go_directive_regex()
+                    .captures(content)
+                    .and_then(|captures| captures.get(1))
+                    .map(|version| version.as_str()),
+            ),
+            "package.json" => Self::parse_engines(content),
+            "Cargo.toml" => {
+                let Ok(table) = content.parse::<TomlTable>() else {
+                    return Vec::new();
+                };
+                let rust_version = table
+                    .get("package")
+                    .or_else(|| {
+                        table
+                            .get("workspace")
+                            .and_then(|workspace| workspace.get("package"))
+                    })
+                    .and_then(|package| package.get("rust-version"))
+                    .and_then(TomlValue::as_str);
+                pinned("rust", rust_version)
+            }
+            "global.json" => {
+                let Ok(value) = serde_json::from_str::<JsonValue>(content) else {
+                    return Vec::new();
+                };
+                pinned(
+                    "dotnet",
+                    value
+                        .get("sdk")
+                        .and_then(|sdk| sdk.get("version"))
+                        .and_then(JsonValue::as_str),
+                )
+            }
+            _ if Self::is_dockerfile(file_name) => Self::parse_dockerfile(content),
+            _ => Vec::new(),
+        }
+    }
+
+    fn is_version_file(file_name: &str) -> bool {
+        VERSION_FILES.contains(&file_name) || Self::is_dockerfile(file_name)
+    }
+
+    fn is_dockerfile(file_name: &str) -> bool {
+        file_name == "Dockerfile"
+            || file_name == "Containerfile"
+            || file_name.starts_with("Dockerfile.")
+            || file_name.ends_with(".dockerfile")
+    }
+
+    fn first_line(content: &str) -> Option<&str> {
+        content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+    }
+
+    /// Parses asdf/mise `.tool-versions` lines such as `nodejs 18.17.0 16.20.0`. The first
+    /// version listed is the one in use.
+    fn parse_tool_versions(content: &str) -> Vec<(String, String)> {
+        content
+            .lines()
+            .filter_map(|line| {
+                let line = line.split_once('#').map_or(line, |(line, _)| line);
+                let mut parts = line.split_whitespace();
+                let tool = parts.next()?;
+                let version = parts.next()?;
+                Some((
+                    RuntimeConfig::runtime_for_tool(tool).to_string(),
+                    version.to_string(),
+                ))
+            })
+            .collect()
+    }
+
+    fn parse_engines(content: &str) -> Vec<(String, String)> {
+        let Ok(value) = serde_json::from_str::<JsonValue>(content) else {
+            return Vec::new();
+        };
+        let Some(engines) = value.get("engines").and_then(JsonValue::as_object) else {
+            return Vec::new();
+        };
+
+        engines
+            .iter()
+            .filter_map(|(engine, version)| Some((engine.clone(), version.as_str()?.to_string())))
+            .collect()
+    }
+
+    /// Reads the base image tags of `FROM` instructions. Build stages, images given by a
+    /// build argument and images that do not provide a known runtime are skipped.
+    fn parse_dockerfile(content: &str) -> Vec<(String, String)> {
+        from_regex()
+            .captures_iter(content)
+            .filter_map(|captures| {
+                let reference = captures.get(1)?.as_str();
+                if reference.contains('$') {
+                    return None;
+                }
+                let reference = reference.split('@').next()?.to_lowercase();
+                // A colon followed by a slash belongs to a registry port, not a tag.
+                let (image, tag) = match reference.rsplit_once(':') {
+                    Some((image, tag)) if !tag.contains('/') => (image, tag),
+                    _ => (reference.as_str(), "latest"),
+                };
+                let runtime = RuntimeConfig::runtime_for_image(image)?;
+                Some((runtime.to_string(), tag.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(versions: &[(&str, &str)]) -> Vec<(String, String)> {
+        versions
+            .iter()
+            .map(|(runtime, version)| (runtime.to_string(), version.to_string()))
+            .collect()
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_single_version_files() {
+            assert_eq!(
+                RuntimeDetector::parse(".nvmrc", "v16.20.2\n"),
+                pairs(&[("node", "16.20.2")])
+            );
+            assert_eq!(
+                RuntimeDetector::parse(".python-version", "3.8.18\n"),
+                pairs(&[("python", "3.8.18")])
+            );
+            assert_eq!(
+                RuntimeDetector::parse(".ruby-version", "ruby-3.2.2\n"),
+                pairs(&[("ruby", "3.2.2")])
+            );
+            assert_eq!(
+                RuntimeDetector::parse("rust-toolchain", "1.78.0\n"),
+                pairs(&[("rust", "1.78.0")])
+            );
+        }
+
+        #[test]
+        fn test_tool_versions() {
+            let content = "nodejs 20.11.0 18.19.0\ngolang 1.22.1 # pinned\n\nterraform 1.7.5\n";
+
+            assert_eq!(
+                RuntimeDetector::parse(".tool-versions", content),
+                pairs(&[
+                    ("node", "20.11.0"),
+                    ("go", "1.22.1"),
+                    ("terraform", "1.7.5")
+                ])
+            );
+        }
+
+        #[test]
+        fn test_manifests() {
+            assert_eq!(
+                RuntimeDetector::parse("go.mod", "module example.com/a\n\ngo 1.21\n"),
+                pairs(&[("go", "1.21")])
+            );
+            assert_eq!(
+                RuntimeDetector::parse(
+                    "package.json",
+                    r#"{"engines": {"node": ">=18", "npm": ">=9"}}"#
+                ),
+                pairs(&[("node", ">=18"), ("npm", ">=9")])
+            );
+            assert_eq!(
+                RuntimeDetector::parse(
+                    "Cargo.toml",
+                    "[workspace.package]\nrust-version = \"1.82\"\n"
+                ),
+                pairs(&[("rust", "1.82")])
+            );
+            assert_eq!(
+                RuntimeDetector::parse(
+                    "rust-toolchain.toml",
+                    "[toolchain]\nchannel = \"stable\"\n"
+                ),
+                pairs(&[("rust", "stable")])
+            );
+            assert_eq!(
+                RuntimeDetector::parse("global.json", r#"{"sdk": {"version": "8.0.100"}}"#),
+                pairs(&[("dotnet", "8.0.100")])
+            );
+            assert!(RuntimeDetector::parse("package.json", r#"{"name": "a"}"#).is_empty());
+        }
+
+        #[test]
+        fn test_dockerfile() {
+            let content = "ARG BASE=alpine\nFROM --platform=linux/amd64 golang:1.22-alpine AS build\nFROM node AS web\nFROM ${BASE}\nFROM build\nFROM mcr.microsoft.com/dotnet/aspnet:8.0\nFROM docker.io/library/python:3.8-slim@sha256:abc\n";
+
+            assert_eq!(
+                RuntimeDetector::parse("Dockerfile", content),
+                pairs(&[
+                    ("go", "1.22-alpine"),
+                    ("node", "latest"),
+                    ("dotnet", "8.0"),
+                    ("python", "3.8-slim"),
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use crate::entity::{DependencyReport, Ecosystem};
+use serde_json::{json, Value as JsonValue};
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const TOOL_NAME: &str = "techscan";
+const TOOL_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A package listed in an SBOM, identified by its package URL.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Component {
+    ecosystem: Ecosystem,
+    name: String,
+    version: Option<String>,
+}
+
+impl Component {
+    fn purl(&self) -> String {
+        self.ecosystem.purl(&self.name, self.version.as_deref())
+    }
+}
+
+pub struct SbomBuilder;
+
+impl SbomBuilder {
+    /// Builds a CycloneDX 1.5 BOM of the packages in `report`. The detected languages are
+    /// recorded as `techscan:language` properties of the metadata.
+    pub fn cyclonedx(report: &DependencyReport, timestamp: &str) -> JsonValue {
+        let components: Vec<JsonValue> = Self::components(report)
+            .iter()
+            .map(|component| {
+                let purl = component.purl();
+                let mut value = json!({
+                    "type": "library",
+                    "bom-ref": purl,
+                    "name": component.name,
+                    "purl": purl,
+                });
+                if let Some(version) = &component.version {
+                    value["version"] = json!(version);
+                }
+                value
+            })
+            .collect();
+        let properties: Vec<JsonValue> = report
+            .languages
+            .iter()
+            .map(|language| json!({ "name": "techscan:language", "value": language }))
+            .collect();
+
+        json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.5",
+            "version": 1,
+            "metadata": {
+                "timestamp": timestamp,
+                "tools": {
+                    "components": [{
+                        "type": "application",
+                        "name": TOOL_NAME,
+                        "version": TOOL_VERSION,
+                    }],
+                },
+                "component": {
+                    "type": "application",
+                    "bom-ref": "project",
+                    "name": Self::project_name(&report.dir),
+                },
+                "properties": properties,
+            },
+            "components": components,
+        })
+    }
+
+    /// Builds an SPDX 2.3 document describing the scanned project, which depends on every
+    /// package in `report`. The detected languages are noted in the project's comment.
+    pub fn spdx(report: &DependencyReport, timestamp: &str, namespace: &str) -> JsonValue {
+        let project_name = Self::project_name(&report.dir);
+        let mut packages = vec![json!({
+            "name": project_name,
+            "SPDXID": "SPDXRef-Project",
+            "downloadLocation": "NOASSERTION",
+            "filesAnalyzed": false,
+            "primaryPackagePurpose": "APPLICATION",
+            "comment": format!("Detected languages: {}", Self::language_list(report)),
+        })];
+        let mut relationships = vec![json!({
+            "spdxElementId": "SPDXRef-DOCUMENT",
+            "relationshipType": "DESCRIBES",
+            "relatedSpdxElement": "SPDXRef-Project",
+        })];
+
+        for (index, component) in Self::components(report).iter().enumerate() {
+            let spdx_id = format!("SPDXRef-Package-{}", index + 1);
+            let mut package = json!({
+                "name": component.name,
+                "SPDXID": spdx_id,
+                "downloadLocation": "NOASSERTION",
+                "filesAnalyzed": false,
+                "externalRefs": [{
+                    "referenceCategory": "PACKAGE-MANAGER",
+                    "referenceType": "purl",
+                    "referenceLocator": component.purl(),
+                }],
+            });
+            if let Some(version) = &component.version {
+                package["versionInfo"] = json!(version);
+            }
+            packages.push(package);
+            relationships.push(json!({
+                "spdxElementId": "SPDXRef-Project",
+                "relationshipType": "DEPENDS_ON",
+                "relatedSpdxElement": spdx_id,
+            }));
+        }
+
+        json!({
+            "spdxVersion": "SPDX-2.3",
+            "dataLicense": "CC0-1.0",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "name": project_name,
+            "documentNamespace": namespace,
+            "creationInfo": {
+                "created": timestamp,
+                "creators": [format!("Tool: {}-{}", TOOL_NAME, TOOL_VERSION)],
+            },
+            "packages": packages,
+            "relationships": relationships,
+        })
+    }
+
+    /// Returns the current time as an RFC 3339 UTC timestamp, e.g. `2024-05-01T12:00:00Z`.
+    pub fn timestamp(now: SystemTime) -> String {
+        let seconds = now.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let (days, time) = (seconds / 86_400, seconds % 86_400);
+
+        // Civil date from days since the epoch (Howard Hinnant's algorithm).
+        let z = days as i64 + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            year,
+            month,
+            day,
+            time / 3_600,
+            time % 3_600 / 60,
+            time % 60
+        )
+    }
+
+    /// Returns a document namespace that is unique per document, as SPDX requires.
+    pub fn namespace(dir: &str, now: SystemTime) -> String {
+        let nanos = now.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos());
+        format!(
+            "https://spdx.org/spdxdocs/{}-{}-{:x}",
+            TOOL_NAME,
+            Self::project_name(dir),
+            nanos
+        )
+    }
+
+    /// Lists the resolved packages of every lockfile, plus the dependencies of manifests
+    /// without a lockfile of the same ecosystem next to them. Manifest versions are kept
+    /// only when they pin an exact version.
+    fn components(report: &DependencyReport) -> BTreeSet<Component> {
+        let mut components = BTreeSet::new();
+
+        for lockfile in &report.lockfiles {
+            for dependency in &lockfile.dependencies {
+                components.insert(Component {
+                    ecosystem: lockfile.ecosystem,
+                    name: dependency.name.clone(),
+                    version: Some(dependency.version.clone()),
+                });
+            }
+        }
+
+        let locked: HashSet<(Ecosystem, Option<&Path>)> = report
+            .lockfiles
+            .iter()
+            .map(|lockfile| (lockfile.ecosystem, Path::new(&lockfile.path).parent()))
+            .collect();
+        for manifest in &report.manifests {
+            if locked.contains(&(manifest.ecosystem, Path::new(&manifest.path).parent())) {
+                continue;
+            }
+            for dependency in &manifest.dependencies {
+                components.insert(Component {
+                    ecosystem: manifest.ecosystem,
+                    name: dependency.name.clone(),
+                    version: dependency
+                        .version
+                        .as_deref()
+                        .and_then(|version| Self::exact_version(manifest.ecosystem, version))
+                        .map(str::to_string),
+                });
+            }
+        }
+
+        components
+    }
+
+    /// Returns the version a constraint pins, such as `1.2.3` for `==1.2.3`, or `None` for
+    /// ranges. A bare version is a caret requirement in Cargo and Mix, so those need `=`/`==`.
+    fn exact_version(ecosystem: Ecosystem, constraint: &str) -> Option<&str> {
+        let version = constraint
+            .trim()
+            .trim_start_matches("==")
+            .trim_start_matches('=')
+            .trim_start();
+        let has_operator = version.len() < constraint.trim().len();
+        let is_exact = (has_operator || !matches!(ecosystem, Ecosystem::Cargo | Ecosystem::Hex))
+            && version
+                .trim_start_matches('v')
+                .starts_with(|c: char| c.is_ascii_digit())
+            && version
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '_'));
+        is_exact.then_some(version)
+    }
+
+    fn language_list(report: &DependencyReport) -> String {
+        if report.languages.is_empty() {
+            "none".to_string()
+        } else {
+            report.languages.join(", ")
+        }
+    }
+
+    fn project_name(dir: &str) -> String {
+        Path::new(dir)
+            .canonicalize()
+            .ok()
+            .as_deref()
+            .unwrap_or(Path::new(dir))
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(dir)
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Dependency, DependencyScope, Lockfile, Manifest, ResolvedDependency};
+
+    fn create_test_report() -> DependencyReport {
+        DependencyReport {
+            dir: "/test/path".to_string(),
+            manifests: vec![
+                Manifest {
+                    path: "/test/path/web/package.json".to_string(),
+                    ecosystem: Ecosystem::Npm,
+                    dependencies: vec![Dependency::new(
+                        "react",
+                        Some("^18.2.0"),
+                        DependencyScope::Runtime,
+                    )],
+                },
+                Manifest {
+                    path: "/test/path/api/requirements.txt".to_string(),
+                    ecosystem: Ecosystem::Pypi,
+                    dependencies: vec![
+                        Dependency::new("Django", Some("==5.0.6"), DependencyScope::Runtime),
+                        Dependency::new("requests", Some(">=2.31"), DependencyScope::Runtime),
+                    ],
+                },
+            ],
+            lockfiles: vec![Lockfile {
+                path: "/test/path/web/package-lock.json".to_string(),
+                ecosystem: Ecosystem::Npm,
+                dependencies: vec![
+                    ResolvedDependency::new("@types/react", "18.3.3", false),
+                    ResolvedDependency::new("react", "18.3.1", true),
+                ],
+            }],
+            languages: vec!["TypeScript".to_string(), "Python".to_string()],
+        }
+    }
+
+    mod cyclonedx {
+        use super::*;
+
+        #[test]
+        fn test_components_and_metadata() {
+            let bom = SbomBuilder::cyclonedx(&create_test_report(), "2024-05-01T12:00:00Z");
+
+            assert_eq!(bom["bomFormat"], "CycloneDX");
+            assert_eq!(bom["specVersion"], "1.5");
+            assert_eq!(bom["metadata"]["component"]["name"], "path");
+            assert_eq!(bom["metadata"]["properties"][0]["value"], "TypeScript");
+
+            let purls: Vec<&str> = bom["components"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|component| component["purl"].as_str().unwrap())
+                .collect();
+            assert_eq!(
+                purls,
+                vec![
+                    "pkg:npm/%40types/react@18.3.3",
+                    "pkg:npm/react@18.3.1",
+                    "pkg:pypi/django@5.0.6",
+                    "pkg:pypi/requests",
+                ]
+            );
+            assert_eq!(bom["components"][2]["version"], "5.0.6");
+            assert!(bom["components"][3].get("version").is_none());
+        }
+    }
+
+    mod spdx {
+        use super::*;
+
+        #[test]
+        fn test_packages_and_relationships() {
+            let document = SbomBuilder::spdx(
+                &create_test_report(),
+                "2024-05-01T12:00:00Z",
+                "https://spdx.org/spdxdocs/techscan-path-1",
+            );
+
+            assert_eq!(document["spdxVersion"], "SPDX-2.3");
+            assert_eq!(document["creationInfo"]["created"], "2024-05-01T12:00:00Z");
+            assert_eq!(
+                document["packages"][0]["comment"],
+                "Detected languages: TypeScript, Python"
+            );
+            assert_eq!(document["packages"].as_array().unwrap().len(), 5);
+            assert_eq!(document["packages"][2]["name"], "react");
+            assert_eq!(document["packages"][2]["versionInfo"], "18.3.1");
+            assert_eq!(
+                document["packages"][2]["externalRefs"][0]["referenceLocator"],
+                "pkg:npm/react@18.3.1"
+            );
+            assert_eq!(
+                document["relationships"][4]["relatedSpdxElement"],
+                "SPDXRef-Package-4"
+            );
+        }
+    }
+
+    mod exact_version {
+        use super::*;
+
+        #[test]
+        fn test_pinned_versions() {
+            assert_eq!(
+                SbomBuilder::exact_version(Ecosystem::Pypi, "==5.0.6"),
+                Some("5.0.6")
+            );
+            assert_eq!(
+                SbomBuilder::exact_version(Ecosystem::Npm, "18.2.0"),
+                Some("18.2.0")
+            );
+            assert_eq!(
+                SbomBuilder::exact_version(Ecosystem::Rubygems, "= 7.1.3"),
+                Some("7.1.3")
+            );
+            assert_eq!(
+                SbomBuilder::exact_version(Ecosystem::Cargo, "=1.0.203"),
+                Some("1.0.203")
+            );
+        }
+
+        #[test]
+        fn test_ranges() {
+            assert_eq!(SbomBuilder::exact_version(Ecosystem::Npm, "^18.2.0"), None);
+            assert_eq!(SbomBuilder::exact_version(Ecosystem::Pypi, ">=2.31"), None);
+            assert_eq!(SbomBuilder::exact_version(Ecosystem::Cargo, "1.0"), None);
+            assert_eq!(SbomBuilder::exact_version(Ecosystem::Hex, "~> 1.7"), None);
+        }
+    }
+
+    mod timestamp {
+        use super::*;
+        use std::time::Duration;
+
+        #[test]
+        fn test_utc_timestamp() {
+            assert_eq!(SbomBuilder::timestamp(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+            assert_eq!(
+                SbomBuilder::timestamp(UNIX_EPOCH + Duration::from_secs(1_709_210_096)),
+                "2024-02-29T12:34:56Z"
+            );
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::entity::SfcSyntax;
+use regex::Regex;
+use std::sync::OnceLock;
+
+const FRONTMATTER_FENCE: &str = "---";
+
+fn open_tag_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        // Quoted attribute values may contain `>`, as in `generic="T extends Map<K, V>"`.
+        Regex::new(r#"(?i)<(script|style|template)\b((?:[^>"']|"[^"]*"|'[^']*')*)>"#)
+            .expect("open tag regex must be valid")
+    })
+}
+
+fn lang_attr_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r#"(?i)\blang\s*=\s*["']?([\w-]+)"#).expect("lang regex must be valid")
+    })
+}
+
+/// A block of embedded code and the language it is written in, as given by its `lang`
+/// attribute or the syntax default.
+#[derive(Debug, PartialEq, Eq)]
+pub struct SfcBlock {
+    pub lang: String,
+    pub content: String,
+}
+
+pub struct SfcParser;
+
+impl SfcParser {
+    /// Splits a single-file component into its script, style and template blocks, plus the
+    /// frontmatter and the surrounding markup when `syntax` counts them.
+    pub fn parse(content: &str, syntax: &SfcSyntax) -> Vec<SfcBlock> {
+        let mut blocks = Vec::new();
+        let mut markup = String::new();
+        let mut pos = 0;
+        let lower = content.to_ascii_lowercase();
+
+        if let Some(lang) = syntax.frontmatter_lang {
+            if let Some((frontmatter, end)) = Self::frontmatter(content) {
+                blocks.push(SfcBlock {
+                    lang: lang.to_string(),
+                    content: frontmatter.to_string(),
+                });
+                pos = end;
+            }
+        }
+
+        while let Some(captures) = open_tag_regex().captures_at(content, pos) {
+            let open = captures.get(0).expect("capture 0 is always present");
+            let tag = captures[1].to_lowercase();
+            let attrs = &captures[2];
+
+            let default_lang = match tag.as_str() {
+                "script" => Some(syntax.script_lang),
+                "style" => Some(syntax.style_lang),
+                _ => syntax.template_lang,
+            };
+            let Some(default_lang) = default_lang else {
+                markup.push_str(&content[pos..open.end()]);
+                pos = open.end();
+                continue;
+            };
+
+            markup.push_str(&content[pos..open.start()]);
+            if attrs.trim_end().ends_with('/') {
+                pos = open.end();
+                continue;
+            }
+
+            let (inner_end, close_end) = Self::find_close(&lower, open.end(), &tag);
+            let lang = lang_attr_regex()
+                .captures(attrs)
+                .map_or(default_lang, |lang| lang.get(1).map_or("", |m| m.as_str()));
+            blocks.push(SfcBlock {
+                lang: lang.to_lowercase(),
+                content: Self::trim_tag_lines(&content[open.end()..inner_end]).to_string(),
+            });
+            pos = close_end;
+        }
+        markup.push_str(&content[pos..]);
+
+        if let Some(lang) = syntax.markup_lang {
+            if !markup.trim().is_empty() {
+                blocks.push(SfcBlock {
+                    lang: lang.to_string(),
+                    content: markup.trim_matches('\n').to_string(),
+                });
+            }
+        }
+
+        blocks
+    }
+
+    /// Returns the body of a leading `---` fenced block and the offset right after it.
+    fn frontmatter(content: &str) -> Option<(&str, usize)> {
+        let mut lines = content.split_inclusive('\n');
+        let fence = lines.next()?;
+        if fence.trim_end() != FRONTMATTER_FENCE {
+            return None;
+        }
+
+        let body_start = fence.len();
+        let mut line_start = body_start;
+        for line in lines {
+            if line.trim_end() == FRONTMATTER_FENCE {
+                return Some((&content[body_start..line_start], line_start + line.len()));
+            }
+            line_start += line.len();
+        }
+        None
+    }
+
+    /// Finds the closing tag of a block that starts at `from`. Returns the end of the block
+    /// content and the offset after the closing tag; unclosed blocks run to the end.
+    /// `lower` is the ASCII-lowercased content, so offsets match the original.
+    fn find_close(lower: &str, from: usize, tag: &str) -> (usize, usize) {
+        let open_marker = format!("<{}", tag);
+        let close_marker = format!("</{}", tag);
+        // Only templates nest, e.g. Vue's `<template v-slot:item>` inside the root template.
+        let nests = tag == "template";
+
+        let mut depth = 1;
+        let mut pos = from;
+        while let Some(close) = lower[pos..].find(&close_marker).map(|i| pos + i) {
+            if nests {
+                depth += lower[pos..close]
+                    .match_indices(&open_marker)
+                    .filter(|(i, _)| {
+                        lower[pos + i + open_marker.len()..]
+                            .starts_with(|c: char| c.is_whitespace() || c == '>')
+                    })
+                    .count();
+            }
+            depth -= 1;
+            let close_end = lower[close..]
+                .find('>')
+                .map_or(lower.len(), |i| close + i + 1);
+            if depth == 0 {
+                return (close, close_end);
+            }
+            pos = close_end;
+        }
+        (lower.len(), lower.len())
+    }
+
+    /// Drops the rest of the opening tag line and the indentation before the closing tag.
+    fn trim_tag_lines(inner: &str) -> &str {
+        let inner = match inner.find('\n') {
+            Some(i) if inner[..i].trim().is_empty() => &inner[i + 1..],
+            _ => inner,
+        };
+        match inner.rfind('\n') {
+            Some(i) if inner[i + 1..].trim().is_empty() => &inner[..i + 1],
+            None if inner.trim().is_empty() => "",
+            _ => inner,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SfcConfig;
+
+    fn langs(blocks: &[SfcBlock]) -> Vec<&str> {
+        blocks.iter().map(|block| block.lang.as_str()).collect()
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_vue_blocks() {
+            let content = "<template lang=\"pug\">\ndiv\n  p hello\n</template>\n\n<script setup lang=\"ts\">\nconst a: number = 1\n</script>\n\n<style scoped lang='scss'>\n.a { b: c; }\n</style>\n";
+            let blocks = SfcParser::parse(content, SfcConfig::find("Vue").unwrap());
+
+            assert_eq!(langs(&blocks), vec!["pug", "ts", "scss"]);
+            assert_eq!(blocks[0].content, "div\n  p hello\n");
+            assert_eq!(blocks[1].content, "const a: number = 1\n");
+        }
+
+        #[test]
+        fn test_vue_defaults_and_nested_templates() {
+            let content = "<template>\n  <List>\n    <template #item>\n      <span />\n    </template>\n  </List>\n</template>\n<script>\nexport default {}\n</script>\n<style>\n.a {}\n</style>\n";
+            let blocks = SfcParser::parse(content, SfcConfig::find("Vue").unwrap());
+
+            assert_eq!(langs(&blocks), vec!["html", "js", "css"]);
+            assert_eq!(blocks[0].content.lines().count(), 5);
+        }
+
+        #[test]
+        fn test_svelte_markup() {
+            let content = "<script lang=\"ts\">\nlet count = 0\n</script>\n\n<button on:click={() => count++}>{count}</button>\n\n<style>\nbutton { color: red; }\n</style>\n";
+            let blocks = SfcParser::parse(content, SfcConfig::find("Svelte").unwrap());
+
+            assert_eq!(langs(&blocks), vec!["ts", "css", "html"]);
+            assert_eq!(
+                blocks[2].content,
+                "<button on:click={() => count++}>{count}</button>"
+            );
+        }
+
+        #[test]
+        fn test_astro_frontmatter() {
+            let content = "---\nconst title = 'Hi'\n---\n<h1>{title}</h1>\n<script>\nconsole.log(1)\n</script>\n";
+            let blocks = SfcParser::parse(content, SfcConfig::find("Astro").unwrap());
+
+            assert_eq!(langs(&blocks), vec!["ts", "ts", "html"]);
+            assert_eq!(blocks[0].content, "const title = 'Hi'\n");
+            assert_eq!(blocks[2].content, "<h1>{title}</h1>");
+        }
+
+        #[test]
+        fn test_greater_than_in_attribute_values() {
+            let content = "<template v-if=\"a > b\">\n<p>hi</p>\n</template>\n<script setup lang=\"ts\" generic=\"T extends Record<string, X>\">\nconst a: T = {}\n</script>\n";
+            let blocks = SfcParser::parse(content, SfcConfig::find("Vue").unwrap());
+
+            assert_eq!(langs(&blocks), vec!["html", "ts"]);
+            assert_eq!(blocks[0].content, "<p>hi</p>\n");
+            assert_eq!(blocks[1].content, "const a: T = {}\n");
+        }
+
+        #[test]
+        fn test_self_closing_and_unclosed_blocks() {
+            let content = "<script src=\"./a.js\" />\n<style>\n.a {}\n";
+            let blocks = SfcParser::parse(content, SfcConfig::find("Vue").unwrap());
+
+            assert_eq!(langs(&blocks), vec!["css"]);
+            assert_eq!(blocks[0].content, ".a {}\n");
+        }
+    }
+}
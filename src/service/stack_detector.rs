@@ -0,0 +1,327 @@
+use crate::entity::{
+    Detection, DetectionRule, Ecosystem, Evidence, EvidenceKind, File, FileAttributes, Manifest,
+};
+use crate::service::FileClassifier;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+
+/// File, import and content matches recorded per rule and kind. One is enough to detect a
+/// technology; a few more keep the result auditable without listing every file.
+const MAX_FILE_EVIDENCE: usize = 3;
+
+pub struct StackDetector;
+
+impl StackDetector {
+    /// Runs `rules` over the files and parsed manifests of `dir` and returns a detection
+    /// for every rule that matched, in rule order. Vendored and generated files are ignored.
+    pub fn detect(
+        rules: &[DetectionRule],
+        dir: &str,
+        files: &[File],
+        manifests: &[Manifest],
+    ) -> Vec<Detection> {
+        let mut evidence: Vec<Vec<Evidence>> = vec![Vec::new(); rules.len()];
+
+        for manifest in manifests {
+            if Self::is_vendored(Self::relative_path(dir, &manifest.path)) {
+                continue;
+            }
+            for dependency in &manifest.dependencies {
+                for (index, rule) in rules.iter().enumerate() {
+                    let matched = rule.dependencies.iter().any(|(ecosystem, pattern)| {
+                        *ecosystem == manifest.ecosystem
+                            && Self::matches_name(*ecosystem, pattern, &dependency.name)
+                    });
+                    if matched {
+                        evidence[index].push(Evidence {
+                            kind: EvidenceKind::Dependency,
+                            path: manifest.path.clone(),
+                            detail: dependency.name.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let file_patterns: Vec<(usize, &str)> = rules
+            .iter()
+            .enumerate()
+            .flat_map(|(index, rule)| rule.files.iter().map(move |pattern| (index, *pattern)))
+            .collect();
+        let file_set = Self::build_glob_set(file_patterns.iter().map(|(_, pattern)| *pattern));
+        let imports: Vec<Vec<(Ecosystem, Regex)>> = rules
+            .iter()
+            .map(|rule| {
+                rule.imports
+                    .iter()
+                    .map(|(ecosystem, pattern)| (*ecosystem, Self::regex(pattern)))
+                    .collect()
+            })
+            .collect();
+        let content_patterns: Vec<(usize, Regex)> = rules
+            .iter()
+            .enumerate()
+            .flat_map(|(index, rule)| {
+                rule.contents
+                    .iter()
+                    .map(move |(_, pattern)| (index, Self::regex(pattern)))
+            })
+            .collect();
+        let content_set = Self::build_glob_set(
+            rules
+                .iter()
+                .flat_map(|rule| rule.contents.iter().map(|(glob, _)| *glob)),
+        );
+
+        for file in files {
+            let relative_path = Self::relative_path(dir, &file.path);
+            if Self::is_vendored(relative_path) {
+                continue;
+            }
+
+            for pattern_index in file_set.matches(relative_path) {
+                let (index, pattern) = file_patterns[pattern_index];
+                if Self::has_room(&evidence[index], EvidenceKind::File) {
+                    evidence[index].push(Evidence {
+                        kind: EvidenceKind::File,
+                        path: file.path.clone(),
+                        detail: pattern.to_string(),
+                    });
+                }
+            }
+
+            // Import patterns only apply to the source files of their ecosystem, so a Go
+            // module path in a Rust string is not read as an import.
+            let imports_of_file = |index: usize| {
+                imports[index].iter().filter(|(ecosystem, _)| {
+                    file.ext
+                        .as_deref()
+                        .is_some_and(|ext| ecosystem.source_extensions().contains(&ext))
+                })
+            };
+            let pending_imports: Vec<usize> = (0..rules.len())
+                .filter(|&index| {
+                    imports_of_file(index).next().is_some()
+                        && Self::has_room(&evidence[index], EvidenceKind::Import)
+                })
+                .collect();
+            let pending_contents: Vec<usize> = content_set
+                .matches(relative_path)
+                .into_iter()
+                .filter(|&pattern_index| {
+                    let (index, _) = content_patterns[pattern_index];
+                    Self::has_room(&evidence[index], EvidenceKind::Content)
+                })
+                .collect();
+            if pending_imports.is_empty() && pending_contents.is_empty() {
+                continue;
+            }
+            // Binary and non UTF-8 files cannot contain the text looked for.
+            let Ok(content) = fs::read_to_string(&file.path) else {
+                continue;
+            };
+
+            for index in pending_imports {
+                if let Some(found) =
+                    imports_of_file(index).find_map(|(_, regex)| regex.find(&content))
+                {
+                    evidence[index].push(Evidence {
+                        kind: EvidenceKind::Import,
+                        path: file.path.clone(),
+                        detail: Self::line_at(&content, found.start()).to_string(),
+                    });
+                }
+            }
+            for pattern_index in pending_contents {
+                let (index, regex) = &content_patterns[pattern_index];
+                // Several patterns of a rule can match the same file; one is recorded.
+                let recorded = evidence[*index]
+                    .iter()
+                    .any(|item| item.kind == EvidenceKind::Content && item.path == file.path);
+                if recorded {
+                    continue;
+                }
+                if let Some(found) = regex.find(&content) {
+                    evidence[*index].push(Evidence {
+                        kind: EvidenceKind::Content,
+                        path: file.path.clone(),
+                        detail: Self::line_at(&content, found.start()).to_string(),
+                    });
+                }
+            }
+        }
+
+        rules
+            .iter()
+            .zip(evidence)
+            .filter(|(_, evidence)| !evidence.is_empty())
+            .map(|(rule, evidence)| Detection {
+                name: rule.name.to_string(),
+                category: rule.category.to_string(),
+                evidence,
+            })
+            .collect()
+    }
+
+    fn matches_name(ecosystem: Ecosystem, pattern: &str, name: &str) -> bool {
+        let name = ecosystem.normalize_name(name);
+        match pattern.strip_suffix('*') {
+            Some(prefix) => name.starts_with(&ecosystem.normalize_name(prefix)),
+            None => name == ecosystem.normalize_name(pattern),
+        }
+    }
+
+    fn has_room(evidence: &[Evidence], kind: EvidenceKind) -> bool {
+        evidence.iter().filter(|item| item.kind == kind).count() < MAX_FILE_EVIDENCE
+    }
+
+    fn regex(pattern: &str) -> Regex {
+        Regex::new(pattern).expect("built-in detection patterns must be valid")
+    }
+
+    fn is_vendored(relative_path: &str) -> bool {
+        FileClassifier::classify_path(relative_path, &FileAttributes::default()).is_some()
+    }
+
+    fn relative_path<'a>(dir: &str, path: &'a str) -> &'a str {
+        Path::new(path)
+            .strip_prefix(dir)
+            .ok()
+            .and_then(|path| path.to_str())
+            .unwrap_or(path)
+    }
+
+    fn line_at(content: &str, offset: usize) -> &str {
+        let start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = content[offset..]
+            .find('\n')
+            .map_or(content.len(), |i| offset + i);
+        content[start..end].trim()
+    }
+
+    fn build_glob_set<'a>(patterns: impl Iterator<Item = &'a str>) -> GlobSet {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            builder.add(Glob::new(pattern).expect("built-in detection globs must be valid"));
+        }
+        builder
+            .build()
+            .expect("built-in detection globs must be valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Dependency, DependencyScope};
+
+    const RULES: &[DetectionRule] = &[
+        DetectionRule {
+            name: "Spring Boot",
+            category: "backend",
+            dependencies: &[(Ecosystem::Maven, "org.springframework.boot:*")],
+            files: &[],
+            imports: &[],
+            contents: &[],
+        },
+        DetectionRule {
+            name: "Django",
+            category: "backend",
+            dependencies: &[(Ecosystem::Pypi, "django")],
+            files: &["**/manage.py"],
+            imports: &[],
+            contents: &[],
+        },
+    ];
+
+    mod detect {
+        use super::*;
+
+        #[test]
+        fn test_dependency_and_file_evidence() {
+            let manifests = vec![
+                Manifest {
+                    path: "repo/api/requirements.txt".to_string(),
+                    ecosystem: Ecosystem::Pypi,
+                    dependencies: vec![Dependency::new(
+                        "Django",
+                        Some(">=5.0"),
+                        DependencyScope::Runtime,
+                    )],
+                },
+                Manifest {
+                    path: "repo/pom.xml".to_string(),
+                    ecosystem: Ecosystem::Maven,
+                    dependencies: vec![Dependency::new(
+                        "org.springframework.boot:spring-boot-starter-web",
+                        None,
+                        DependencyScope::Runtime,
+                    )],
+                },
+            ];
+            let files = vec![
+                File::from_path("repo/api/manage.py"),
+                File::from_path("repo/vendor/lib/manage.py"),
+            ];
+
+            let detections = StackDetector::detect(RULES, "repo", &files, &manifests);
+
+            assert_eq!(detections.len(), 2);
+            assert_eq!(detections[0].name, "Spring Boot");
+            assert_eq!(
+                detections[1].evidence,
+                vec![
+                    Evidence {
+                        kind: EvidenceKind::Dependency,
+                        path: "repo/api/requirements.txt".to_string(),
+                        detail: "Django".to_string(),
+                    },
+                    Evidence {
+                        kind: EvidenceKind::File,
+                        path: "repo/api/manage.py".to_string(),
+                        detail: "**/manage.py".to_string(),
+                    },
+                ]
+            );
+        }
+
+        #[test]
+        fn test_import_only_in_ecosystem_sources() {
+            const GIN: &[DetectionRule] = &[DetectionRule {
+                name: "Gin",
+                category: "backend",
+                dependencies: &[],
+                files: &[],
+                imports: &[(Ecosystem::Go, r#""github\.com/gin-gonic/gin""#)],
+                contents: &[],
+            }];
+            let files = vec![
+                File::from_path("tests/fixtures/stack/svc/main.go"),
+                File::from_path("tests/fixtures/stack/worker/src/main.rs"),
+            ];
+
+            let detections = StackDetector::detect(GIN, "tests/fixtures/stack", &files, &[]);
+            assert_eq!(detections.len(), 1);
+            assert_eq!(
+                detections[0].evidence,
+                vec![Evidence {
+                    kind: EvidenceKind::Import,
+                    path: "tests/fixtures/stack/svc/main.go".to_string(),
+                    detail: "import \"github.com/gin-gonic/gin\"".to_string(),
+                }]
+            );
+
+            let rust_only = vec![File::from_path("tests/fixtures/stack/worker/src/main.rs")];
+            assert!(StackDetector::detect(GIN, "tests/fixtures/stack", &rust_only, &[]).is_empty());
+        }
+
+        #[test]
+        fn test_no_match() {
+            let files = vec![File::from_path("repo/src/main.rs")];
+
+            assert!(StackDetector::detect(RULES, "repo", &files, &[]).is_empty());
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::config::REPORTER_FORMAT_JSON;
+use crate::entity::StackReport;
+use crate::service::Reporter;
+use std::io::{self, Write};
+
+pub struct StackJsonReporter;
+
+impl Default for StackJsonReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StackJsonReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn to_json(&self, report: &StackReport) -> io::Result<String> {
+        serde_json::to_string_pretty(report)
+            .map_err(|e| io::Error::other(format!("JSON serialization error: {}", e)))
+    }
+}
+
+impl Reporter<StackReport> for StackJsonReporter {
+    fn name(&self) -> &str {
+        REPORTER_FORMAT_JSON
+    }
+
+    fn file_extension(&self) -> &str {
+        "json"
+    }
+
+    fn render(&self, report: &StackReport, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_json(report)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Detection, Evidence, EvidenceKind, ProjectRuntimes, RuntimeVersion};
+
+    fn create_test_report() -> StackReport {
+        StackReport {
+            dir: "/test/path".to_string(),
+            frameworks: vec![Detection {
+                name: "Django".to_string(),
+                category: "backend".to_string(),
+                evidence: vec![
+                    Evidence {
+                        kind: EvidenceKind::Dependency,
+                        path: "/test/path/requirements.txt".to_string(),
+                        detail: "Django".to_string(),
+                    },
+                    Evidence {
+                        kind: EvidenceKind::File,
+                        path: "/test/path/manage.py".to_string(),
+                        detail: "**/manage.py".to_string(),
+                    },
+                ],
+            }],
+            infrastructure: vec![Detection {
+                name: "Kubernetes".to_string(),
+                category: "orchestration".to_string(),
+                evidence: vec![Evidence {
+                    kind: EvidenceKind::Content,
+                    path: "/test/path/deploy/app.yaml".to_string(),
+                    detail: "apiVersion: apps/v1".to_string(),
+                }],
+            }],
+            tooling: vec![Detection {
+                name: "ESLint".to_string(),
+                category: "lint".to_string(),
+                evidence: vec![Evidence {
+                    kind: EvidenceKind::File,
+                    path: "/test/path/web/.eslintrc.json".to_string(),
+                    detail: "**/.eslintrc.*".to_string(),
+                }],
+            }],
+            datastores: vec![Detection {
+                name: "PostgreSQL".to_string(),
+                category: "relational".to_string(),
+                evidence: vec![Evidence {
+                    kind: EvidenceKind::Content,
+                    path: "/test/path/docker-compose.yml".to_string(),
+                    detail: "image: postgres:16-alpine".to_string(),
+                }],
+            }],
+            runtimes: vec![ProjectRuntimes {
+                root: "/test/path/web".to_string(),
+                runtimes: vec![RuntimeVersion {
+                    runtime: "node".to_string(),
+                    version: "16.20.2".to_string(),
+                    source: "/test/path/web/.nvmrc".to_string(),
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_to_json_success() {
+        let reporter = StackJsonReporter::new();
+        let report = create_test_report();
+
+        let json_string = reporter.to_json(&report).unwrap();
+        let parsed: serde_json::Value =
+            serde_json::from_str(&json_string).expect("Generated JSON is invalid");
+
+        assert_eq!(parsed["frameworks"][0]["name"], "Django");
+        assert_eq!(parsed["infrastructure"][0]["category"], "orchestration");
+        assert_eq!(parsed["tooling"][0]["name"], "ESLint");
+        assert_eq!(parsed["datastores"][0]["category"], "relational");
+        assert_eq!(parsed["runtimes"][0]["runtimes"][0]["version"], "16.20.2");
+        assert_eq!(parsed["frameworks"][0]["evidence"][0]["kind"], "dependency");
+        assert_eq!(
+            parsed["frameworks"][0]["evidence"][1]["path"],
+            "/test/path/manage.py"
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use crate::config::{DatastoreConfig, FrameworkConfig, InfrastructureConfig, ToolingConfig};
+use crate::entity::{File, Result, StackReport, StackScannerOptions, TechScanError};
+use crate::service::{DependencyScanner, FileWalker, RuntimeDetector, StackDetector};
+use std::path::Path;
+
+#[derive(Debug)]
+pub struct StackScanner {
+    dir: String,
+    opts: StackScannerOptions,
+}
+
+impl StackScanner {
+    pub fn new(dir: &str, opts: Option<StackScannerOptions>) -> Result<Self> {
+        if !Path::new(dir).exists() {
+            return Err(TechScanError::DirectoryNotFound(dir.to_string()));
+        }
+
+        Ok(Self {
+            dir: dir.to_string(),
+            opts: opts.unwrap_or_default(),
+        })
+    }
+
+    pub fn scan(&self) -> Result<Vec<File>> {
+        FileWalker::walk(&self.dir, &self.opts.exclude)
+    }
+
+    pub fn analyze(&self, files: Vec<File>) -> StackReport {
+        let manifests = DependencyScanner::parse_manifests(&files);
+
+        StackReport {
+            dir: self.dir.clone(),
+            frameworks: StackDetector::detect(
+                FrameworkConfig::rules(),
+                &self.dir,
+                &files,
+                &manifests,
+            ),
+            infrastructure: StackDetector::detect(
+                InfrastructureConfig::rules(),
+                &self.dir,
+                &files,
+                &manifests,
+            ),
+            tooling: StackDetector::detect(ToolingConfig::rules(), &self.dir, &files, &manifests),
+            datastores: StackDetector::detect(
+                DatastoreConfig::rules(),
+                &self.dir,
+                &files,
+                &manifests,
+            ),
+            runtimes: RuntimeDetector::detect(&self.dir, &files),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Detection, EvidenceKind};
+
+    fn detected(detections: &[Detection]) -> Vec<(&str, &str)> {
+        detections
+            .iter()
+            .map(|detection| (detection.name.as_str(), detection.category.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_scanner_nonexistent_directory() {
+        let result = StackScanner::new("nonexistent_directory_12345", None);
+
+        assert!(matches!(result, Err(TechScanError::DirectoryNotFound(_))));
+    }
+
+    #[test]
+    fn test_analyze_detects_frameworks() {
+        let scanner = StackScanner::new("tests/fixtures/stack", None)
+            .expect("StackScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let frameworks: Vec<(&str, Vec<EvidenceKind>)> = report
+            .frameworks
+            .iter()
+            .map(|framework| {
+                let mut kinds: Vec<EvidenceKind> =
+                    framework.evidence.iter().map(|item| item.kind).collect();
+                kinds.sort();
+                kinds.dedup();
+                (framework.name.as_str(), kinds)
+            })
+            .collect();
+
+        assert_eq!(
+            frameworks,
+            vec![
+                (
+                    "React",
+                    vec![EvidenceKind::Dependency, EvidenceKind::Import]
+                ),
+                (
+                    "Next.js",
+                    vec![EvidenceKind::Dependency, EvidenceKind::File]
+                ),
+                (
+                    "Django",
+                    vec![
+                        EvidenceKind::Dependency,
+                        EvidenceKind::File,
+                        EvidenceKind::Import
+                    ]
+                ),
+                ("Gin", vec![EvidenceKind::Import]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_analyze_detects_infrastructure() {
+        let scanner = StackScanner::new("tests/fixtures/stack", None)
+            .expect("StackScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        assert_eq!(
+            detected(&report.infrastructure),
+            vec![
+                ("GitHub Actions", "ci"),
+                ("Docker", "container"),
+                ("Docker Compose", "container"),
+                ("Kubernetes", "orchestration"),
+                ("Helm", "orchestration"),
+                ("Terraform", "iac"),
+            ]
+        );
+
+        let kubernetes = &report.infrastructure[3];
+        assert_eq!(kubernetes.evidence.len(), 1);
+        assert_eq!(kubernetes.evidence[0].kind, EvidenceKind::Content);
+        assert_eq!(
+            kubernetes.evidence[0].path,
+            "tests/fixtures/stack/deploy/k8s/deployment.yaml"
+        );
+    }
+
+    #[test]
+    fn test_analyze_detects_tooling() {
+        let scanner = StackScanner::new("tests/fixtures/stack", None)
+            .expect("StackScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        assert_eq!(
+            detected(&report.tooling),
+            vec![
+                ("Jest", "test"),
+                ("pytest", "test"),
+                ("Go test", "test"),
+                ("ESLint", "lint"),
+                ("Ruff", "lint"),
+                ("EditorConfig", "editor"),
+            ]
+        );
+
+        let eslint = &report.tooling[3];
+        let kinds: Vec<EvidenceKind> = eslint.evidence.iter().map(|item| item.kind).collect();
+        assert_eq!(kinds, vec![EvidenceKind::Dependency, EvidenceKind::File]);
+    }
+
+    #[test]
+    fn test_analyze_detects_datastores() {
+        let scanner = StackScanner::new("tests/fixtures/stack", None)
+            .expect("StackScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        assert_eq!(
+            detected(&report.datastores),
+            vec![
+                ("PostgreSQL", "relational"),
+                ("MySQL", "relational"),
+                ("Redis", "key-value"),
+                ("Schema migrations", "migrations"),
+            ]
+        );
+
+        let postgres: Vec<(EvidenceKind, &str)> = report.datastores[0]
+            .evidence
+            .iter()
+            .map(|item| (item.kind, item.detail.as_str()))
+            .collect();
+        assert_eq!(
+            postgres,
+            vec![
+                (EvidenceKind::Dependency, "psycopg"),
+                (EvidenceKind::Content, "provider = \"postgresql\""),
+                (EvidenceKind::Content, "image: postgres:16-alpine"),
+            ]
+        );
+        assert_eq!(
+            report.datastores[1].evidence[0].path,
+            "tests/fixtures/stack/worker/Cargo.toml"
+        );
+    }
+
+    #[test]
+    fn test_analyze_detects_runtimes() {
+        let scanner = StackScanner::new("tests/fixtures/stack", None)
+            .expect("StackScanner creation should succeed");
+
+        let files = scanner.scan().expect("Scanning should succeed");
+        let report = scanner.analyze(files);
+
+        let runtimes: Vec<(&str, &str, &str)> = report
+            .runtimes
+            .iter()
+            .flat_map(|project| {
+                project.runtimes.iter().map(|runtime| {
+                    (
+                        project.root.as_str(),
+                        runtime.runtime.as_str(),
+                        runtime.version.as_str(),
+                    )
+                })
+            })
+            .collect();
+
+        assert_eq!(
+            runtimes,
+            vec![
+                ("tests/fixtures/stack/api", "python", "3.8.18"),
+                ("tests/fixtures/stack/api", "python", "3.12-slim"),
+                ("tests/fixtures/stack/svc", "go", "1.21.5"),
+                ("tests/fixtures/stack/web", "node", "16"),
+                ("tests/fixtures/stack/web", "node", ">=16"),
+            ]
+        );
+    }
+}
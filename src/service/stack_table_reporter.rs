@@ -0,0 +1,211 @@
+use crate::config::REPORTER_FORMAT_TABLE;
+use crate::entity::{Detection, StackReport};
+use crate::service::Reporter;
+use std::io::{self, Write};
+use tabled::builder::Builder;
+use tabled::settings::{object::Rows, Alignment, Modify, Style};
+
+pub struct StackTableReporter;
+
+impl Default for StackTableReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StackTableReporter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn to_table(&self, report: &StackReport) -> String {
+        let mut output = Vec::new();
+
+        let mut summary_builder = Builder::default();
+        summary_builder.push_record(vec!["Item", "Value"]);
+        summary_builder.push_record(vec!["Directory", &report.dir]);
+        summary_builder.push_record(vec!["Frameworks", &report.frameworks.len().to_string()]);
+        summary_builder.push_record(vec![
+            "Infrastructure",
+            &report.infrastructure.len().to_string(),
+        ]);
+        summary_builder.push_record(vec!["Tooling", &report.tooling.len().to_string()]);
+        summary_builder.push_record(vec!["Datastores", &report.datastores.len().to_string()]);
+        summary_builder.push_record(vec![
+            "Projects with Runtimes",
+            &report.runtimes.len().to_string(),
+        ]);
+
+        let summary_table = summary_builder
+            .build()
+            .with(Style::sharp())
+            .with(Modify::new(Rows::new(0..=0)).with(Alignment::center()))
+            .to_string();
+
+        output.push("=== Scan Summary ===".to_string());
+        output.push(summary_table);
+
+        if !report.frameworks.is_empty() {
+            output.push(String::new());
+            output.push("=== Frameworks ===".to_string());
+            output.push(Self::detection_table("Framework", &report.frameworks));
+        }
+
+        if !report.infrastructure.is_empty() {
+            output.push(String::new());
+            output.push("=== Infrastructure ===".to_string());
+            output.push(Self::detection_table("Tool", &report.infrastructure));
+        }
+
+        if !report.tooling.is_empty() {
+            output.push(String::new());
+            output.push("=== Tooling ===".to_string());
+            output.push(Self::detection_table("Tool", &report.tooling));
+        }
+
+        if !report.datastores.is_empty() {
+            output.push(String::new());
+            output.push("=== Datastores ===".to_string());
+            output.push(Self::detection_table("Datastore", &report.datastores));
+        }
+
+        if !report.runtimes.is_empty() {
+            output.push(String::new());
+            output.push("=== Runtimes ===".to_string());
+            output.push(Self::runtime_table(report));
+        }
+
+        output.join("\n")
+    }
+
+    fn runtime_table(report: &StackReport) -> String {
+        let mut builder = Builder::default();
+        builder.push_record(vec!["Project", "Runtime", "Version", "Source"]);
+        for project in &report.runtimes {
+            for runtime in &project.runtimes {
+                builder.push_record(vec![
+                    &project.root,
+                    &runtime.runtime,
+                    &runtime.version,
+                    &runtime.source,
+                ]);
+            }
+        }
+
+        builder.build().with(Style::sharp()).to_string()
+    }
+
+    /// Lists each detection with one line of evidence per match.
+    fn detection_table(label: &str, detections: &[Detection]) -> String {
+        let mut builder = Builder::default();
+        builder.push_record(vec![label, "Category", "Evidence"]);
+        for detection in detections {
+            let evidence = detection
+                .evidence
+                .iter()
+                .map(|item| format!("{}: {} ({})", item.kind.as_str(), item.detail, item.path))
+                .collect::<Vec<_>>()
+                .join("\n");
+            builder.push_record(vec![&detection.name, &detection.category, &evidence]);
+        }
+
+        builder.build().with(Style::sharp()).to_string()
+    }
+}
+
+impl Reporter<StackReport> for StackTableReporter {
+    fn name(&self) -> &str {
+        REPORTER_FORMAT_TABLE
+    }
+
+    fn file_extension(&self) -> &str {
+        "txt"
+    }
+
+    fn render(&self, report: &StackReport, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "{}", self.to_table(report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Evidence, EvidenceKind, ProjectRuntimes, RuntimeVersion};
+
+    fn create_test_report() -> StackReport {
+        StackReport {
+            dir: "/test/path".to_string(),
+            frameworks: vec![Detection {
+                name: "Django".to_string(),
+                category: "backend".to_string(),
+                evidence: vec![
+                    Evidence {
+                        kind: EvidenceKind::Dependency,
+                        path: "/test/path/requirements.txt".to_string(),
+                        detail: "Django".to_string(),
+                    },
+                    Evidence {
+                        kind: EvidenceKind::File,
+                        path: "/test/path/manage.py".to_string(),
+                        detail: "**/manage.py".to_string(),
+                    },
+                ],
+            }],
+            infrastructure: vec![Detection {
+                name: "Kubernetes".to_string(),
+                category: "orchestration".to_string(),
+                evidence: vec![Evidence {
+                    kind: EvidenceKind::Content,
+                    path: "/test/path/deploy/app.yaml".to_string(),
+                    detail: "apiVersion: apps/v1".to_string(),
+                }],
+            }],
+            tooling: vec![Detection {
+                name: "ESLint".to_string(),
+                category: "lint".to_string(),
+                evidence: vec![Evidence {
+                    kind: EvidenceKind::File,
+                    path: "/test/path/web/.eslintrc.json".to_string(),
+                    detail: "**/.eslintrc.*".to_string(),
+                }],
+            }],
+            datastores: vec![Detection {
+                name: "PostgreSQL".to_string(),
+                category: "relational".to_string(),
+                evidence: vec![Evidence {
+                    kind: EvidenceKind::Content,
+                    path: "/test/path/docker-compose.yml".to_string(),
+                    detail: "image: postgres:16-alpine".to_string(),
+                }],
+            }],
+            runtimes: vec![ProjectRuntimes {
+                root: "/test/path/web".to_string(),
+                runtimes: vec![RuntimeVersion {
+                    runtime: "node".to_string(),
+                    version: "16.20.2".to_string(),
+                    source: "/test/path/web/.nvmrc".to_string(),
+                }],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_to_table_lists_evidence() {
+        let reporter = StackTableReporter::new();
+        let report = create_test_report();
+
+        let table_output = reporter.to_table(&report);
+
+        assert!(table_output.contains("=== Frameworks ==="));
+        assert!(table_output.contains("dependency: Django (/test/path/requirements.txt)"));
+        assert!(table_output.contains("file: **/manage.py (/test/path/manage.py)"));
+        assert!(table_output.contains("=== Infrastructure ==="));
+        assert!(table_output.contains("=== Tooling ==="));
+        assert!(table_output.contains("file: **/.eslintrc.* (/test/path/web/.eslintrc.json)"));
+        assert!(table_output.contains("=== Datastores ==="));
+        assert!(table_output.contains("content: image: postgres:16-alpine"));
+        assert!(table_output.contains("=== Runtimes ==="));
+        assert!(table_output.contains("16.20.2"));
+        assert!(table_output.contains("content: apiVersion: apps/v1 (/test/path/deploy/app.yaml)"));
+    }
+}
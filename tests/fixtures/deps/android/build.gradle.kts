@@ -0,0 +1,8 @@
+plugins {
+    id("com.android.application")
+}
+
+dependencies {
+    implementation("androidx.core:core-ktx:1.13.1")
+    testImplementation("junit:junit:4.13.2")
+}
@@ -0,0 +1,9 @@
+module example.com/api
+
+go 1.22
+
+require (
+	github.com/gin-gonic/gin v1.9.1
+	github.com/lib/pq v1.10.9
+	golang.org/x/net v0.25.0 // indirect
+)
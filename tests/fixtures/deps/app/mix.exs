@@ -0,0 +1,14 @@
+defmodule App.MixProject do
+  use Mix.Project
+
+  def project do
+    [app: :app, version: "0.1.0", elixir: "~> 1.16", deps: deps()]
+  end
+
+  defp deps do
+    [
+      {:phoenix, "~> 1.7.12"},
+      {:ex_doc, "~> 0.31", only: :dev, runtime: false}
+    ]
+  end
+end
@@ -0,0 +1,2 @@
+// Code generated by protoc-gen-go. DO NOT EDIT.
+package api
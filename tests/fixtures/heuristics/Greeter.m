@@ -0,0 +1,3 @@
+#import "Greeter.h"
+@implementation Greeter
+@end
@@ -0,0 +1,2 @@
+parent(tom, bob).
+child(X) :- parent(_, X).
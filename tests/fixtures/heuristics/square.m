@@ -0,0 +1,4 @@
+% Compute the square
+function y = square(x)
+  y = x .^ 2;
+end
@@ -0,0 +1,4 @@
+# comment
+
+print("a")
+print("b")
@@ -0,0 +1,8 @@
+// Entry point
+
+/*
+ * Block comment
+ */
+fn main() {
+    println!("hello"); // greet
+}
@@ -0,0 +1,5 @@
+package pkg
+
+func Ping() string {
+	return "pong"
+}
@@ -0,0 +1,9 @@
+package pkg
+
+import "testing"
+
+func TestPing(t *testing.T) {
+	if Ping() != "pong" {
+		t.Fatal("unexpected reply")
+	}
+}
@@ -0,0 +1,5 @@
+from app.models import User
+
+
+def test_user_name():
+    assert User("ada").name == "ada"
@@ -0,0 +1,5 @@
+package main
+
+import "testing"
+
+func TestPing(t *testing.T) {}
@@ -0,0 +1,8 @@
+/// Services the worker reports the health of, by module path.
+const SERVICES: &[&str] = &["github.com/gin-gonic/gin"];
+
+fn main() {
+    for service in SERVICES {
+        println!("checking {}", service);
+    }
+}
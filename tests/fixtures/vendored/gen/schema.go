@@ -0,0 +1,5 @@
+// Code generated by sqlc. DO NOT EDIT.
+
+package gen
+
+type User struct{}